@@ -0,0 +1,26 @@
+-- ==========================================================
+-- Creator-defined subscription tiers
+-- Subscriptions now reference the tier they were created from;
+-- price and interval are copied from the tier at join time.
+-- ==========================================================
+
+CREATE TABLE IF NOT EXISTS subscription_tiers (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tier_pda         VARCHAR(64) UNIQUE NOT NULL,
+    profile_pda      VARCHAR(64) NOT NULL REFERENCES profiles(profile_pda),
+    tier_id          BIGINT NOT NULL,
+    name             VARCHAR(32) NOT NULL,
+    price            BIGINT NOT NULL,
+    interval_seconds BIGINT NOT NULL,
+    token_mint       VARCHAR(64) NOT NULL DEFAULT '',
+    max_members      INT NOT NULL DEFAULT 0,
+    benefits_hash    VARCHAR(64) NOT NULL DEFAULT '',
+    is_active        BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(profile_pda, tier_id)
+);
+CREATE INDEX IF NOT EXISTS idx_tiers_profile ON subscription_tiers(profile_pda, is_active);
+
+ALTER TABLE subscriptions ADD COLUMN IF NOT EXISTS tier_pda VARCHAR(64);
+CREATE INDEX IF NOT EXISTS idx_subs_tier ON subscriptions(tier_pda, is_active);
@@ -0,0 +1,7 @@
+-- ==========================================================
+-- Runtime feature flags
+-- Mirrors PlatformConfig.disabled_features on-chain: a set bit
+-- switches the feature off. Zero = everything enabled.
+-- ==========================================================
+
+ALTER TABLE platform_config ADD COLUMN IF NOT EXISTS disabled_features INT NOT NULL DEFAULT 0;
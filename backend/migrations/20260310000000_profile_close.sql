@@ -0,0 +1,9 @@
+-- ==========================================================
+-- Profile closing
+-- Set when the owner runs close_profile on-chain. Closed
+-- profiles are hidden from listings; their history is kept.
+-- ==========================================================
+
+ALTER TABLE profiles ADD COLUMN IF NOT EXISTS closed_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_profiles_closed ON profiles(closed_at);
@@ -0,0 +1,10 @@
+-- ==========================================================
+-- Username registry
+-- Usernames are unique on-chain through the username registry
+-- PDA, which close_profile releases. Closed profiles keep their
+-- row, so uniqueness only applies to open profiles.
+-- ==========================================================
+
+ALTER TABLE profiles DROP CONSTRAINT IF EXISTS profiles_username_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_profiles_username_open ON profiles(username) WHERE closed_at IS NULL;
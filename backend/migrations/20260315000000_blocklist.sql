@@ -0,0 +1,15 @@
+-- ==========================================================
+-- Creator blocklist
+-- Mirrors the on-chain BlockedTipper PDAs. Tips from blocked
+-- wallets are rejected on-chain; earlier tips stay in `tips`,
+-- are hidden from overlays, and are kept in exports with their
+-- messages blanked.
+-- ==========================================================
+
+CREATE TABLE IF NOT EXISTS blocked_tippers (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    profile_pda    VARCHAR(64) NOT NULL REFERENCES profiles(profile_pda),
+    wallet_address VARCHAR(64) NOT NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(profile_pda, wallet_address)
+);
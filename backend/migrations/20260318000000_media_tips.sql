@@ -0,0 +1,12 @@
+-- ==========================================================
+-- Media tips
+-- Tips may carry a media attachment (image, GIF or YouTube
+-- clip) for the overlay. Creators opt in by setting a media
+-- minimum; 0 keeps media tips off.
+-- ==========================================================
+
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS media_kind VARCHAR(16);
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS media_url VARCHAR(200);
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS media_start_seconds INT;
+
+ALTER TABLE profiles ADD COLUMN IF NOT EXISTS min_media_tip_amount BIGINT NOT NULL DEFAULT 0;
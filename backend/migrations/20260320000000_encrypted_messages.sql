@@ -0,0 +1,10 @@
+-- ==========================================================
+-- Sealed tip messages
+-- Creators publish an X25519 key; tippers may send a message
+-- sealed to it instead of plaintext. The blob is stored as
+-- sent (base64 fields) and only the creator can open it.
+-- ==========================================================
+
+ALTER TABLE profiles ADD COLUMN IF NOT EXISTS encryption_pubkey VARCHAR(64) NOT NULL DEFAULT '';
+
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS encrypted_message JSONB;
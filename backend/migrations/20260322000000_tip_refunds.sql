@@ -0,0 +1,11 @@
+-- ==========================================================
+-- Tip refunds
+-- Creators can return a SOL tip from the vault within their
+-- refund window. Refunded tips stay in the history but drop
+-- out of stats, leaderboards and gate eligibility.
+-- ==========================================================
+
+ALTER TABLE profiles ADD COLUMN IF NOT EXISTS refund_window_seconds BIGINT NOT NULL DEFAULT 604800;
+
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS refunded_at TIMESTAMPTZ;
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS refund_tx_signature VARCHAR(128) UNIQUE;
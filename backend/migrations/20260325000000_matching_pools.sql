@@ -0,0 +1,33 @@
+-- ==========================================================
+-- Sponsor matching pools
+-- Mirrors the on-chain MatchingPool PDAs. A sponsor escrows
+-- SOL to match tips to a profile, or contributions to one
+-- goal, until the pool runs dry or expires.
+-- ==========================================================
+
+CREATE TABLE IF NOT EXISTS matching_pools (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    pool_pda        VARCHAR(64) NOT NULL UNIQUE,
+    profile_pda     VARCHAR(64) NOT NULL REFERENCES profiles(profile_pda),
+    goal_pda        VARCHAR(64),
+    sponsor_address VARCHAR(64) NOT NULL,
+    match_ratio_bps INT NOT NULL,
+    per_tipper_cap  BIGINT NOT NULL,
+    total_cap       BIGINT NOT NULL,
+    matched_amount  BIGINT NOT NULL DEFAULT 0,
+    expires_at      TIMESTAMPTZ NOT NULL,
+    closed_at       TIMESTAMPTZ,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_matching_pools_profile ON matching_pools(profile_pda);
+
+-- Per-wallet match totals, for the per-tipper cap
+CREATE TABLE IF NOT EXISTS pool_matches (
+    pool_pda       VARCHAR(64) NOT NULL REFERENCES matching_pools(pool_pda),
+    tipper_address VARCHAR(64) NOT NULL,
+    matched_amount BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (pool_pda, tipper_address)
+);
+
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS matched_amount BIGINT NOT NULL DEFAULT 0;
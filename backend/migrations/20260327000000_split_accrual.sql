@@ -0,0 +1,21 @@
+-- ==========================================================
+-- Split accrual mode
+-- Accrual-mode split tips land in the creator's vault and
+-- credit each recipient's claimable balance, which they pull
+-- with claim_split_share. Balances are keyed by profile and
+-- wallet so they survive the split being reconfigured.
+-- ==========================================================
+
+ALTER TABLE tip_splits ADD COLUMN IF NOT EXISTS accrue BOOLEAN NOT NULL DEFAULT false;
+
+-- Recipient order matters: the last recipient takes the rounding remainder
+ALTER TABLE split_recipients ADD COLUMN IF NOT EXISTS position INT NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS split_balances (
+    profile_pda    VARCHAR(64) NOT NULL REFERENCES profiles(profile_pda),
+    wallet_address VARCHAR(64) NOT NULL,
+    claimable      BIGINT NOT NULL DEFAULT 0,
+    total_claimed  BIGINT NOT NULL DEFAULT 0,
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (profile_pda, wallet_address)
+);
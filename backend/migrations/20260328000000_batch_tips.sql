@@ -0,0 +1,11 @@
+-- ==========================================================
+-- Batch tips
+-- send_tip_batch tips several creators in one transaction,
+-- so a signature can now back one tip row per creator. Rows
+-- of a batch are told apart by their position in it.
+-- ==========================================================
+
+ALTER TABLE tips ADD COLUMN IF NOT EXISTS batch_index INT NOT NULL DEFAULT 0;
+
+ALTER TABLE tips DROP CONSTRAINT IF EXISTS tips_tx_signature_key;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tips_tx_batch ON tips(tx_signature, batch_index);
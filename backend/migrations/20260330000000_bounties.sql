@@ -0,0 +1,30 @@
+-- ==========================================================
+-- Bounties
+-- Mirrors the on-chain Bounty PDAs. A viewer escrows SOL or
+-- SPL against a request; the creator accepts and fulfills,
+-- and the escrow is released to the creator or refunded to
+-- the viewer. Disputes are settled by the platform authority.
+-- ==========================================================
+
+CREATE TABLE IF NOT EXISTS bounties (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bounty_pda        VARCHAR(64) NOT NULL UNIQUE,
+    profile_pda       VARCHAR(64) NOT NULL REFERENCES profiles(profile_pda),
+    requester_address VARCHAR(64) NOT NULL,
+    creator_address   VARCHAR(64) NOT NULL,
+    -- NULL for SOL
+    token_mint        VARCHAR(64),
+    amount            BIGINT NOT NULL,
+    description       VARCHAR(256) NOT NULL,
+    status            VARCHAR(16) NOT NULL DEFAULT 'open'
+                      CHECK (status IN ('open', 'accepted', 'fulfilled', 'disputed', 'released', 'refunded')),
+    expires_at        TIMESTAMPTZ NOT NULL,
+    accepted_at       TIMESTAMPTZ,
+    fulfilled_at      TIMESTAMPTZ,
+    settled_at        TIMESTAMPTZ,
+    tx_signature      VARCHAR(128) NOT NULL,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_bounties_profile_status ON bounties(profile_pda, status);
+CREATE INDEX IF NOT EXISTS idx_bounties_requester ON bounties(requester_address);
@@ -0,0 +1,21 @@
+-- ==========================================================
+-- Pinned slots
+-- Mirrors the on-chain PinnedSlot PDAs. A tip that outbids
+-- the current pin (by the creator's minimum raise) holds the
+-- pinned overlay message for duration_seconds.
+-- ==========================================================
+
+CREATE TABLE IF NOT EXISTS pinned_slots (
+    profile_pda       VARCHAR(64) PRIMARY KEY REFERENCES profiles(profile_pda),
+    duration_seconds  BIGINT NOT NULL,
+    min_bid           BIGINT NOT NULL,
+    min_increment_bps INT NOT NULL,
+    -- Current (or last) pin; NULL until the first one
+    tipper_address    VARCHAR(64),
+    bid               BIGINT NOT NULL DEFAULT 0,
+    message           VARCHAR(280),
+    pinned_at         TIMESTAMPTZ,
+    expires_at        TIMESTAMPTZ,
+    total_pins        BIGINT NOT NULL DEFAULT 0,
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -1,4 +1,5 @@
 pub const PLATFORM_FEE_BPS: u16 = 100;
+pub const MAX_PLATFORM_FEE_BPS: i32 = 1_000;
 pub const MAX_USERNAME_LENGTH: usize = 32;
 pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
 pub const MAX_DESCRIPTION_LENGTH: usize = 256;
@@ -7,6 +8,7 @@ pub const MAX_MESSAGE_LENGTH: usize = 280;
 pub const MAX_GOAL_TITLE_LENGTH: usize = 64;
 pub const MAX_GOAL_DESCRIPTION_LENGTH: usize = 256;
 pub const MAX_SPLIT_RECIPIENTS: usize = 5;
+pub const MAX_ACCRUED_SPLIT_RECIPIENTS: usize = 25;
 pub const BPS_DENOMINATOR: u64 = 10_000;
 pub const MAX_POLL_OPTIONS: usize = 4;
 pub const MIN_POLL_OPTIONS: usize = 2;
@@ -16,3 +18,28 @@ pub const MAX_REFERRAL_FEE_BPS: i32 = 2_000;
 pub const MAX_PRESET_AMOUNTS: usize = 5;
 pub const MAX_WEBHOOK_URL_LENGTH: usize = 200;
 pub const MAX_SOCIAL_LINKS_LENGTH: usize = 256;
+pub const MAX_MEDIA_URL_LENGTH: usize = 200;
+pub const MAX_REFUND_WINDOW_SECONDS: i64 = 2_592_000;
+pub const MAX_MATCH_RATIO_BPS: i32 = 50_000;
+pub const MAX_MATCHING_POOL_DURATION: i64 = 31_536_000;
+pub const MAX_BATCH_TIPS: usize = 5;
+pub const MAX_BOUNTY_DESCRIPTION_LENGTH: usize = 256;
+pub const MAX_BOUNTY_DURATION: i64 = 7_776_000;
+pub const BOUNTY_REVIEW_WINDOW_SECONDS: i64 = 259_200;
+pub const MIN_TIP_AMOUNT: i64 = 1_000;
+pub const MIN_PIN_DURATION: i64 = 60;
+pub const MAX_PIN_DURATION: i64 = 86_400;
+pub const MAX_PIN_INCREMENT_BPS: i32 = 10_000;
+
+// Feature flag bits, matching PlatformConfig.disabled_features on-chain
+pub const FEATURE_FLAGS: &[(&str, i32)] = &[
+    ("subscriptions", 1 << 0),
+    ("goals", 1 << 1),
+    ("anonymous_tips", 1 << 2),
+    ("multi_token", 1 << 3),
+    ("rate_limiting", 1 << 4),
+    ("tip_splits", 1 << 5),
+    ("polls", 1 << 6),
+    ("referrals", 1 << 7),
+    ("content_gates", 1 << 8),
+];
@@ -22,7 +22,7 @@ pub async fn rebuild_daily_analytics(
     pool: &PgPool,
     profile_pda: &str,
 ) -> Result<(), sqlx::Error> {
-    sqlx::query(
+    sqlx::query(&format!(
         r#"INSERT INTO analytics_daily (profile_pda, date, tip_count, total_amount, unique_tippers, spl_amount)
            SELECT
                recipient_profile_pda,
@@ -30,9 +30,10 @@ pub async fn rebuild_daily_analytics(
                COUNT(*)::int,
                COALESCE(SUM(CASE WHEN tip_type = 'sol' THEN amount_lamports ELSE 0 END), 0),
                COUNT(DISTINCT tipper_address)::int,
-               COALESCE(SUM(CASE WHEN tip_type = 'spl' THEN amount_lamports ELSE 0 END), 0)
+               COALESCE(SUM(CASE WHEN tip_type IN ('spl', 'split_spl') THEN amount_lamports ELSE 0 END), 0)
            FROM tips
            WHERE recipient_profile_pda = $1
+             AND {}
            GROUP BY recipient_profile_pda, DATE(created_at)
            ON CONFLICT (profile_pda, date)
            DO UPDATE SET
@@ -40,7 +41,8 @@ pub async fn rebuild_daily_analytics(
                total_amount = EXCLUDED.total_amount,
                unique_tippers = EXCLUDED.unique_tippers,
                spl_amount = EXCLUDED.spl_amount"#,
-    )
+        crate::db::tips::NOT_REFUNDED
+    ))
     .bind(profile_pda)
     .execute(pool)
     .await?;
@@ -70,10 +72,12 @@ pub async fn get_time_window_leaderboard(
            WHERE recipient_profile_pda = $1
              AND created_at >= NOW() - INTERVAL '{}'
              AND is_anonymous = FALSE
+             AND {}
            GROUP BY tipper_address
            ORDER BY total_amount DESC
            LIMIT $2"#,
-        interval
+        interval,
+        crate::db::tips::NOT_REFUNDED
     );
 
     sqlx::query_as::<_, LeaderboardEntry>(&query)
@@ -103,8 +107,10 @@ pub async fn get_window_stats(
                COALESCE(COUNT(DISTINCT tipper_address), 0)::bigint as unique_tippers
            FROM tips
            WHERE recipient_profile_pda = $1
-             AND created_at >= NOW() - INTERVAL '{}'"#,
-        interval
+             AND created_at >= NOW() - INTERVAL '{}'
+             AND {}"#,
+        interval,
+        crate::db::tips::NOT_REFUNDED
     );
 
     let row = sqlx::query_as::<_, (i64, i64, i64)>(&query)
@@ -0,0 +1,37 @@
+use sqlx::PgPool;
+use crate::models::BlockedTipper;
+
+/// SQL fragment excluding tips from wallets the recipient profile has
+/// blocked. Expects the `tips` table unaliased.
+pub const NOT_BLOCKED: &str = "NOT EXISTS (SELECT 1 FROM blocked_tippers b \
+    WHERE b.profile_pda = tips.recipient_profile_pda AND b.wallet_address = tips.tipper_address)";
+
+pub async fn block(pool: &PgPool, profile_pda: &str, wallet_address: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO blocked_tippers (profile_pda, wallet_address) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+    )
+    .bind(profile_pda)
+    .bind(wallet_address)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Returns false if the wallet was not blocked
+pub async fn unblock(pool: &PgPool, profile_pda: &str, wallet_address: &str) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM blocked_tippers WHERE profile_pda = $1 AND wallet_address = $2")
+        .bind(profile_pda)
+        .bind(wallet_address)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn find_by_profile(pool: &PgPool, profile_pda: &str) -> Result<Vec<BlockedTipper>, sqlx::Error> {
+    sqlx::query_as::<_, BlockedTipper>(
+        "SELECT * FROM blocked_tippers WHERE profile_pda = $1 ORDER BY created_at DESC",
+    )
+    .bind(profile_pda)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use crate::models::{Bounty, CreateBountyRequest};
+
+pub async fn create(
+    pool: &PgPool,
+    requester_address: &str,
+    creator_address: &str,
+    req: &CreateBountyRequest,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO bounties (bounty_pda, profile_pda, requester_address, creator_address, token_mint, amount, description, expires_at, tx_signature) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+    )
+    .bind(&req.bounty_pda)
+    .bind(&req.profile_pda)
+    .bind(requester_address)
+    .bind(creator_address)
+    .bind(&req.token_mint)
+    .bind(req.amount)
+    .bind(&req.description)
+    .bind(expires_at)
+    .bind(&req.tx_signature)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_by_pda(pool: &PgPool, bounty_pda: &str) -> Result<Option<Bounty>, sqlx::Error> {
+    sqlx::query_as::<_, Bounty>("SELECT * FROM bounties WHERE bounty_pda = $1")
+        .bind(bounty_pda)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Bounties for a profile that still hold escrow, newest first
+pub async fn find_open_by_profile(pool: &PgPool, profile_pda: &str) -> Result<Vec<Bounty>, sqlx::Error> {
+    sqlx::query_as::<_, Bounty>(
+        "SELECT * FROM bounties WHERE profile_pda = $1 AND status NOT IN ('released', 'refunded') \
+         ORDER BY created_at DESC",
+    )
+    .bind(profile_pda)
+    .fetch_all(pool)
+    .await
+}
+
+/// Move a bounty to `status`, stamping the matching timestamp
+pub async fn set_status(pool: &PgPool, bounty_pda: &str, status: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE bounties SET status = $2, \
+         accepted_at  = CASE WHEN $2 = 'accepted'  THEN NOW() ELSE accepted_at END, \
+         fulfilled_at = CASE WHEN $2 = 'fulfilled' THEN NOW() ELSE fulfilled_at END, \
+         settled_at   = CASE WHEN $2 IN ('released', 'refunded') THEN NOW() ELSE settled_at END \
+         WHERE bounty_pda = $1",
+    )
+    .bind(bounty_pda)
+    .bind(status)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
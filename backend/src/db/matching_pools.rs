@@ -0,0 +1,113 @@
+use sqlx::{PgConnection, PgPool};
+use chrono::{DateTime, Utc};
+use crate::models::{CreateMatchingPoolRequest, MatchingPool};
+
+pub async fn create(
+    pool: &PgPool,
+    sponsor_address: &str,
+    req: &CreateMatchingPoolRequest,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO matching_pools (pool_pda, profile_pda, goal_pda, sponsor_address, match_ratio_bps, per_tipper_cap, total_cap, expires_at) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(&req.pool_pda)
+    .bind(&req.profile_pda)
+    .bind(&req.goal_pda)
+    .bind(sponsor_address)
+    .bind(req.match_ratio_bps)
+    .bind(req.per_tipper_cap)
+    .bind(req.total_cap)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_by_pda(pool: &PgPool, pool_pda: &str) -> Result<Option<MatchingPool>, sqlx::Error> {
+    sqlx::query_as::<_, MatchingPool>("SELECT * FROM matching_pools WHERE pool_pda = $1")
+        .bind(pool_pda)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Open, unexpired pools for a profile, tip pools first
+pub async fn find_active_by_profile(pool: &PgPool, profile_pda: &str) -> Result<Vec<MatchingPool>, sqlx::Error> {
+    sqlx::query_as::<_, MatchingPool>(
+        "SELECT * FROM matching_pools WHERE profile_pda = $1 AND closed_at IS NULL AND expires_at > NOW() \
+         ORDER BY goal_pda IS NOT NULL, created_at DESC",
+    )
+    .bind(profile_pda)
+    .fetch_all(pool)
+    .await
+}
+
+/// Record the match a pool pays on `amount`, mirroring the on-chain rule:
+/// the ratio share, limited by the wallet's remaining cap and what is left
+/// in the pool. Returns the lamports matched (0 once expired or drained).
+pub async fn apply_match(
+    pool: &PgPool,
+    matching_pool: &MatchingPool,
+    tipper_address: &str,
+    amount: i64,
+) -> Result<i64, sqlx::Error> {
+    if matching_pool.closed_at.is_some() || matching_pool.expires_at <= Utc::now() {
+        return Ok(0);
+    }
+
+    let already: Option<(i64,)> = sqlx::query_as(
+        "SELECT matched_amount FROM pool_matches WHERE pool_pda = $1 AND tipper_address = $2",
+    )
+    .bind(&matching_pool.pool_pda)
+    .bind(tipper_address)
+    .fetch_optional(pool)
+    .await?;
+    let already = already.map(|a| a.0).unwrap_or(0);
+
+    let by_ratio = amount.saturating_mul(matching_pool.match_ratio_bps as i64) / 10_000;
+    let tipper_left = (matching_pool.per_tipper_cap - already).max(0);
+    let pool_left = (matching_pool.total_cap - matching_pool.matched_amount).max(0);
+    let matched = by_ratio.min(tipper_left).min(pool_left);
+    if matched == 0 {
+        return Ok(0);
+    }
+
+    let mut conn = pool.acquire().await?;
+    record_match(&mut conn, &matching_pool.pool_pda, tipper_address, matched).await?;
+    Ok(matched)
+}
+
+/// Record a match already settled on-chain, e.g. read from MatchedEvent
+pub async fn record_match(
+    conn: &mut PgConnection,
+    pool_pda: &str,
+    tipper_address: &str,
+    matched: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO pool_matches (pool_pda, tipper_address, matched_amount) VALUES ($1, $2, $3) \
+         ON CONFLICT (pool_pda, tipper_address) DO UPDATE SET matched_amount = pool_matches.matched_amount + $3",
+    )
+    .bind(pool_pda)
+    .bind(tipper_address)
+    .bind(matched)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query("UPDATE matching_pools SET matched_amount = matched_amount + $1 WHERE pool_pda = $2")
+        .bind(matched)
+        .bind(pool_pda)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn close(pool: &PgPool, pool_pda: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE matching_pools SET closed_at = NOW() WHERE pool_pda = $1")
+        .bind(pool_pda)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
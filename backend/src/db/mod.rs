@@ -1,11 +1,16 @@
 pub mod analytics;
+pub mod blocklist;
 pub mod content_gates;
 pub mod goals;
+pub mod matching_pools;
+pub mod pinned_slots;
+pub mod bounties;
 pub mod platform;
 pub mod polls;
 pub mod profiles;
 pub mod referrals;
 pub mod splits;
+pub mod subscription_tiers;
 pub mod subscriptions;
 pub mod tips;
 pub mod vault;
@@ -0,0 +1,73 @@
+use sqlx::{PgConnection, PgPool};
+use chrono::{DateTime, Duration, Utc};
+use log::warn;
+use crate::models::{ConfigurePinnedSlotRequest, PinnedSlot};
+
+/// Create or reconfigure a profile's slot; a live pin keeps its bid and expiry
+pub async fn upsert(pool: &PgPool, req: &ConfigurePinnedSlotRequest) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO pinned_slots (profile_pda, duration_seconds, min_bid, min_increment_bps) VALUES ($1, $2, $3, $4) \
+         ON CONFLICT (profile_pda) DO UPDATE SET duration_seconds = $2, min_bid = $3, min_increment_bps = $4, updated_at = NOW()",
+    )
+    .bind(&req.profile_pda)
+    .bind(req.duration_seconds)
+    .bind(req.min_bid)
+    .bind(req.min_increment_bps)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_by_profile(pool: &PgPool, profile_pda: &str) -> Result<Option<PinnedSlot>, sqlx::Error> {
+    sqlx::query_as::<_, PinnedSlot>("SELECT * FROM pinned_slots WHERE profile_pda = $1")
+        .bind(profile_pda)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Smallest tip that takes the slot at `now`, mirroring PinnedSlot::min_next_bid
+pub fn min_next_bid(slot: &PinnedSlot, now: DateTime<Utc>) -> i64 {
+    if slot.expires_at.is_none_or(|t| t <= now) {
+        return slot.min_bid;
+    }
+    let raise = (slot.bid.saturating_mul(slot.min_increment_bps as i64) / 10_000).max(1);
+    slot.bid.saturating_add(raise)
+}
+
+/// Record a pin the chain accepted at `pinned_at`. A pin older than the one
+/// stored (recorded out of order) is skipped; disagreements with the stored
+/// slot are logged and the chain's values win.
+pub async fn apply_pin(
+    conn: &mut PgConnection,
+    slot: &PinnedSlot,
+    tipper_address: &str,
+    amount: i64,
+    message: &str,
+    pinned_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    if slot.pinned_at.is_some_and(|t| t >= pinned_at) {
+        warn!("Skipping pin on {} at {}: a later pin is already recorded", slot.profile_pda, pinned_at);
+        return Ok(());
+    }
+    if amount < min_next_bid(slot, pinned_at) {
+        warn!("Pin on {} of {} is below the stored min_next_bid; recording the chain's pin", slot.profile_pda, amount);
+    }
+    if expires_at != pinned_at + Duration::seconds(slot.duration_seconds) {
+        warn!("Pin on {} expires at {}, not after the stored duration; recording the chain's expiry", slot.profile_pda, expires_at);
+    }
+
+    sqlx::query(
+        "UPDATE pinned_slots SET tipper_address = $1, bid = $2, message = $3, pinned_at = $4, expires_at = $5, \
+         total_pins = total_pins + 1, updated_at = NOW() WHERE profile_pda = $6",
+    )
+    .bind(tipper_address)
+    .bind(amount)
+    .bind(message)
+    .bind(pinned_at)
+    .bind(expires_at)
+    .bind(&slot.profile_pda)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
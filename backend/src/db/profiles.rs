@@ -6,7 +6,7 @@ use crate::models::Profile;
 
 pub async fn find_by_address(pool: &PgPool, address: &str) -> Result<Option<Profile>, ApiError> {
     let profile = sqlx::query_as::<_, Profile>(
-        "SELECT * FROM profiles WHERE owner_address = $1 OR profile_pda = $1 OR username = $1"
+        "SELECT * FROM profiles WHERE owner_address = $1 OR profile_pda = $1 OR username = $1 ORDER BY closed_at IS NOT NULL LIMIT 1"
     )
         .bind(address)
         .fetch_optional(pool)
@@ -26,7 +26,7 @@ pub async fn find_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Profile>, ApiE
 
 pub async fn exists_by_username(pool: &PgPool, username: &str) -> Result<bool, ApiError> {
     let result: (i64,) = sqlx::query_as(
-        "SELECT COUNT(*) FROM profiles WHERE username = $1"
+        "SELECT COUNT(*) FROM profiles WHERE username = $1 AND closed_at IS NULL"
     )
         .bind(username)
         .fetch_one(pool)
@@ -46,7 +46,7 @@ pub async fn find_by_pda(pool: &PgPool, profile_pda: &str) -> Result<Option<Prof
 
 pub async fn find_by_username(pool: &PgPool, username: &str) -> Result<Option<Profile>, ApiError> {
     let profile = sqlx::query_as::<_, Profile>(
-        "SELECT * FROM profiles WHERE username = $1"
+        "SELECT * FROM profiles WHERE username = $1 AND closed_at IS NULL"
     )
         .bind(username)
         .fetch_optional(pool)
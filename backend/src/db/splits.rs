@@ -15,10 +15,69 @@ pub async fn find_by_profile(pool: &PgPool, profile_pda: &str) -> Result<Option<
 
 pub async fn find_recipients(pool: &PgPool, split_id: uuid::Uuid) -> Result<Vec<SplitRecipient>, ApiError> {
     let recipients = sqlx::query_as::<_, SplitRecipient>(
-        "SELECT * FROM split_recipients WHERE split_id = $1 ORDER BY share_bps DESC"
+        "SELECT * FROM split_recipients WHERE split_id = $1 ORDER BY position"
     )
         .bind(split_id)
         .fetch_all(pool)
         .await?;
     Ok(recipients)
 }
+
+/// Credit accrual-mode shares, mirroring TipSplit::calculate_shares: each
+/// recipient gets their bps of `amount`, the last one the remainder.
+/// Expects `recipients` in split order.
+pub async fn credit_shares(
+    pool: &PgPool,
+    profile_pda: &str,
+    recipients: &[SplitRecipient],
+    amount: i64,
+) -> Result<(), ApiError> {
+    let mut distributed = 0i64;
+    for (i, r) in recipients.iter().enumerate() {
+        let share = if i == recipients.len() - 1 {
+            amount - distributed
+        } else {
+            ((amount as i128 * r.share_bps as i128) / 10_000) as i64
+        };
+        distributed += share;
+        sqlx::query(
+            "INSERT INTO split_balances (profile_pda, wallet_address, claimable) VALUES ($1, $2, $3) \
+             ON CONFLICT (profile_pda, wallet_address) DO UPDATE SET claimable = split_balances.claimable + $3, updated_at = NOW()"
+        )
+            .bind(profile_pda)
+            .bind(&r.wallet_address)
+            .bind(share)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Unclaimed balances for a profile, keyed by wallet
+pub async fn find_claimable(pool: &PgPool, profile_pda: &str) -> Result<Vec<(String, i64)>, ApiError> {
+    let balances = sqlx::query_as::<_, (String, i64)>(
+        "SELECT wallet_address, claimable FROM split_balances WHERE profile_pda = $1 AND claimable > 0"
+    )
+        .bind(profile_pda)
+        .fetch_all(pool)
+        .await?;
+    Ok(balances)
+}
+
+/// Zero a wallet's balance; returns what it was owed, if anything
+pub async fn claim(pool: &PgPool, profile_pda: &str, wallet_address: &str) -> Result<Option<i64>, ApiError> {
+    let claimed = sqlx::query_as::<_, (i64,)>(
+        "WITH owed AS ( \
+             SELECT claimable FROM split_balances \
+             WHERE profile_pda = $1 AND wallet_address = $2 AND claimable > 0 FOR UPDATE \
+         ) \
+         UPDATE split_balances SET total_claimed = total_claimed + owed.claimable, claimable = 0, updated_at = NOW() \
+         FROM owed WHERE profile_pda = $1 AND wallet_address = $2 \
+         RETURNING owed.claimable"
+    )
+        .bind(profile_pda)
+        .bind(wallet_address)
+        .fetch_optional(pool)
+        .await?;
+    Ok(claimed.map(|c| c.0))
+}
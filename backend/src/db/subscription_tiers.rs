@@ -0,0 +1,86 @@
+use sqlx::PgPool;
+use crate::models::{CreateTierRequest, SubscriptionTier, UpdateTierRequest};
+
+/// Tier columns plus the live member count (active subscriptions on the tier)
+const TIER_SELECT: &str = r#"SELECT t.*,
+    (SELECT COUNT(*) FROM subscriptions s WHERE s.tier_pda = t.tier_pda AND s.is_active = TRUE) AS member_count
+    FROM subscription_tiers t"#;
+
+pub async fn create_tier(
+    pool: &PgPool,
+    tier_pda: &str,
+    profile_pda: &str,
+    t: &CreateTierRequest,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"INSERT INTO subscription_tiers
+               (tier_pda, profile_pda, tier_id, name, price, interval_seconds, token_mint, max_members, benefits_hash)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"#,
+    )
+    .bind(tier_pda)
+    .bind(profile_pda)
+    .bind(t.tier_id)
+    .bind(&t.name)
+    .bind(t.price)
+    .bind(t.interval_seconds)
+    .bind(t.token_mint.as_deref().unwrap_or(""))
+    .bind(t.max_members.unwrap_or(0))
+    .bind(t.benefits_hash.as_deref().unwrap_or(""))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_tiers_by_profile(
+    pool: &PgPool,
+    profile_pda: &str,
+    active_only: bool,
+) -> Result<Vec<SubscriptionTier>, sqlx::Error> {
+    let sql = if active_only {
+        format!("{} WHERE t.profile_pda = $1 AND t.is_active = TRUE ORDER BY t.price ASC LIMIT 100", TIER_SELECT)
+    } else {
+        format!("{} WHERE t.profile_pda = $1 ORDER BY t.price ASC LIMIT 100", TIER_SELECT)
+    };
+    sqlx::query_as::<_, SubscriptionTier>(&sql)
+        .bind(profile_pda)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn find_tier_by_pda(pool: &PgPool, tier_pda: &str) -> Result<Option<SubscriptionTier>, sqlx::Error> {
+    sqlx::query_as::<_, SubscriptionTier>(&format!("{} WHERE t.tier_pda = $1", TIER_SELECT))
+        .bind(tier_pda)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Apply a partial update; existing subscriptions keep the terms they joined at.
+pub async fn update_tier(pool: &PgPool, tier_pda: &str, u: &UpdateTierRequest) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"UPDATE subscription_tiers SET
+               name             = COALESCE($2, name),
+               price            = COALESCE($3, price),
+               interval_seconds = COALESCE($4, interval_seconds),
+               max_members      = COALESCE($5, max_members),
+               benefits_hash    = COALESCE($6, benefits_hash),
+               updated_at       = NOW()
+           WHERE tier_pda = $1"#,
+    )
+    .bind(tier_pda)
+    .bind(&u.name)
+    .bind(u.price)
+    .bind(u.interval_seconds)
+    .bind(u.max_members)
+    .bind(&u.benefits_hash)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn retire_tier(pool: &PgPool, tier_pda: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE subscription_tiers SET is_active = FALSE, updated_at = NOW() WHERE tier_pda = $1")
+        .bind(tier_pda)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
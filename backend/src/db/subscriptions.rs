@@ -35,7 +35,7 @@ pub async fn find_active_by_recipient(pool: &PgPool, profile_pda: &str) -> Resul
 
 pub async fn find_due_subscriptions(pool: &PgPool) -> Result<Vec<Subscription>, ApiError> {
     let subs = sqlx::query_as::<_, Subscription>(
-        "SELECT * FROM subscriptions WHERE is_active = true AND auto_renew = true AND next_payment_due <= NOW()"
+        "SELECT * FROM subscriptions WHERE is_active = true AND auto_renew = true AND next_payment_due <= NOW() ORDER BY next_payment_due ASC LIMIT 100"
     )
         .fetch_all(pool)
         .await?;
@@ -3,6 +3,10 @@ use sqlx::PgPool;
 use crate::error::ApiError;
 use crate::models::Tip;
 
+/// SQL fragment excluding tips the creator has refunded.
+/// Expects the `tips` table unaliased.
+pub const NOT_REFUNDED: &str = "tips.refunded_at IS NULL";
+
 pub async fn find_by_address(pool: &PgPool, address: &str, limit: i64, offset: i64) -> Result<Vec<Tip>, ApiError> {
     let tips = sqlx::query_as::<_, Tip>(
         "SELECT * FROM tips WHERE tipper_address = $1 OR recipient_address = $1 ORDER BY created_at DESC LIMIT $2 OFFSET $3"
@@ -27,9 +31,21 @@ pub async fn count_by_address(pool: &PgPool, address: &str) -> Result<i64, ApiEr
 
 pub async fn find_by_tx(pool: &PgPool, tx_signature: &str) -> Result<Option<Tip>, ApiError> {
     let tip = sqlx::query_as::<_, Tip>(
-        "SELECT * FROM tips WHERE tx_signature = $1"
+        "SELECT * FROM tips WHERE tx_signature = $1 ORDER BY batch_index LIMIT 1"
+    )
+        .bind(tx_signature)
+        .fetch_optional(pool)
+        .await?;
+    Ok(tip)
+}
+
+/// The tip a transaction paid to `recipient`; batch transactions pay several
+pub async fn find_by_tx_for_recipient(pool: &PgPool, tx_signature: &str, recipient: &str) -> Result<Option<Tip>, ApiError> {
+    let tip = sqlx::query_as::<_, Tip>(
+        "SELECT * FROM tips WHERE tx_signature = $1 AND recipient_address = $2"
     )
         .bind(tx_signature)
+        .bind(recipient)
         .fetch_optional(pool)
         .await?;
     Ok(tip)
@@ -1,6 +1,7 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 
 use crate::app_middleware::require_wallet_auth;
+use crate::config::{FEATURE_FLAGS, MAX_PLATFORM_FEE_BPS};
 use crate::error::ApiError;
 use crate::models::*;
 use crate::AppState;
@@ -25,6 +26,11 @@ pub async fn get_platform_config(
             platform_fee_bps: c.platform_fee_bps,
             paused: c.paused,
             created_at: c.created_at.timestamp(),
+            disabled_features: c.disabled_features,
+            features: FEATURE_FLAGS
+                .iter()
+                .map(|(name, bit)| (name.to_string(), c.disabled_features & bit == 0))
+                .collect(),
         })),
         None => Err(ApiError::NotFound("Platform config not found".to_string())),
     }
@@ -65,6 +71,107 @@ pub async fn pause_platform(
     }))
 }
 
+pub async fn set_feature_flags(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<SetFeatureFlagsRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    if auth.wallet_address != body.authority_address {
+        return Err(ApiError::Unauthorized("Wallet does not match authority_address".to_string()));
+    }
+
+    // Verify authority
+    let config: Option<PlatformConfigRow> = sqlx::query_as(
+        "SELECT * FROM platform_config ORDER BY created_at DESC LIMIT 1"
+    )
+        .fetch_optional(&state.db)
+        .await?;
+
+    let config = config.ok_or_else(|| ApiError::NotFound("Platform config not found".to_string()))?;
+
+    if config.authority_address != body.authority_address {
+        return Err(ApiError::Unauthorized("Not platform authority".to_string()));
+    }
+
+    let mut mask = 0;
+    for name in &body.features {
+        let (_, bit) = FEATURE_FLAGS
+            .iter()
+            .find(|(n, _)| n == name)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown feature: {}", name)))?;
+        mask |= bit;
+    }
+    if mask == 0 {
+        return Err(ApiError::BadRequest("At least one feature is required".into()));
+    }
+
+    let disabled_features = if body.enabled {
+        config.disabled_features & !mask
+    } else {
+        config.disabled_features | mask
+    };
+
+    sqlx::query("UPDATE platform_config SET disabled_features = $1 WHERE id = $2")
+        .bind(disabled_features)
+        .bind(config.id)
+        .execute(&state.db)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse {
+        success: true,
+        message: format!(
+            "Features {} {}",
+            body.features.join(", "),
+            if body.enabled { "enabled" } else { "disabled" }
+        ),
+    }))
+}
+
+pub async fn update_platform_config(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<UpdatePlatformConfigRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    if auth.wallet_address != body.authority_address {
+        return Err(ApiError::Unauthorized("Wallet does not match authority_address".to_string()));
+    }
+
+    // Verify authority
+    let config: Option<PlatformConfigRow> = sqlx::query_as(
+        "SELECT * FROM platform_config ORDER BY created_at DESC LIMIT 1"
+    )
+        .fetch_optional(&state.db)
+        .await?;
+
+    let config = config.ok_or_else(|| ApiError::NotFound("Platform config not found".to_string()))?;
+
+    if config.authority_address != body.authority_address {
+        return Err(ApiError::Unauthorized("Not platform authority".to_string()));
+    }
+
+    let platform_fee_bps = body.platform_fee_bps.unwrap_or(config.platform_fee_bps);
+    if !(0..=MAX_PLATFORM_FEE_BPS).contains(&platform_fee_bps) {
+        return Err(ApiError::BadRequest(format!("Platform fee must be 0-{} bps", MAX_PLATFORM_FEE_BPS)));
+    }
+    let treasury = body.treasury_address.clone().unwrap_or(config.treasury_address);
+
+    sqlx::query(
+        "UPDATE platform_config SET platform_fee_bps = $1, treasury_address = $2 WHERE id = $3"
+    )
+        .bind(platform_fee_bps)
+        .bind(&treasury)
+        .bind(config.id)
+        .execute(&state.db)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse {
+        success: true,
+        message: format!("Platform config updated: fee {} bps, treasury {}", platform_fee_bps, treasury),
+    }))
+}
+
 pub async fn verify_creator(
     req: HttpRequest,
     state: web::Data<AppState>,
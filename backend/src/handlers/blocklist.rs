@@ -0,0 +1,65 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use crate::error::ApiError;
+use crate::models::*;
+use crate::db;
+use crate::services;
+use crate::AppState;
+use crate::app_middleware::require_wallet_auth;
+
+/// GET /blocklist -- wallets blocked by the caller's profile
+pub async fn list_blocked(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let profile = find_own_profile(&state, &auth.wallet_address).await?;
+
+    let blocked = db::blocklist::find_by_profile(&state.db, &profile.profile_pda).await?;
+    let responses: Vec<BlockedTipperResponse> = blocked.into_iter().map(|b| BlockedTipperResponse {
+        wallet_address: b.wallet_address,
+        blocked_at: b.created_at.timestamp(),
+    }).collect();
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+/// POST /blocklist -- record a block after block_tipper ran on-chain
+pub async fn block_tipper(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Json<BlockTipperRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    services::solana::validate_address(&body.wallet_address)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid wallet_address: {}", e)))?;
+    if body.wallet_address == auth.wallet_address {
+        return Err(ApiError::BadRequest("Cannot block your own wallet".into()));
+    }
+
+    let profile = find_own_profile(&state, &auth.wallet_address).await?;
+    db::blocklist::block(&state.db, &profile.profile_pda, &body.wallet_address).await?;
+
+    Ok(HttpResponse::Created().json(TxResponse { success: true, message: "Wallet blocked".into() }))
+}
+
+/// DELETE /blocklist/{wallet_address} -- record an unblock_tipper
+pub async fn unblock_tipper(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let wallet_address = path.into_inner();
+
+    let profile = find_own_profile(&state, &auth.wallet_address).await?;
+    if !db::blocklist::unblock(&state.db, &profile.profile_pda, &wallet_address).await? {
+        return Err(ApiError::NotFound("Wallet is not blocked".into()));
+    }
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Wallet unblocked".into() }))
+}
+
+async fn find_own_profile(state: &web::Data<AppState>, wallet: &str) -> Result<Profile, ApiError> {
+    db::profiles::find_by_address(&state.db, wallet)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Profile not found".into()))
+}
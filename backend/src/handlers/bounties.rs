@@ -0,0 +1,259 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use crate::error::ApiError;
+use crate::models::*;
+use crate::db;
+use crate::services;
+use crate::AppState;
+use crate::app_middleware::require_wallet_auth;
+
+/// POST /bounties -- record a create_bounty / create_bounty_spl once confirmed
+pub async fn create_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Json<CreateBountyRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+
+    if body.amount <= 0 {
+        return Err(ApiError::BadRequest("Amount must be positive".into()));
+    }
+    if body.description.is_empty() || body.description.len() > crate::config::MAX_BOUNTY_DESCRIPTION_LENGTH {
+        return Err(ApiError::BadRequest(format!(
+            "Description must be 1-{} characters", crate::config::MAX_BOUNTY_DESCRIPTION_LENGTH
+        )));
+    }
+    let now = Utc::now().timestamp();
+    if body.expires_at <= now || body.expires_at - now > crate::config::MAX_BOUNTY_DURATION {
+        return Err(ApiError::BadRequest("Invalid bounty expiry".into()));
+    }
+    let expires_at = DateTime::from_timestamp(body.expires_at, 0)
+        .ok_or_else(|| ApiError::BadRequest("Invalid bounty expiry".into()))?;
+    if let Some(mint) = &body.token_mint {
+        services::solana::validate_address(mint)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid token_mint: {}", e)))?;
+    }
+
+    let profile = db::profiles::find_by_pda(&state.db, &body.profile_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Profile not found".into()))?;
+    if profile.owner_address == auth.wallet_address {
+        return Err(ApiError::BadRequest("Cannot post a bounty to your own profile".into()));
+    }
+
+    if db::bounties::find_by_pda(&state.db, &body.bounty_pda).await?.is_some() {
+        return Err(ApiError::BadRequest("Bounty already recorded".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+
+    db::bounties::create(&state.db, &auth.wallet_address, &profile.owner_address, &body, expires_at).await?;
+
+    Ok(HttpResponse::Created().json(TxResponse { success: true, message: "Bounty created".into() }))
+}
+
+/// GET /bounties/{profile_pda} -- bounties for a profile still holding escrow
+pub async fn list_bounties(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let profile_pda = path.into_inner();
+    let bounties = db::bounties::find_open_by_profile(&state.db, &profile_pda).await?;
+    let responses: Vec<BountyResponse> = bounties.into_iter().map(to_response).collect();
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+/// POST /bounties/{bounty_pda}/accept -- creator takes on an open bounty
+pub async fn accept_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<BountyTxRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let bounty = load(&state, &path).await?;
+    if bounty.creator_address != auth.wallet_address {
+        return Err(ApiError::Unauthorized("Only the creator can accept this bounty".into()));
+    }
+    if bounty.status != "open" {
+        return Err(ApiError::BadRequest("Bounty is not open".into()));
+    }
+    if bounty.expires_at <= Utc::now() {
+        return Err(ApiError::BadRequest("Bounty has expired".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+    db::bounties::set_status(&state.db, &bounty.bounty_pda, "accepted").await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Bounty accepted".into() }))
+}
+
+/// POST /bounties/{bounty_pda}/fulfill -- creator marks an accepted bounty done
+pub async fn fulfill_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<BountyTxRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let bounty = load(&state, &path).await?;
+    if bounty.creator_address != auth.wallet_address {
+        return Err(ApiError::Unauthorized("Only the creator can fulfill this bounty".into()));
+    }
+    if bounty.status != "accepted" {
+        return Err(ApiError::BadRequest("Bounty has not been accepted".into()));
+    }
+    if bounty.expires_at <= Utc::now() {
+        return Err(ApiError::BadRequest("Bounty has expired".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+    db::bounties::set_status(&state.db, &bounty.bounty_pda, "fulfilled").await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Bounty fulfilled".into() }))
+}
+
+/// POST /bounties/{bounty_pda}/dispute -- requester contests a fulfillment
+pub async fn dispute_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<BountyTxRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let bounty = load(&state, &path).await?;
+    if bounty.requester_address != auth.wallet_address {
+        return Err(ApiError::Unauthorized("Only the requester can dispute this bounty".into()));
+    }
+    if bounty.status != "fulfilled" {
+        return Err(ApiError::BadRequest("Bounty has not been fulfilled".into()));
+    }
+    if review_ends_at(&bounty).is_none_or(|end| end <= Utc::now()) {
+        return Err(ApiError::BadRequest("Bounty review window has closed".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+    db::bounties::set_status(&state.db, &bounty.bounty_pda, "disputed").await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Bounty disputed".into() }))
+}
+
+/// POST /bounties/{bounty_pda}/release -- requester approval, or anyone once
+/// the review window has passed
+pub async fn release_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<BountyTxRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let bounty = load(&state, &path).await?;
+    if bounty.status != "fulfilled" {
+        return Err(ApiError::BadRequest("Bounty has not been fulfilled".into()));
+    }
+    let window_passed = review_ends_at(&bounty).is_some_and(|end| end <= Utc::now());
+    if bounty.requester_address != auth.wallet_address && !window_passed {
+        return Err(ApiError::Unauthorized("Only the requester can release before the review window ends".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+    db::bounties::set_status(&state.db, &bounty.bounty_pda, "released").await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Bounty released".into() }))
+}
+
+/// POST /bounties/{bounty_pda}/refund -- requester cancels an open bounty,
+/// or anyone refunds one that expired unfulfilled
+pub async fn refund_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<BountyTxRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let bounty = load(&state, &path).await?;
+    let expired = bounty.expires_at <= Utc::now();
+    let refundable = match bounty.status.as_str() {
+        "open" => bounty.requester_address == auth.wallet_address || expired,
+        "accepted" => expired,
+        _ => false,
+    };
+    if !refundable {
+        return Err(ApiError::BadRequest("Bounty cannot be refunded in its current state".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+    db::bounties::set_status(&state.db, &bounty.bounty_pda, "refunded").await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Bounty refunded".into() }))
+}
+
+/// POST /bounties/{bounty_pda}/resolve -- platform authority settles a dispute
+pub async fn resolve_bounty(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<ResolveBountyRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    if auth.wallet_address != body.authority_address {
+        return Err(ApiError::Unauthorized("Wallet does not match authority_address".into()));
+    }
+    let config = db::platform::get_config(&state.db)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Platform config not found".into()))?;
+    if config.authority_address != body.authority_address {
+        return Err(ApiError::Unauthorized("Not platform authority".into()));
+    }
+
+    let bounty = load(&state, &path).await?;
+    if bounty.status != "disputed" {
+        return Err(ApiError::BadRequest("Bounty is not disputed".into()));
+    }
+
+    confirm(&state, &body.tx_signature).await?;
+    let status = if body.release { "released" } else { "refunded" };
+    db::bounties::set_status(&state.db, &bounty.bounty_pda, status).await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: format!("Bounty dispute resolved: {}", status) }))
+}
+
+async fn load(state: &AppState, bounty_pda: &str) -> Result<Bounty, ApiError> {
+    db::bounties::find_by_pda(&state.db, bounty_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Bounty not found".into()))
+}
+
+async fn confirm(state: &AppState, tx_signature: &str) -> Result<(), ApiError> {
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, tx_signature)
+        .await
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".into()));
+    }
+    Ok(())
+}
+
+/// Same rule as Bounty::review_ends_at on-chain
+fn review_ends_at(b: &Bounty) -> Option<DateTime<Utc>> {
+    b.fulfilled_at.map(|t| t + Duration::seconds(crate::config::BOUNTY_REVIEW_WINDOW_SECONDS))
+}
+
+pub fn to_response(b: Bounty) -> BountyResponse {
+    BountyResponse {
+        review_ends_at: review_ends_at(&b).map(|t| t.timestamp()),
+        bounty_pda: b.bounty_pda,
+        profile_pda: b.profile_pda,
+        requester_address: b.requester_address,
+        creator_address: b.creator_address,
+        token_mint: b.token_mint,
+        amount: b.amount.to_string(),
+        description: b.description,
+        status: b.status,
+        expires_at: b.expires_at.timestamp(),
+        accepted_at: b.accepted_at.map(|t| t.timestamp()),
+        fulfilled_at: b.fulfilled_at.map(|t| t.timestamp()),
+        settled_at: b.settled_at.map(|t| t.timestamp()),
+        created_at: b.created_at.timestamp(),
+    }
+}
@@ -75,9 +75,10 @@ pub async fn verify_access(
     }
 
     // Check tip history: total tips from this user to this profile >= required_amount
-    let total_tipped: i64 = sqlx::query_scalar(
-        "SELECT COALESCE(SUM(amount_lamports), 0) FROM tips WHERE tipper_address = $1 AND recipient_profile_pda = $2",
-    )
+    let total_tipped: i64 = sqlx::query_scalar(&format!(
+        "SELECT COALESCE(SUM(amount_lamports), 0) FROM tips WHERE tipper_address = $1 AND recipient_profile_pda = $2 AND {}",
+        crate::db::tips::NOT_REFUNDED
+    ))
     .bind(&auth.wallet_address)
     .bind(&gate.profile_pda)
     .fetch_one(&state.db)
@@ -140,7 +140,21 @@ pub async fn contribute_goal(
         return Err(ApiError::BadRequest("Goal is already completed".to_string()));
     }
 
-    let new_amount = goal.current_amount + body.amount_lamports;
+    // Sponsor match: paid to the creator on-chain and counted toward the goal
+    let matched = match &body.matching_pool_pda {
+        Some(pool_pda) => {
+            let pool = db::matching_pools::find_by_pda(&state.db, pool_pda)
+                .await?
+                .ok_or_else(|| ApiError::NotFound("Matching pool not found".to_string()))?;
+            if pool.goal_pda.as_deref() != Some(goal_pda.as_str()) {
+                return Err(ApiError::BadRequest("Matching pool does not match this goal".to_string()));
+            }
+            db::matching_pools::apply_match(&state.db, &pool, &body.contributor_address, body.amount_lamports).await?
+        }
+        None => 0,
+    };
+
+    let new_amount = goal.current_amount + body.amount_lamports + matched;
     let is_completed = new_amount >= goal.target_amount;
 
     sqlx::query(
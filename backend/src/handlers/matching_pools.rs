@@ -0,0 +1,118 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use crate::error::ApiError;
+use crate::models::*;
+use crate::db;
+use crate::services;
+use crate::AppState;
+use crate::app_middleware::require_wallet_auth;
+
+/// POST /matching-pools -- record a create_matching_pool once confirmed
+pub async fn create_matching_pool(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Json<CreateMatchingPoolRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+
+    if body.match_ratio_bps <= 0 || body.match_ratio_bps > crate::config::MAX_MATCH_RATIO_BPS {
+        return Err(ApiError::BadRequest("Invalid match ratio".into()));
+    }
+    if body.per_tipper_cap <= 0 || body.per_tipper_cap > body.total_cap {
+        return Err(ApiError::BadRequest("Per-tipper cap must be positive and within the total cap".into()));
+    }
+    let now = chrono::Utc::now().timestamp();
+    if body.expires_at <= now || body.expires_at - now > crate::config::MAX_MATCHING_POOL_DURATION {
+        return Err(ApiError::BadRequest("Invalid pool expiry".into()));
+    }
+    let expires_at = chrono::DateTime::from_timestamp(body.expires_at, 0)
+        .ok_or_else(|| ApiError::BadRequest("Invalid pool expiry".into()))?;
+
+    db::profiles::find_by_pda(&state.db, &body.profile_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Profile not found".into()))?;
+    if let Some(goal_pda) = &body.goal_pda {
+        let goal = db::goals::find_by_pda(&state.db, goal_pda)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Goal not found".into()))?;
+        if goal.profile_pda != body.profile_pda {
+            return Err(ApiError::BadRequest("Goal does not belong to this profile".into()));
+        }
+        if goal.completed {
+            return Err(ApiError::BadRequest("Goal is already completed".into()));
+        }
+    }
+
+    if db::matching_pools::find_by_pda(&state.db, &body.pool_pda).await?.is_some() {
+        return Err(ApiError::BadRequest("Matching pool already recorded".into()));
+    }
+
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, &body.tx_signature)
+        .await
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".into()));
+    }
+
+    db::matching_pools::create(&state.db, &auth.wallet_address, &body, expires_at).await?;
+
+    Ok(HttpResponse::Created().json(TxResponse { success: true, message: "Matching pool created".into() }))
+}
+
+/// GET /matching-pools/{profile_pda} -- open pools for a profile
+pub async fn list_matching_pools(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let profile_pda = path.into_inner();
+    let pools = db::matching_pools::find_active_by_profile(&state.db, &profile_pda).await?;
+    let responses: Vec<MatchingPoolResponse> = pools.into_iter().map(to_response).collect();
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+/// POST /matching-pools/{pool_pda}/close -- record a close_matching_pool
+pub async fn close_matching_pool(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let pool_pda = path.into_inner();
+
+    let pool = db::matching_pools::find_by_pda(&state.db, &pool_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Matching pool not found".into()))?;
+    if pool.sponsor_address != auth.wallet_address {
+        return Err(ApiError::Unauthorized("Only the sponsor can close this pool".into()));
+    }
+    if pool.closed_at.is_some() {
+        return Err(ApiError::BadRequest("Matching pool already closed".into()));
+    }
+    if pool.expires_at > chrono::Utc::now() {
+        return Err(ApiError::BadRequest("Matching pool has not expired".into()));
+    }
+
+    db::matching_pools::close(&state.db, &pool_pda).await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Matching pool closed".into() }))
+}
+
+pub fn to_response(p: MatchingPool) -> MatchingPoolResponse {
+    let progress_pct = if p.total_cap > 0 {
+        (p.matched_amount as f64 / p.total_cap as f64 * 100.0).min(100.0)
+    } else {
+        0.0
+    };
+    MatchingPoolResponse {
+        pool_pda: p.pool_pda,
+        profile_pda: p.profile_pda,
+        goal_pda: p.goal_pda,
+        sponsor_address: p.sponsor_address,
+        match_ratio_bps: p.match_ratio_bps,
+        per_tipper_cap: p.per_tipper_cap.to_string(),
+        total_cap: p.total_cap.to_string(),
+        matched_amount: p.matched_amount.to_string(),
+        progress_pct,
+        expires_at: p.expires_at.timestamp(),
+        closed_at: p.closed_at.map(|t| t.timestamp()),
+    }
+}
@@ -1,12 +1,17 @@
 pub mod admin;
 pub mod analytics;
+pub mod blocklist;
 pub mod content_gates;
 pub mod goals;
+pub mod matching_pools;
+pub mod pinned_slots;
+pub mod bounties;
 pub mod health;
 pub mod polls;
 pub mod profiles;
 pub mod referrals;
 pub mod splits;
+pub mod subscription_tiers;
 pub mod subscriptions;
 pub mod tips;
 pub mod vault;
@@ -0,0 +1,83 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::Utc;
+use crate::error::ApiError;
+use crate::models::*;
+use crate::db;
+use crate::services;
+use crate::AppState;
+use crate::app_middleware::require_wallet_auth;
+
+/// POST /pinned-slot -- record a configure_pinned_slot once confirmed
+pub async fn configure_pinned_slot(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Json<ConfigurePinnedSlotRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+
+    if body.duration_seconds < crate::config::MIN_PIN_DURATION || body.duration_seconds > crate::config::MAX_PIN_DURATION {
+        return Err(ApiError::BadRequest(format!(
+            "Pin duration must be {}-{} seconds", crate::config::MIN_PIN_DURATION, crate::config::MAX_PIN_DURATION
+        )));
+    }
+    if body.min_bid < crate::config::MIN_TIP_AMOUNT {
+        return Err(ApiError::BadRequest("Minimum bid is below the minimum tip".into()));
+    }
+    if body.min_increment_bps < 0 || body.min_increment_bps > crate::config::MAX_PIN_INCREMENT_BPS {
+        return Err(ApiError::BadRequest("Invalid minimum increment".into()));
+    }
+
+    let profile = db::profiles::find_by_pda(&state.db, &body.profile_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Profile not found".into()))?;
+    if profile.owner_address != auth.wallet_address {
+        return Err(ApiError::Unauthorized("Only the profile owner can configure the pinned slot".into()));
+    }
+
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, &body.tx_signature)
+        .await
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".into()));
+    }
+
+    db::pinned_slots::upsert(&state.db, &body).await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Pinned slot configured".into() }))
+}
+
+/// GET /pinned-slot/{profile_pda} -- auction rules and the live pin
+pub async fn get_pinned_slot(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let profile_pda = path.into_inner();
+    let slot = db::pinned_slots::find_by_profile(&state.db, &profile_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Pinned slot not configured".into()))?;
+    Ok(HttpResponse::Ok().json(to_response(slot)))
+}
+
+pub fn to_response(s: PinnedSlot) -> PinnedSlotResponse {
+    let now = Utc::now();
+    let pinned = match (&s.tipper_address, s.pinned_at, s.expires_at) {
+        (Some(tipper), Some(pinned_at), Some(expires_at)) if expires_at > now => Some(PinnedMessageResponse {
+            tipper_address: tipper.clone(),
+            bid: s.bid.to_string(),
+            message: s.message.clone(),
+            pinned_at: pinned_at.timestamp(),
+            expires_at: expires_at.timestamp(),
+            remaining_seconds: (expires_at - now).num_seconds(),
+        }),
+        _ => None,
+    };
+    PinnedSlotResponse {
+        min_next_bid: db::pinned_slots::min_next_bid(&s, now).to_string(),
+        profile_pda: s.profile_pda,
+        duration_seconds: s.duration_seconds,
+        min_bid: s.min_bid.to_string(),
+        min_increment_bps: s.min_increment_bps,
+        total_pins: s.total_pins,
+        pinned,
+    }
+}
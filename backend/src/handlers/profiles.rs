@@ -32,7 +32,7 @@ pub async fn list_profiles(
     };
 
     let query_str = format!(
-        "SELECT * FROM profiles WHERE ($1 = '' OR username ILIKE concat('%%', $1, '%%') OR display_name ILIKE concat('%%', $1, '%%')) AND ($2 = false OR is_verified = true) ORDER BY {} LIMIT $3 OFFSET $4",
+        "SELECT * FROM profiles WHERE ($1 = '' OR username ILIKE concat('%%', $1, '%%') OR display_name ILIKE concat('%%', $1, '%%')) AND ($2 = false OR is_verified = true) AND closed_at IS NULL ORDER BY {} LIMIT $3 OFFSET $4",
         order_clause
     );
 
@@ -45,7 +45,7 @@ pub async fn list_profiles(
         .await?;
 
     let total: (i64,) = sqlx::query_as(
-        "SELECT COUNT(*) FROM profiles WHERE ($1 = '' OR username ILIKE concat('%%', $1, '%%') OR display_name ILIKE concat('%%', $1, '%%')) AND ($2 = false OR is_verified = true)"
+        "SELECT COUNT(*) FROM profiles WHERE ($1 = '' OR username ILIKE concat('%%', $1, '%%') OR display_name ILIKE concat('%%', $1, '%%')) AND ($2 = false OR is_verified = true) AND closed_at IS NULL"
     )
         .bind(&search)
         .bind(only_verified)
@@ -69,7 +69,7 @@ pub async fn get_profile(
 ) -> Result<HttpResponse, ApiError> {
     let address = path.into_inner();
     let profile: Option<Profile> = sqlx::query_as(
-        "SELECT * FROM profiles WHERE owner_address = $1 OR profile_pda = $1 OR username = $1"
+        "SELECT * FROM profiles WHERE owner_address = $1 OR profile_pda = $1 OR username = $1 ORDER BY closed_at IS NOT NULL LIMIT 1"
     )
         .bind(&address)
         .fetch_optional(&state.db)
@@ -100,20 +100,41 @@ pub async fn create_profile(
     services::solana::validate_address(&body.owner_address)
         .map_err(|e| ApiError::BadRequest(format!("Invalid owner_address: {}", e)))?;
 
-    if body.username.is_empty() || body.username.len() > crate::config::MAX_USERNAME_LENGTH {
-        return Err(ApiError::BadRequest("Invalid username length".to_string()));
-    }
+    validate_username(&body.username)?;
 
-    let existing: Option<(Uuid,)> = sqlx::query_as(
-        "SELECT id FROM profiles WHERE username = $1 OR owner_address = $2"
+    let existing: Vec<(Uuid, String, Option<chrono::DateTime<chrono::Utc>>)> = sqlx::query_as(
+        "SELECT id, owner_address, closed_at FROM profiles WHERE (username = $1 AND closed_at IS NULL) OR owner_address = $2"
     )
         .bind(&body.username)
         .bind(&body.owner_address)
-        .fetch_optional(&state.db)
+        .fetch_all(&state.db)
         .await?;
 
-    if existing.is_some() {
-        return Err(ApiError::BadRequest("Profile already exists for this user or username is taken".to_string()));
+    // An owner re-creating a profile they closed reuses their old row
+    let reopen = match existing.as_slice() {
+        [] => None,
+        [(id, owner, Some(_))] if *owner == body.owner_address => Some(*id),
+        _ => return Err(ApiError::BadRequest("Profile already exists for this user or username is taken".to_string())),
+    };
+
+    if let Some(id) = reopen {
+        sqlx::query(
+            "UPDATE profiles SET username = $2, display_name = $3, description = $4, image_url = $5, total_tips_received = 0, total_amount_received_lamports = 0, total_amount_received_spl = 0, total_unique_tippers = 0, active_goals_count = 0, active_polls_count = 0, active_gates_count = 0, min_tip_amount = 0, withdrawal_fee_bps = 0, accept_anonymous = true, is_verified = false, closed_at = NULL, created_at = NOW(), updated_at = NOW() WHERE id = $1"
+        )
+            .bind(id)
+            .bind(&body.username)
+            .bind(&body.display_name)
+            .bind(&body.description)
+            .bind(&body.image_url)
+            .execute(&state.db)
+            .await?;
+
+        let profile: Profile = sqlx::query_as("SELECT * FROM profiles WHERE id = $1")
+            .bind(id)
+            .fetch_one(&state.db)
+            .await?;
+
+        return Ok(HttpResponse::Created().json(profile_to_response(profile)));
     }
 
     let id = Uuid::new_v4();
@@ -189,6 +210,16 @@ pub async fn update_profile(
             )));
         }
     }
+    if body.min_media_tip_amount.is_some_and(|a| a < 0) {
+        return Err(ApiError::BadRequest("min_media_tip_amount cannot be negative".to_string()));
+    }
+    if let Some(key) = body.encryption_pubkey.as_deref().filter(|k| !k.is_empty()) {
+        services::encryption::decode_key(key)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid encryption_pubkey: {}", e)))?;
+    }
+    if body.refund_window_seconds.is_some_and(|s| !(0..=crate::config::MAX_REFUND_WINDOW_SECONDS).contains(&s)) {
+        return Err(ApiError::BadRequest("refund_window_seconds must be between 0 and 30 days".to_string()));
+    }
     if let Some(ref preset_amounts) = body.preset_amounts {
         if preset_amounts.len() > crate::config::MAX_PRESET_AMOUNTS {
             return Err(ApiError::BadRequest(format!(
@@ -217,9 +248,12 @@ pub async fn update_profile(
         .unwrap_or(profile.preset_amounts);
     let social_links = body.social_links.clone().unwrap_or(profile.social_links);
     let webhook_url = body.webhook_url.clone().unwrap_or(profile.webhook_url);
+    let min_media_tip_amount = body.min_media_tip_amount.unwrap_or(profile.min_media_tip_amount);
+    let encryption_pubkey = body.encryption_pubkey.clone().unwrap_or(profile.encryption_pubkey);
+    let refund_window_seconds = body.refund_window_seconds.unwrap_or(profile.refund_window_seconds);
 
     sqlx::query(
-        "UPDATE profiles SET display_name = $1, description = $2, image_url = $3, min_tip_amount = $4, withdrawal_fee_bps = $5, accept_anonymous = $6, preset_amounts = $8, social_links = $9, webhook_url = $10, updated_at = NOW() WHERE id = $7"
+        "UPDATE profiles SET display_name = $1, description = $2, image_url = $3, min_tip_amount = $4, withdrawal_fee_bps = $5, accept_anonymous = $6, preset_amounts = $8, social_links = $9, webhook_url = $10, min_media_tip_amount = $11, encryption_pubkey = $12, refund_window_seconds = $13, updated_at = NOW() WHERE id = $7"
     )
         .bind(&display_name)
         .bind(&description)
@@ -231,6 +265,9 @@ pub async fn update_profile(
         .bind(&preset_amounts)
         .bind(&social_links)
         .bind(&webhook_url)
+        .bind(min_media_tip_amount)
+        .bind(&encryption_pubkey)
+        .bind(refund_window_seconds)
         .execute(&state.db)
         .await?;
 
@@ -242,6 +279,123 @@ pub async fn update_profile(
     Ok(HttpResponse::Ok().json(profile_to_response(updated)))
 }
 
+/// Record a username change after the owner ran change_username on-chain.
+pub async fn change_username(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    body: web::Json<ChangeUsernameRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    let address = path.into_inner();
+
+    if auth.wallet_address != address {
+        return Err(ApiError::Unauthorized("Wallet does not match profile owner".to_string()));
+    }
+
+    validate_username(&body.username)?;
+
+    let profile: Option<Profile> = sqlx::query_as(
+        "SELECT * FROM profiles WHERE owner_address = $1 AND closed_at IS NULL"
+    )
+        .bind(&address)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let profile = profile.ok_or_else(|| ApiError::NotFound("Profile not found".to_string()))?;
+
+    if profile.username == body.username {
+        return Err(ApiError::BadRequest("New username must differ from the current one".to_string()));
+    }
+    if crate::db::profiles::exists_by_username(&state.db, &body.username).await? {
+        return Err(ApiError::BadRequest("Username is taken".to_string()));
+    }
+
+    sqlx::query("UPDATE profiles SET username = $1, updated_at = NOW() WHERE id = $2")
+        .bind(&body.username)
+        .bind(profile.id)
+        .execute(&state.db)
+        .await?;
+
+    let updated: Profile = sqlx::query_as("SELECT * FROM profiles WHERE id = $1")
+        .bind(profile.id)
+        .fetch_one(&state.db)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(profile_to_response(updated)))
+}
+
+/// Mark a profile closed after the owner ran close_profile on-chain.
+/// The on-chain vault, tip split and pinned slot are closed with it, so their rows
+/// are removed; tips and other history stay for the owner's records.
+pub async fn close_profile(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    let address = path.into_inner();
+
+    if auth.wallet_address != address {
+        return Err(ApiError::Unauthorized("Wallet does not match profile owner".to_string()));
+    }
+
+    let profile: Option<Profile> = sqlx::query_as(
+        "SELECT * FROM profiles WHERE owner_address = $1"
+    )
+        .bind(&address)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let profile = profile.ok_or_else(|| ApiError::NotFound("Profile not found".to_string()))?;
+
+    if profile.closed_at.is_some() {
+        return Err(ApiError::BadRequest("Profile is already closed".to_string()));
+    }
+    if profile.active_goals_count > 0 || profile.active_polls_count > 0 || profile.active_gates_count > 0 {
+        return Err(ApiError::BadRequest("Close all goals, polls and content gates first".to_string()));
+    }
+
+    let active_subscriptions: (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM subscriptions WHERE recipient_profile_pda = $1 AND is_active = true"
+    )
+        .bind(&profile.profile_pda)
+        .fetch_one(&state.db)
+        .await?;
+    if active_subscriptions.0 > 0 {
+        return Err(ApiError::BadRequest("Profile still has active subscribers".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| ApiError::Database(e.to_string()))?;
+
+    sqlx::query("DELETE FROM vaults WHERE profile_pda = $1")
+        .bind(&profile.profile_pda)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("DELETE FROM tip_splits WHERE profile_pda = $1")
+        .bind(&profile.profile_pda)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("DELETE FROM pinned_slots WHERE profile_pda = $1")
+        .bind(&profile.profile_pda)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("UPDATE profiles SET closed_at = NOW(), updated_at = NOW() WHERE id = $1")
+        .bind(profile.id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await.map_err(|e| ApiError::Database(e.to_string()))?;
+
+    Ok(HttpResponse::Ok().json(TxResponse {
+        success: true,
+        message: "Profile closed".to_string(),
+    }))
+}
+
 pub async fn get_leaderboard(
     state: web::Data<AppState>,
     path: web::Path<String>,
@@ -264,9 +418,10 @@ async fn get_profile_leaderboard(
     pool: &sqlx::PgPool,
     profile_pda: &str,
 ) -> Result<Vec<LeaderboardResponse>, ApiError> {
-    let entries: Vec<LeaderboardEntry> = sqlx::query_as(
-        "SELECT tipper_address, COALESCE(SUM(amount_lamports), 0)::bigint as total_amount, COUNT(*)::int as tip_count FROM tips WHERE recipient_profile_pda = $1 AND is_anonymous = false GROUP BY tipper_address ORDER BY total_amount DESC LIMIT 10"
-    )
+    let entries: Vec<LeaderboardEntry> = sqlx::query_as(&format!(
+        "SELECT tipper_address, COALESCE(SUM(amount_lamports), 0)::bigint as total_amount, COUNT(*)::int as tip_count FROM tips WHERE recipient_profile_pda = $1 AND is_anonymous = false AND {} GROUP BY tipper_address ORDER BY total_amount DESC LIMIT 10",
+        crate::db::tips::NOT_REFUNDED
+    ))
         .bind(profile_pda)
         .fetch_all(pool)
         .await?;
@@ -281,6 +436,17 @@ async fn get_profile_leaderboard(
         .collect())
 }
 
+/// Mirrors the on-chain `validate_username` rules.
+fn validate_username(username: &str) -> Result<(), ApiError> {
+    if username.is_empty() || username.len() > crate::config::MAX_USERNAME_LENGTH {
+        return Err(ApiError::BadRequest("Invalid username length".to_string()));
+    }
+    if !username.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') {
+        return Err(ApiError::BadRequest("Username may only contain a-z, 0-9 and _".to_string()));
+    }
+    Ok(())
+}
+
 fn profile_to_response(p: Profile) -> ProfileResponse {
     let preset_amounts: Vec<i64> = serde_json::from_value(p.preset_amounts.clone()).unwrap_or_default();
     ProfileResponse {
@@ -308,6 +474,10 @@ fn profile_to_response(p: Profile) -> ProfileResponse {
             webhook_url: p.webhook_url,
             active_polls_count: p.active_polls_count,
             active_gates_count: p.active_gates_count,
+            closed_at: p.closed_at.map(|d| d.timestamp()),
+            min_media_tip_amount: p.min_media_tip_amount.to_string(),
+            encryption_pubkey: p.encryption_pubkey,
+            refund_window_seconds: p.refund_window_seconds,
         },
     }
 }
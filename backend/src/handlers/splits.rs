@@ -4,6 +4,8 @@ use uuid::Uuid;
 use crate::app_middleware::require_wallet_auth;
 use crate::error::ApiError;
 use crate::models::*;
+use crate::db;
+use crate::services;
 use crate::AppState;
 
 pub async fn get_split(
@@ -22,11 +24,16 @@ pub async fn get_split(
     match split {
         Some(s) => {
             let recipients: Vec<SplitRecipient> = sqlx::query_as(
-                "SELECT * FROM split_recipients WHERE split_id = $1 ORDER BY share_bps DESC"
+                "SELECT * FROM split_recipients WHERE split_id = $1 ORDER BY position"
             )
                 .bind(s.id)
                 .fetch_all(&state.db)
                 .await?;
+            let balances = db::splits::find_claimable(&state.db, &profile_pda).await?;
+            let claimable_for = |wallet: &str| balances.iter()
+                .find(|(w, _)| w == wallet)
+                .map(|(_, c)| *c)
+                .unwrap_or(0);
 
             Ok(HttpResponse::Ok().json(TipSplitResponse {
                 public_key: s.split_pda.clone(),
@@ -34,11 +41,13 @@ pub async fn get_split(
                     profile: s.profile_pda,
                     num_recipients: recipients.len() as i32,
                     recipients: recipients.into_iter().map(|r| SplitRecipientResponse {
+                        claimable: claimable_for(&r.wallet_address).to_string(),
                         wallet: r.wallet_address,
                         share_bps: r.share_bps,
                         label: r.label,
                     }).collect(),
                     is_active: s.is_active,
+                    accrue: s.accrue,
                 },
             }))
         }
@@ -56,9 +65,15 @@ pub async fn configure_split(
         return Err(ApiError::Unauthorized("Wallet does not match owner_address".to_string()));
     }
 
-    if body.recipients.is_empty() || body.recipients.len() > crate::config::MAX_SPLIT_RECIPIENTS {
+    let accrue = body.accrue.unwrap_or(false);
+    let max_recipients = if accrue {
+        crate::config::MAX_ACCRUED_SPLIT_RECIPIENTS
+    } else {
+        crate::config::MAX_SPLIT_RECIPIENTS
+    };
+    if body.recipients.is_empty() || body.recipients.len() > max_recipients {
         return Err(ApiError::BadRequest(
-            format!("Recipients must be between 1 and {}", crate::config::MAX_SPLIT_RECIPIENTS)
+            format!("Recipients must be between 1 and {}", max_recipients)
         ));
     }
 
@@ -78,6 +93,12 @@ pub async fn configure_split(
         .ok_or_else(|| ApiError::NotFound("Profile not found".to_string()))?
         .0;
 
+    // Same rule as TipSplit::update: a wallet with an unclaimed balance stays
+    let balances = db::splits::find_claimable(&state.db, &profile_pda).await?;
+    if balances.iter().any(|(wallet, _)| !body.recipients.iter().any(|r| &r.wallet == wallet)) {
+        return Err(ApiError::BadRequest("Cannot remove a recipient with an unclaimed balance".to_string()));
+    }
+
     // BE-12: Wrap delete+insert in a database transaction to prevent race conditions
     let mut tx = state.db.begin().await.map_err(|e| ApiError::Database(e.to_string()))?;
 
@@ -104,24 +125,26 @@ pub async fn configure_split(
     let split_pda = format!("split_{}", &profile_pda[..8.min(profile_pda.len())]);
 
     sqlx::query(
-        "INSERT INTO tip_splits (id, split_pda, profile_pda, is_active, created_at) VALUES ($1, $2, $3, true, NOW())"
+        "INSERT INTO tip_splits (id, split_pda, profile_pda, is_active, accrue, created_at) VALUES ($1, $2, $3, true, $4, NOW())"
     )
         .bind(split_id)
         .bind(&split_pda)
         .bind(&profile_pda)
+        .bind(accrue)
         .execute(&mut *tx)
         .await?;
 
-    for recipient in &body.recipients {
+    for (position, recipient) in body.recipients.iter().enumerate() {
         let rid = Uuid::new_v4();
         sqlx::query(
-            "INSERT INTO split_recipients (id, split_id, wallet_address, share_bps, label) VALUES ($1, $2, $3, $4, $5)"
+            "INSERT INTO split_recipients (id, split_id, wallet_address, share_bps, label, position) VALUES ($1, $2, $3, $4, $5, $6)"
         )
             .bind(rid)
             .bind(split_id)
             .bind(&recipient.wallet)
             .bind(recipient.share_bps)
             .bind(&recipient.label)
+            .bind(position as i32)
             .execute(&mut *tx)
             .await?;
     }
@@ -133,3 +156,30 @@ pub async fn configure_split(
         message: "Split configuration saved".to_string(),
     }))
 }
+
+/// POST /splits/{profile_pda}/claim -- record a claim_split_share
+pub async fn claim_split_share(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    body: web::Json<ClaimSplitShareRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    let profile_pda = path.into_inner();
+
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, &body.tx_signature)
+        .await
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".to_string()));
+    }
+
+    let claimed = db::splits::claim(&state.db, &profile_pda, &auth.wallet_address)
+        .await?
+        .ok_or_else(|| ApiError::BadRequest("No split balance to claim".to_string()))?;
+
+    Ok(HttpResponse::Ok().json(TxResponse {
+        success: true,
+        message: format!("Claimed {} lamports", claimed),
+    }))
+}
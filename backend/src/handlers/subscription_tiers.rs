@@ -0,0 +1,156 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use crate::error::ApiError;
+use crate::models::*;
+use crate::db;
+use crate::services;
+use crate::AppState;
+use crate::app_middleware::require_wallet_auth;
+
+/// Mirrors the on-chain limits in SubscriptionTier
+const MAX_TIER_NAME_LENGTH: usize = 32;
+const MIN_TIER_INTERVAL_SECONDS: i64 = 86_400;
+
+/// POST /subscription-tiers -- create a tier on the caller's profile
+pub async fn create_tier(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Json<CreateTierRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let b = body.into_inner();
+
+    validate_terms(Some(&b.name), Some(b.price), Some(b.interval_seconds), b.max_members)?;
+    if let Some(mint) = b.token_mint.as_deref() {
+        services::solana::validate_address(mint)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid token_mint: {}", e)))?;
+    }
+
+    let profile = db::profiles::find_by_address(&state.db, &auth.wallet_address)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Profile not found".into()))?;
+
+    let tier_pda = format!("tier_{}_{}", &profile.profile_pda[..8.min(profile.profile_pda.len())], b.tier_id);
+    db::subscription_tiers::create_tier(&state.db, &tier_pda, &profile.profile_pda, &b).await?;
+
+    let tier = db::subscription_tiers::find_tier_by_pda(&state.db, &tier_pda)
+        .await?
+        .ok_or_else(|| ApiError::Internal("Tier not found after insert".into()))?;
+    Ok(HttpResponse::Created().json(to_tier_response(tier)))
+}
+
+/// GET /subscription-tiers/{profile_pda} -- list tiers with member counts
+pub async fn list_tiers(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    query: web::Query<TierListQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let profile_pda = path.into_inner();
+    let active_only = query.active_only.unwrap_or(true);
+    let tiers = db::subscription_tiers::find_tiers_by_profile(&state.db, &profile_pda, active_only).await?;
+    let responses: Vec<TierResponse> = tiers.into_iter().map(to_tier_response).collect();
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+/// PUT /subscription-tiers/{tier_pda} -- update tier terms (owner only)
+pub async fn update_tier(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<UpdateTierRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let tier_pda = path.into_inner();
+    let b = body.into_inner();
+
+    let tier = find_owned_tier(&state, &tier_pda, &auth.wallet_address).await?;
+    if !tier.is_active {
+        return Err(ApiError::BadRequest("Tier is retired".into()));
+    }
+    validate_terms(b.name.as_deref(), b.price, b.interval_seconds, b.max_members)?;
+
+    db::subscription_tiers::update_tier(&state.db, &tier_pda, &b).await?;
+
+    let tier = db::subscription_tiers::find_tier_by_pda(&state.db, &tier_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Tier not found".into()))?;
+    Ok(HttpResponse::Ok().json(to_tier_response(tier)))
+}
+
+/// POST /subscription-tiers/{tier_pda}/retire -- stop accepting new members
+pub async fn retire_tier(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Auth required".into()))?;
+    let tier_pda = path.into_inner();
+
+    let tier = find_owned_tier(&state, &tier_pda, &auth.wallet_address).await?;
+    if !tier.is_active {
+        return Err(ApiError::BadRequest("Tier is already retired".into()));
+    }
+
+    db::subscription_tiers::retire_tier(&state.db, &tier_pda).await?;
+
+    Ok(HttpResponse::Ok().json(TxResponse { success: true, message: "Tier retired".into() }))
+}
+
+async fn find_owned_tier(
+    state: &web::Data<AppState>,
+    tier_pda: &str,
+    wallet: &str,
+) -> Result<SubscriptionTier, ApiError> {
+    let tier = db::subscription_tiers::find_tier_by_pda(&state.db, tier_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Tier not found".into()))?;
+
+    let profile = db::profiles::find_by_pda(&state.db, &tier.profile_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Profile not found".into()))?;
+
+    if profile.owner_address != wallet {
+        return Err(ApiError::Unauthorized("Not tier owner".into()));
+    }
+    Ok(tier)
+}
+
+fn validate_terms(
+    name: Option<&str>,
+    price: Option<i64>,
+    interval_seconds: Option<i64>,
+    max_members: Option<i32>,
+) -> Result<(), ApiError> {
+    if let Some(name) = name {
+        if name.is_empty() || name.len() > MAX_TIER_NAME_LENGTH {
+            return Err(ApiError::BadRequest("Tier name must be 1-32 characters".into()));
+        }
+    }
+    if price.is_some_and(|p| p <= 0) {
+        return Err(ApiError::BadRequest("Price must be positive".into()));
+    }
+    if interval_seconds.is_some_and(|i| i < MIN_TIER_INTERVAL_SECONDS) {
+        return Err(ApiError::BadRequest("Interval must be at least 1 day".into()));
+    }
+    if max_members.is_some_and(|m| m < 0) {
+        return Err(ApiError::BadRequest("max_members cannot be negative".into()));
+    }
+    Ok(())
+}
+
+fn to_tier_response(t: SubscriptionTier) -> TierResponse {
+    TierResponse {
+        public_key: t.tier_pda,
+        profile_pda: t.profile_pda,
+        tier_id: t.tier_id,
+        name: t.name,
+        price: t.price.to_string(),
+        interval_seconds: t.interval_seconds,
+        token_mint: t.token_mint,
+        max_members: t.max_members,
+        member_count: t.member_count,
+        benefits_hash: t.benefits_hash,
+        is_active: t.is_active,
+        created_at: t.created_at.timestamp(),
+        updated_at: t.updated_at.timestamp(),
+    }
+}
@@ -29,13 +29,6 @@ pub async fn create_subscription(
     services::solana::validate_address(&body.recipient_address)
         .map_err(|e| ApiError::BadRequest(format!("Invalid recipient_address: {}", e)))?;
 
-    if body.amount_per_interval <= 0 {
-        return Err(ApiError::BadRequest("Amount must be positive".to_string()));
-    }
-    if body.interval_seconds <= 0 {
-        return Err(ApiError::BadRequest("Interval must be positive".to_string()));
-    }
-
     let profile: Option<(String,)> = sqlx::query_as(
         "SELECT profile_pda FROM profiles WHERE owner_address = $1"
     )
@@ -47,23 +40,39 @@ pub async fn create_subscription(
         .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?
         .0;
 
+    // Price, interval and mint come from the creator's tier
+    let tier = db::subscription_tiers::find_tier_by_pda(&state.db, &body.tier_pda)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Subscription tier not found".to_string()))?;
+    if tier.profile_pda != profile_pda {
+        return Err(ApiError::BadRequest("Tier does not belong to recipient".to_string()));
+    }
+    if !tier.is_active {
+        return Err(ApiError::BadRequest("Subscription tier is retired".to_string()));
+    }
+    if tier.max_members > 0 && tier.member_count >= tier.max_members as i64 {
+        return Err(ApiError::BadRequest("Subscription tier is full".to_string()));
+    }
+    let is_spl = !tier.token_mint.is_empty();
+
     let id = Uuid::new_v4();
     let subscription_pda = format!("sub_{}_{}", &body.subscriber_address[..8.min(body.subscriber_address.len())], id.to_string().split('-').next().unwrap_or("x"));
 
-    let next_payment = chrono::Utc::now() + chrono::Duration::seconds(body.interval_seconds);
+    let next_payment = chrono::Utc::now() + chrono::Duration::seconds(tier.interval_seconds);
 
     sqlx::query(
-        "INSERT INTO subscriptions (id, subscription_pda, subscriber_address, recipient_profile_pda, amount_per_interval, interval_seconds, next_payment_due, auto_renew, total_paid, payment_count, is_active, is_spl, token_mint, created_at, last_payment_at) VALUES ($1, $2, $3, $4, $5, $6, $7, true, $5, 1, true, $8, $9, NOW(), NOW())"
+        "INSERT INTO subscriptions (id, subscription_pda, subscriber_address, recipient_profile_pda, amount_per_interval, interval_seconds, next_payment_due, auto_renew, total_paid, payment_count, is_active, is_spl, token_mint, created_at, last_payment_at, tier_pda) VALUES ($1, $2, $3, $4, $5, $6, $7, true, $5, 1, true, $8, $9, NOW(), NOW(), $10)"
     )
         .bind(id)
         .bind(&subscription_pda)
         .bind(&body.subscriber_address)
         .bind(&profile_pda)
-        .bind(body.amount_per_interval)
-        .bind(body.interval_seconds)
+        .bind(tier.price)
+        .bind(tier.interval_seconds)
         .bind(next_payment)
-        .bind(body.is_spl)
-        .bind(&body.token_mint)
+        .bind(is_spl)
+        .bind(&tier.token_mint)
+        .bind(&tier.tier_pda)
         .execute(&state.db)
         .await?;
 
@@ -93,6 +102,16 @@ pub async fn get_by_subscriber(
     Ok(HttpResponse::Ok().json(items))
 }
 
+/// Active auto-renewing subscriptions whose next payment is due.
+/// The keeper job polls this and submits `crank_subscription` for each.
+pub async fn get_due(
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let subs = db::subscriptions::find_due_subscriptions(&state.db).await?;
+    let items: Vec<SubscriptionResponse> = subs.into_iter().map(subscription_to_response).collect();
+    Ok(HttpResponse::Ok().json(items))
+}
+
 pub async fn cancel_subscription(
     req: HttpRequest,
     state: web::Data<AppState>,
@@ -147,6 +166,7 @@ fn subscription_to_response(s: Subscription) -> SubscriptionResponse {
             token_mint: s.token_mint,
             created_at: s.created_at.timestamp(),
             last_payment_at: s.last_payment_at.map(|d| d.timestamp()),
+            tier: s.tier_pda,
         },
     }
 }
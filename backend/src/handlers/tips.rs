@@ -1,4 +1,5 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::app_middleware::require_wallet_auth;
@@ -47,22 +48,76 @@ pub async fn record_tip(
         return Err(ApiError::BadRequest("Transaction not confirmed on-chain".to_string()));
     }
 
-    let profile: Option<(String,)> = sqlx::query_as(
-        "SELECT profile_pda FROM profiles WHERE owner_address = $1"
+    let profile: Option<(String, i64, String)> = sqlx::query_as(
+        "SELECT profile_pda, min_media_tip_amount, encryption_pubkey FROM profiles WHERE owner_address = $1"
     )
         .bind(&body.recipient_address)
         .fetch_optional(&state.db)
         .await?;
 
-    let profile_pda = profile
-        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?
-        .0;
+    let (profile_pda, min_media_tip_amount, encryption_pubkey) = profile
+        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?;
+    validate_media(body.media.as_ref(), Some(body.amount_lamports), min_media_tip_amount)?;
+    validate_encrypted_message(body.encrypted_message.as_ref(), body.message.is_some(), &encryption_pubkey)?;
+
+    // Sponsor match: paid into the vault on-chain alongside the tip. The
+    // amount comes from the tx's MatchedEvent; the pool's state here may lag.
+    let matched = body.matched_amount.unwrap_or(0);
+    if matched < 0 {
+        return Err(ApiError::BadRequest("Matched amount cannot be negative".to_string()));
+    }
+    match &body.matching_pool_pda {
+        Some(pool_pda) => {
+            let pool = db::matching_pools::find_by_pda(&state.db, pool_pda)
+                .await?
+                .ok_or_else(|| ApiError::NotFound("Matching pool not found".to_string()))?;
+            if pool.profile_pda != profile_pda || pool.goal_pda.is_some() {
+                return Err(ApiError::BadRequest("Matching pool does not match this profile's tips".to_string()));
+            }
+            if matched > body.amount_lamports.saturating_mul(pool.match_ratio_bps as i64) / 10_000 {
+                return Err(ApiError::BadRequest("Matched amount exceeds the pool's ratio".to_string()));
+            }
+        }
+        None if matched > 0 => {
+            return Err(ApiError::BadRequest("Matched amount needs a matching pool".to_string()));
+        }
+        None => {}
+    }
+
+    // Pinned slot: times come from the tx's TipPinnedEvent, not this server's clock
+    let pin = match &body.pin {
+        Some(pin) => {
+            let message = body.message.as_deref()
+                .ok_or_else(|| ApiError::BadRequest("Pinned tips need a message".to_string()))?;
+            let slot = db::pinned_slots::find_by_profile(&state.db, &profile_pda)
+                .await?
+                .ok_or_else(|| ApiError::NotFound("Pinned slot not configured".to_string()))?;
+            let pinned_at = DateTime::<Utc>::from_timestamp(pin.timestamp, 0)
+                .ok_or_else(|| ApiError::BadRequest("Invalid pin timestamp".to_string()))?;
+            let expires_at = DateTime::<Utc>::from_timestamp(pin.expires_at, 0)
+                .filter(|t| *t > pinned_at)
+                .ok_or_else(|| ApiError::BadRequest("Invalid pin expires_at".to_string()))?;
+            Some((slot, message, pinned_at, expires_at))
+        }
+        None => None,
+    };
 
     let id = Uuid::new_v4();
     let is_anonymous = body.is_anonymous.unwrap_or(false);
 
+    // The tip, its match and its pin succeeded or failed together on-chain; record them the same way
+    let mut tx = state.db.begin().await.map_err(|e| ApiError::Database(e.to_string()))?;
+
+    let (earlier,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM tips WHERE recipient_profile_pda = $1 AND tipper_address = $2"
+    )
+        .bind(&profile_pda)
+        .bind(&body.tipper_address)
+        .fetch_one(&mut *tx)
+        .await?;
+
     sqlx::query(
-        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'sol', NULL, $7, $8, NOW())"
+        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, media_kind, media_url, media_start_seconds, encrypted_message, matched_amount, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'sol', NULL, $7, $8, $9, $10, $11, $12, $13, NOW())"
     )
         .bind(id)
         .bind(&body.tx_signature)
@@ -72,41 +127,148 @@ pub async fn record_tip(
         .bind(body.amount_lamports)
         .bind(&body.message)
         .bind(is_anonymous)
-        .execute(&state.db)
+        .bind(body.media.as_ref().map(|m| m.kind.clone()))
+        .bind(body.media.as_ref().map(|m| m.url.clone()))
+        .bind(body.media.as_ref().map(|m| m.start_seconds))
+        .bind(body.encrypted_message.as_ref().map(|m| serde_json::json!(m)))
+        .bind(matched)
+        .execute(&mut *tx)
         .await?;
 
-    // Update profile stats
+    // Update profile stats and unique tippers count; the match counts as received
     sqlx::query(
-        "UPDATE profiles SET total_tips_received = total_tips_received + 1, total_amount_received_lamports = total_amount_received_lamports + $1, updated_at = NOW() WHERE profile_pda = $2"
+        "UPDATE profiles SET total_tips_received = total_tips_received + 1, total_amount_received_lamports = total_amount_received_lamports + $1, total_unique_tippers = total_unique_tippers + $2, updated_at = NOW() WHERE profile_pda = $3"
     )
-        .bind(body.amount_lamports)
+        .bind(body.amount_lamports + matched)
+        .bind(if earlier == 0 { 1 } else { 0 })
         .bind(&profile_pda)
-        .execute(&state.db)
+        .execute(&mut *tx)
         .await?;
 
-    // Update unique tippers count
-    let existing_tipper: Option<(i64,)> = sqlx::query_as(
-        "SELECT COUNT(*) FROM tips WHERE recipient_profile_pda = $1 AND tipper_address = $2 AND id != $3"
-    )
-        .bind(&profile_pda)
-        .bind(&body.tipper_address)
-        .bind(id)
-        .fetch_one(&state.db)
+    if let (true, Some(pool_pda)) = (matched > 0, &body.matching_pool_pda) {
+        db::matching_pools::record_match(&mut tx, pool_pda, &body.tipper_address, matched).await?;
+    }
+
+    if let Some((slot, message, pinned_at, expires_at)) = &pin {
+        db::pinned_slots::apply_pin(&mut tx, slot, &body.tipper_address, body.amount_lamports, message, *pinned_at, *expires_at).await?;
+    }
+
+    tx.commit().await.map_err(|e| ApiError::Database(e.to_string()))?;
+
+    Ok(HttpResponse::Created().json(TxResponse {
+        success: true,
+        message: "Tip recorded successfully".to_string(),
+    }))
+}
+
+/// Mirrors send_tip_batch: one confirmed transaction, one tip row per
+/// creator, told apart by batch_index
+pub async fn record_tip_batch(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<SendTipBatchRequest>,
+) -> Result<HttpResponse, ApiError> {
+    // BE-26: Check platform pause
+    if db::platform::is_paused(&state.db).await? {
+        return Err(ApiError::BadRequest("Platform is currently paused".to_string()));
+    }
+
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    if auth.wallet_address != body.tipper_address {
+        return Err(ApiError::Unauthorized("Wallet does not match tipper_address".to_string()));
+    }
+
+    // BE-09: Validate Solana addresses
+    services::solana::validate_address(&body.tipper_address)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid tipper_address: {}", e)))?;
+
+    if body.tips.is_empty() || body.tips.len() > crate::config::MAX_BATCH_TIPS {
+        return Err(ApiError::BadRequest(format!(
+            "A batch holds 1 to {} tips", crate::config::MAX_BATCH_TIPS
+        )));
+    }
+    for (i, tip) in body.tips.iter().enumerate() {
+        services::solana::validate_address(&tip.recipient_address)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid recipient_address: {}", e)))?;
+        if tip.amount_lamports <= 0 {
+            return Err(ApiError::BadRequest("Amount must be positive".to_string()));
+        }
+        // Same rule as send_tip_batch: each creator once
+        if body.tips[..i].iter().any(|t| t.recipient_address == tip.recipient_address) {
+            return Err(ApiError::BadRequest("Each creator can appear only once per batch".to_string()));
+        }
+    }
+
+    // BE-18: Check duplicate tx_signature
+    let existing_tip = db::tips::find_by_tx(&state.db, &body.tx_signature).await?;
+    if existing_tip.is_some() {
+        return Err(ApiError::BadRequest("Transaction already recorded".to_string()));
+    }
+
+    // BE-01: Verify transaction on-chain
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, &body.tx_signature)
         .await
-        .ok();
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".to_string()));
+    }
+
+    let mut profile_pdas = Vec::with_capacity(body.tips.len());
+    for tip in &body.tips {
+        let profile: Option<(String,)> = sqlx::query_as(
+            "SELECT profile_pda FROM profiles WHERE owner_address = $1"
+        )
+            .bind(&tip.recipient_address)
+            .fetch_optional(&state.db)
+            .await?;
+        let (profile_pda,) = profile
+            .ok_or_else(|| ApiError::NotFound(format!("Recipient profile not found: {}", tip.recipient_address)))?;
+        profile_pdas.push(profile_pda);
+    }
+
+    // The batch succeeded or failed as a whole on-chain; record it the same way
+    let mut tx = state.db.begin().await.map_err(|e| ApiError::Database(e.to_string()))?;
+
+    for (i, (tip, profile_pda)) in body.tips.iter().zip(&profile_pdas).enumerate() {
+        let (earlier,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM tips WHERE recipient_profile_pda = $1 AND tipper_address = $2"
+        )
+            .bind(profile_pda)
+            .bind(&body.tipper_address)
+            .fetch_one(&mut *tx)
+            .await?;
 
-    if existing_tipper.map(|c| c.0).unwrap_or(0) == 0 {
         sqlx::query(
-            "UPDATE profiles SET total_unique_tippers = total_unique_tippers + 1 WHERE profile_pda = $1"
+            "INSERT INTO tips (id, tx_signature, batch_index, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, 'sol', NULL, $8, $9, NOW())"
         )
-            .bind(&profile_pda)
-            .execute(&state.db)
+            .bind(Uuid::new_v4())
+            .bind(&body.tx_signature)
+            .bind(i as i32)
+            .bind(&body.tipper_address)
+            .bind(&tip.recipient_address)
+            .bind(profile_pda)
+            .bind(tip.amount_lamports)
+            .bind(&tip.message)
+            .bind(tip.is_anonymous.unwrap_or(false))
+            .execute(&mut *tx)
+            .await?;
+
+        // Update profile stats and unique tippers count
+        sqlx::query(
+            "UPDATE profiles SET total_tips_received = total_tips_received + 1, total_amount_received_lamports = total_amount_received_lamports + $1, total_unique_tippers = total_unique_tippers + $2, updated_at = NOW() WHERE profile_pda = $3"
+        )
+            .bind(tip.amount_lamports)
+            .bind(if earlier == 0 { 1 } else { 0 })
+            .bind(profile_pda)
+            .execute(&mut *tx)
             .await?;
     }
 
+    tx.commit().await.map_err(|e| ApiError::Database(e.to_string()))?;
+
     Ok(HttpResponse::Created().json(TxResponse {
         success: true,
-        message: "Tip recorded successfully".to_string(),
+        message: format!("Batch of {} tips recorded successfully", body.tips.len()),
     }))
 }
 
@@ -151,22 +313,23 @@ pub async fn record_tip_spl(
         return Err(ApiError::BadRequest("Transaction not confirmed on-chain".to_string()));
     }
 
-    let profile: Option<(String,)> = sqlx::query_as(
-        "SELECT profile_pda FROM profiles WHERE owner_address = $1"
+    let profile: Option<(String, i64, String)> = sqlx::query_as(
+        "SELECT profile_pda, min_media_tip_amount, encryption_pubkey FROM profiles WHERE owner_address = $1"
     )
         .bind(&body.recipient_address)
         .fetch_optional(&state.db)
         .await?;
 
-    let profile_pda = profile
-        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?
-        .0;
+    let (profile_pda, min_media_tip_amount, encryption_pubkey) = profile
+        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?;
+    validate_media(body.media.as_ref(), None, min_media_tip_amount)?;
+    validate_encrypted_message(body.encrypted_message.as_ref(), body.message.is_some(), &encryption_pubkey)?;
 
     let id = Uuid::new_v4();
     let is_anonymous = body.is_anonymous.unwrap_or(false);
 
     sqlx::query(
-        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'spl', $7, $8, $9, NOW())"
+        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, media_kind, media_url, media_start_seconds, encrypted_message, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'spl', $7, $8, $9, $10, $11, $12, $13, NOW())"
     )
         .bind(id)
         .bind(&body.tx_signature)
@@ -177,6 +340,10 @@ pub async fn record_tip_spl(
         .bind(&body.token_mint)
         .bind(&body.message)
         .bind(is_anonymous)
+        .bind(body.media.as_ref().map(|m| m.kind.clone()))
+        .bind(body.media.as_ref().map(|m| m.url.clone()))
+        .bind(body.media.as_ref().map(|m| m.start_seconds))
+        .bind(body.encrypted_message.as_ref().map(|m| serde_json::json!(m)))
         .execute(&state.db)
         .await?;
 
@@ -230,22 +397,23 @@ pub async fn record_tip_split(
     }
 
     // For split tips, record similarly but mark as split type
-    let profile: Option<(String,)> = sqlx::query_as(
-        "SELECT profile_pda FROM profiles WHERE owner_address = $1"
+    let profile: Option<(String, i64, String)> = sqlx::query_as(
+        "SELECT profile_pda, min_media_tip_amount, encryption_pubkey FROM profiles WHERE owner_address = $1"
     )
         .bind(&body.recipient_address)
         .fetch_optional(&state.db)
         .await?;
 
-    let profile_pda = profile
-        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?
-        .0;
+    let (profile_pda, min_media_tip_amount, encryption_pubkey) = profile
+        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?;
+    validate_media(body.media.as_ref(), Some(body.amount_lamports), min_media_tip_amount)?;
+    validate_encrypted_message(body.encrypted_message.as_ref(), body.message.is_some(), &encryption_pubkey)?;
 
     let id = Uuid::new_v4();
     let is_anonymous = body.is_anonymous.unwrap_or(false);
 
     sqlx::query(
-        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'split', NULL, $7, $8, NOW())"
+        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, media_kind, media_url, media_start_seconds, encrypted_message, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'split', NULL, $7, $8, $9, $10, $11, $12, NOW())"
     )
         .bind(id)
         .bind(&body.tx_signature)
@@ -255,15 +423,208 @@ pub async fn record_tip_split(
         .bind(body.amount_lamports)
         .bind(&body.message)
         .bind(is_anonymous)
+        .bind(body.media.as_ref().map(|m| m.kind.clone()))
+        .bind(body.media.as_ref().map(|m| m.url.clone()))
+        .bind(body.media.as_ref().map(|m| m.start_seconds))
+        .bind(body.encrypted_message.as_ref().map(|m| serde_json::json!(m)))
         .execute(&state.db)
         .await?;
 
+    // Accrual mode: the tip sits in the vault until each recipient claims
+    if let Some(split) = db::splits::find_by_profile(&state.db, &profile_pda).await? {
+        if split.accrue {
+            let recipients = db::splits::find_recipients(&state.db, split.id).await?;
+            db::splits::credit_shares(&state.db, &profile_pda, &recipients, body.amount_lamports).await?;
+        }
+    }
+
     Ok(HttpResponse::Created().json(TxResponse {
         success: true,
         message: "Split tip recorded successfully".to_string(),
     }))
 }
 
+pub async fn record_tip_split_spl(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    body: web::Json<SendTipSplRequest>,
+) -> Result<HttpResponse, ApiError> {
+    // BE-26: Check platform pause
+    if db::platform::is_paused(&state.db).await? {
+        return Err(ApiError::BadRequest("Platform is currently paused".to_string()));
+    }
+
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+    if auth.wallet_address != body.tipper_address {
+        return Err(ApiError::Unauthorized("Wallet does not match tipper_address".to_string()));
+    }
+
+    // BE-09: Validate Solana addresses
+    services::solana::validate_address(&body.tipper_address)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid tipper_address: {}", e)))?;
+    services::solana::validate_address(&body.recipient_address)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid recipient_address: {}", e)))?;
+    services::solana::validate_address(&body.token_mint)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid token_mint: {}", e)))?;
+
+    if body.amount <= 0 {
+        return Err(ApiError::BadRequest("Amount must be positive".to_string()));
+    }
+
+    // BE-18: Check duplicate tx_signature
+    let existing_tip = db::tips::find_by_tx(&state.db, &body.tx_signature).await?;
+    if existing_tip.is_some() {
+        return Err(ApiError::BadRequest("Transaction already recorded".to_string()));
+    }
+
+    // BE-01: Verify transaction on-chain
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, &body.tx_signature)
+        .await
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".to_string()));
+    }
+
+    // Shares went straight to the split recipients' token accounts
+    let profile: Option<(String, i64, String)> = sqlx::query_as(
+        "SELECT profile_pda, min_media_tip_amount, encryption_pubkey FROM profiles WHERE owner_address = $1"
+    )
+        .bind(&body.recipient_address)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let (profile_pda, min_media_tip_amount, encryption_pubkey) = profile
+        .ok_or_else(|| ApiError::NotFound("Recipient profile not found".to_string()))?;
+    validate_media(body.media.as_ref(), None, min_media_tip_amount)?;
+    validate_encrypted_message(body.encrypted_message.as_ref(), body.message.is_some(), &encryption_pubkey)?;
+
+    let id = Uuid::new_v4();
+    let is_anonymous = body.is_anonymous.unwrap_or(false);
+
+    sqlx::query(
+        "INSERT INTO tips (id, tx_signature, tipper_address, recipient_address, recipient_profile_pda, amount_lamports, tip_type, token_mint, message, is_anonymous, media_kind, media_url, media_start_seconds, encrypted_message, created_at) VALUES ($1, $2, $3, $4, $5, $6, 'split_spl', $7, $8, $9, $10, $11, $12, $13, NOW())"
+    )
+        .bind(id)
+        .bind(&body.tx_signature)
+        .bind(&body.tipper_address)
+        .bind(&body.recipient_address)
+        .bind(&profile_pda)
+        .bind(body.amount)
+        .bind(&body.token_mint)
+        .bind(&body.message)
+        .bind(is_anonymous)
+        .bind(body.media.as_ref().map(|m| m.kind.clone()))
+        .bind(body.media.as_ref().map(|m| m.url.clone()))
+        .bind(body.media.as_ref().map(|m| m.start_seconds))
+        .bind(body.encrypted_message.as_ref().map(|m| serde_json::json!(m)))
+        .execute(&state.db)
+        .await?;
+
+    sqlx::query(
+        "UPDATE profiles SET total_tips_received = total_tips_received + 1, total_amount_received_spl = total_amount_received_spl + $1, updated_at = NOW() WHERE profile_pda = $2"
+    )
+        .bind(body.amount)
+        .bind(&profile_pda)
+        .execute(&state.db)
+        .await?;
+
+    Ok(HttpResponse::Created().json(TxResponse {
+        success: true,
+        message: "SPL split tip recorded successfully".to_string(),
+    }))
+}
+
+/// Mirrors refund_tip once confirmed on-chain. Refunds are recorded per tip;
+/// the tip stays in the history but leaves the profile stats.
+pub async fn record_refund(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    body: web::Json<RefundTipRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let tx_signature = path.into_inner();
+    let auth = require_wallet_auth(&req).map_err(|_| ApiError::Unauthorized("Wallet auth required".to_string()))?;
+
+    // A batch transaction holds one tip per creator; pick the caller's
+    let tip = match db::tips::find_by_tx_for_recipient(&state.db, &tx_signature, &auth.wallet_address).await? {
+        Some(tip) => tip,
+        None if db::tips::find_by_tx(&state.db, &tx_signature).await?.is_some() => {
+            return Err(ApiError::Unauthorized("Only the recipient can refund a tip".to_string()));
+        }
+        None => return Err(ApiError::NotFound("Tip not found".to_string())),
+    };
+    if tip.tip_type != "sol" {
+        return Err(ApiError::BadRequest("Only SOL tips can be refunded".to_string()));
+    }
+    if tip.refunded_at.is_some() {
+        return Err(ApiError::BadRequest("Tip already refunded".to_string()));
+    }
+
+    // Same rule as TipperRecord::ensure_refundable: the tipper's latest tip
+    // must be inside the creator's refund window
+    let (refund_window_seconds,): (i64,) = sqlx::query_as(
+        "SELECT refund_window_seconds FROM profiles WHERE profile_pda = $1"
+    )
+        .bind(&tip.recipient_profile_pda)
+        .fetch_one(&state.db)
+        .await?;
+    let (recent,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM tips WHERE recipient_profile_pda = $1 AND tipper_address = $2 AND created_at >= NOW() - make_interval(secs => $3)"
+    )
+        .bind(&tip.recipient_profile_pda)
+        .bind(&tip.tipper_address)
+        .bind(refund_window_seconds as f64)
+        .fetch_one(&state.db)
+        .await?;
+    if refund_window_seconds <= 0 || recent == 0 {
+        return Err(ApiError::BadRequest("Refund window has passed".to_string()));
+    }
+
+    let tx_valid = services::solana::verify_transaction(&state.rpc_url, &body.refund_tx_signature)
+        .await
+        .map_err(ApiError::Solana)?;
+    if !tx_valid {
+        return Err(ApiError::BadRequest("Transaction not confirmed on-chain".to_string()));
+    }
+
+    sqlx::query("UPDATE tips SET refunded_at = NOW(), refund_tx_signature = $1 WHERE id = $2")
+        .bind(&body.refund_tx_signature)
+        .bind(tip.id)
+        .execute(&state.db)
+        .await?;
+
+    sqlx::query(
+        "UPDATE profiles SET total_tips_received = GREATEST(total_tips_received - 1, 0), total_amount_received_lamports = GREATEST(total_amount_received_lamports - $1, 0), updated_at = NOW() WHERE profile_pda = $2"
+    )
+        .bind(tip.amount_lamports)
+        .bind(&tip.recipient_profile_pda)
+        .execute(&state.db)
+        .await?;
+
+    // Drop the tipper from the unique count once nothing of theirs is left
+    let (remaining,): (i64,) = sqlx::query_as(&format!(
+        "SELECT COUNT(*) FROM tips WHERE recipient_profile_pda = $1 AND tipper_address = $2 AND {}",
+        db::tips::NOT_REFUNDED
+    ))
+        .bind(&tip.recipient_profile_pda)
+        .bind(&tip.tipper_address)
+        .fetch_one(&state.db)
+        .await?;
+    if remaining == 0 {
+        sqlx::query(
+            "UPDATE profiles SET total_unique_tippers = GREATEST(total_unique_tippers - 1, 0) WHERE profile_pda = $1"
+        )
+            .bind(&tip.recipient_profile_pda)
+            .execute(&state.db)
+            .await?;
+    }
+
+    Ok(HttpResponse::Ok().json(TxResponse {
+        success: true,
+        message: "Refund recorded successfully".to_string(),
+    }))
+}
+
 pub async fn get_tip_history(
     state: web::Data<AppState>,
     path: web::Path<String>,
@@ -293,6 +654,8 @@ pub async fn get_tip_history(
     let items: Vec<TipResponse> = tips
         .into_iter()
         .map(|t| TipResponse {
+            media: tip_media(&t),
+            encrypted_message: t.encrypted_message.and_then(|m| serde_json::from_value(m).ok()),
             id: t.id.to_string(),
             tx_signature: t.tx_signature,
             tipper_address: t.tipper_address,
@@ -303,6 +666,7 @@ pub async fn get_tip_history(
             message: t.message,
             is_anonymous: t.is_anonymous,
             created_at: t.created_at.timestamp(),
+            refunded_at: t.refunded_at.map(|d| d.timestamp()),
         })
         .collect();
 
@@ -314,3 +678,56 @@ pub async fn get_tip_history(
         page_size,
     }))
 }
+
+/// Mirrors MediaAttachment::validate and TipProfile::validate_media.
+/// The minimum is in lamports; SPL tips pass `None` and skip it.
+fn validate_media(media: Option<&TipMedia>, lamports: Option<i64>, min_media_tip_amount: i64) -> Result<(), ApiError> {
+    let Some(media) = media else {
+        return Ok(());
+    };
+    if min_media_tip_amount <= 0 {
+        return Err(ApiError::BadRequest("Creator does not accept media tips".to_string()));
+    }
+    if lamports.is_some_and(|amount| amount < min_media_tip_amount) {
+        return Err(ApiError::BadRequest("Tip amount below the creator's media minimum".to_string()));
+    }
+    if !matches!(media.kind.as_str(), "image" | "gif" | "youtube_clip") {
+        return Err(ApiError::BadRequest("media.kind must be image, gif or youtube_clip".to_string()));
+    }
+    if media.url.len() > crate::config::MAX_MEDIA_URL_LENGTH || !media.url.starts_with("https://") {
+        return Err(ApiError::BadRequest(format!(
+            "media.url must be an https URL of at most {} chars", crate::config::MAX_MEDIA_URL_LENGTH
+        )));
+    }
+    if media.start_seconds < 0 || (media.start_seconds > 0 && media.kind != "youtube_clip") {
+        return Err(ApiError::BadRequest("media.start_seconds is only valid for clips".to_string()));
+    }
+    Ok(())
+}
+
+/// Mirrors TipProfile::validate_encrypted_message; the blob is stored as sent
+fn validate_encrypted_message(
+    message: Option<&EncryptedTipMessage>,
+    has_plaintext: bool,
+    encryption_pubkey: &str,
+) -> Result<(), ApiError> {
+    let Some(message) = message else {
+        return Ok(());
+    };
+    if has_plaintext {
+        return Err(ApiError::BadRequest("Send either message or encrypted_message, not both".to_string()));
+    }
+    if encryption_pubkey.is_empty() {
+        return Err(ApiError::BadRequest("Creator has not published an encryption key".to_string()));
+    }
+    services::encryption::validate_message(message)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid encrypted_message: {}", e)))
+}
+
+pub(crate) fn tip_media(t: &Tip) -> Option<TipMedia> {
+    Some(TipMedia {
+        kind: t.media_kind.clone()?,
+        url: t.media_url.clone()?,
+        start_seconds: t.media_start_seconds.unwrap_or(0),
+    })
+}
@@ -38,6 +38,8 @@ pub async fn get_widget_config(
                 "min_tip_amount": profile.min_tip_amount,
                 "accept_anonymous": profile.accept_anonymous,
                 "preset_amounts": preset_amounts,
+                "min_media_tip_amount": profile.min_media_tip_amount,
+                "encryption_pubkey": profile.encryption_pubkey,
                 "sol_price_usd": sol_price,
             },
             "stats": {
@@ -62,10 +64,12 @@ pub async fn get_overlay_config(
 
     let sol_price = services::price::get_sol_price(&state.db).await.unwrap_or(0.0);
 
-    // Get recent tips for the alert feed (last 10)
-    let recent_tips: Vec<crate::models::Tip> = sqlx::query_as::<_, crate::models::Tip>(
-        "SELECT * FROM tips WHERE recipient_profile_pda = $1 ORDER BY created_at DESC LIMIT 10",
-    )
+    // Get recent tips for the alert feed (last 10), skipping blocked wallets and refunds
+    let recent_tips: Vec<crate::models::Tip> = sqlx::query_as::<_, crate::models::Tip>(&format!(
+        "SELECT * FROM tips WHERE recipient_profile_pda = $1 AND {} AND {} ORDER BY created_at DESC LIMIT 10",
+        db::blocklist::NOT_BLOCKED,
+        db::tips::NOT_REFUNDED,
+    ))
     .bind(&profile.profile_pda)
     .fetch_all(&state.db)
     .await?;
@@ -76,6 +80,8 @@ pub async fn get_overlay_config(
         "amount_usd": services::price::lamports_to_usd(t.amount_lamports, sol_price),
         "message": t.message,
         "tip_type": t.tip_type,
+        "media": super::tips::tip_media(t),
+        "matched_amount": t.matched_amount.to_string(),
         "created_at": t.created_at.timestamp(),
     })).collect();
 
@@ -96,6 +102,15 @@ pub async fn get_overlay_config(
         "total_votes": p.total_votes,
     })).collect();
 
+    // Sponsor matching pools, for "matched by" progress bars
+    let pools = db::matching_pools::find_active_by_profile(&state.db, &profile.profile_pda).await?;
+    let matching_json: Vec<crate::models::MatchingPoolResponse> = pools.into_iter().map(super::matching_pools::to_response).collect();
+
+    // Current pin and its remaining time, if the creator runs a pinned slot
+    let pinned_json = db::pinned_slots::find_by_profile(&state.db, &profile.profile_pda)
+        .await?
+        .map(super::pinned_slots::to_response);
+
     Ok(HttpResponse::Ok()
         .insert_header(("Access-Control-Allow-Origin", "*"))
         .insert_header(("Cache-Control", "no-cache"))
@@ -109,6 +124,8 @@ pub async fn get_overlay_config(
             "recent_tips": tips_json,
             "active_goals": goals_json,
             "active_polls": polls_json,
+            "matching_pools": matching_json,
+            "pinned_slot": pinned_json,
         })))
 }
 
@@ -147,7 +164,14 @@ pub async fn export_tips_csv(
     .fetch_all(&state.db)
     .await?;
 
-    let mut csv = String::from("Date,Tipper,Amount (lamports),Type,Token Mint,Message,Anonymous\n");
+    // Tips from blocked wallets stay in the export for bookkeeping, without their messages
+    let blocked: std::collections::HashSet<String> = db::blocklist::find_by_profile(&state.db, &profile_pda)
+        .await?
+        .into_iter()
+        .map(|b| b.wallet_address)
+        .collect();
+
+    let mut csv = String::from("Date,Tipper,Amount (lamports),Type,Token Mint,Message,Anonymous,Refunded\n");
     for t in &tips {
         // BE-19: Sanitize fields to prevent CSV injection
         let tipper = if t.is_anonymous {
@@ -155,10 +179,16 @@ pub async fn export_tips_csv(
         } else {
             sanitize_csv_field(&t.tipper_address)
         };
-        let message = t.message.as_deref().unwrap_or("").replace(',', ";");
+        let message = if blocked.contains(&t.tipper_address) {
+            String::new()
+        } else if t.encrypted_message.is_some() {
+            "[encrypted]".to_string()
+        } else {
+            t.message.as_deref().unwrap_or("").replace(',', ";")
+        };
         let message = sanitize_csv_field(&message);
         csv.push_str(&format!(
-            "{},{},{},{},{},{},{}\n",
+            "{},{},{},{},{},{},{},{}\n",
             t.created_at.format("%Y-%m-%d %H:%M:%S"),
             tipper,
             t.amount_lamports,
@@ -166,6 +196,7 @@ pub async fn export_tips_csv(
             t.token_mint.as_deref().unwrap_or("SOL"),
             message,
             t.is_anonymous,
+            t.refunded_at.is_some(),
         ));
     }
 
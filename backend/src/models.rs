@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -32,6 +34,14 @@ pub struct Profile {
     pub webhook_url: String,
     pub active_polls_count: i32,
     pub active_gates_count: i32,
+    /// Set once the profile has been closed on-chain
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Smallest tip that may carry media (0 = media tips off)
+    pub min_media_tip_amount: i64,
+    /// Base64 X25519 key for sealed tip messages ('' = not published)
+    pub encryption_pubkey: String,
+    /// Seconds after a tip during which the creator may refund it (0 = off)
+    pub refund_window_seconds: i64,
 }
 
 // ============================================================
@@ -65,6 +75,17 @@ pub struct Tip {
     pub message: Option<String>,
     pub is_anonymous: bool,
     pub created_at: DateTime<Utc>,
+    pub media_kind: Option<String>,
+    pub media_url: Option<String>,
+    pub media_start_seconds: Option<i32>,
+    /// Sealed message as sent (EncryptedTipMessage JSON)
+    pub encrypted_message: Option<serde_json::Value>,
+    pub refunded_at: Option<DateTime<Utc>>,
+    pub refund_tx_signature: Option<String>,
+    /// Sponsor match paid on top of the tip
+    pub matched_amount: i64,
+    /// Position within a send_tip_batch transaction (0 otherwise)
+    pub batch_index: i32,
 }
 
 // ============================================================
@@ -108,6 +129,29 @@ pub struct Subscription {
     pub token_mint: String,
     pub created_at: DateTime<Utc>,
     pub last_payment_at: Option<DateTime<Utc>>,
+    pub tier_pda: Option<String>,
+}
+
+// ============================================================
+// SubscriptionTier
+// ============================================================
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SubscriptionTier {
+    pub id: Uuid,
+    pub tier_pda: String,
+    pub profile_pda: String,
+    pub tier_id: i64,
+    pub name: String,
+    pub price: i64,
+    pub interval_seconds: i64,
+    pub token_mint: String,
+    pub max_members: i32,
+    pub benefits_hash: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Active subscriptions on this tier (computed)
+    pub member_count: i64,
 }
 
 // ============================================================
@@ -120,6 +164,8 @@ pub struct TipSplit {
     pub profile_pda: String,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
+    /// Shares accrue to claimable balances instead of being paid out
+    pub accrue: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
@@ -129,6 +175,80 @@ pub struct SplitRecipient {
     pub wallet_address: String,
     pub share_bps: i32,
     pub label: String,
+    pub position: i32,
+}
+
+// ============================================================
+// BlockedTipper
+// ============================================================
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BlockedTipper {
+    pub id: Uuid,
+    pub profile_pda: String,
+    pub wallet_address: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// ============================================================
+// MatchingPool
+// ============================================================
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MatchingPool {
+    pub id: Uuid,
+    pub pool_pda: String,
+    pub profile_pda: String,
+    /// Set when the pool matches one goal instead of tips
+    pub goal_pda: Option<String>,
+    pub sponsor_address: String,
+    pub match_ratio_bps: i32,
+    pub per_tipper_cap: i64,
+    pub total_cap: i64,
+    pub matched_amount: i64,
+    pub expires_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ============================================================
+// Bounty
+// ============================================================
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Bounty {
+    pub id: Uuid,
+    pub bounty_pda: String,
+    pub profile_pda: String,
+    pub requester_address: String,
+    pub creator_address: String,
+    /// None for SOL bounties
+    pub token_mint: Option<String>,
+    pub amount: i64,
+    pub description: String,
+    /// open, accepted, fulfilled, disputed, released or refunded
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub fulfilled_at: Option<DateTime<Utc>>,
+    pub settled_at: Option<DateTime<Utc>>,
+    pub tx_signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// ============================================================
+// PinnedSlot
+// ============================================================
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PinnedSlot {
+    pub profile_pda: String,
+    pub duration_seconds: i64,
+    pub min_bid: i64,
+    pub min_increment_bps: i32,
+    pub tipper_address: Option<String>,
+    pub bid: i64,
+    pub message: Option<String>,
+    pub pinned_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub total_pins: i64,
+    pub updated_at: DateTime<Utc>,
 }
 
 // ============================================================
@@ -142,6 +262,7 @@ pub struct PlatformConfigRow {
     pub platform_fee_bps: i32,
     pub paused: bool,
     pub created_at: DateTime<Utc>,
+    pub disabled_features: i32,
 }
 
 // ============================================================
@@ -264,6 +385,27 @@ pub struct UpdateProfileRequest {
     pub preset_amounts: Option<Vec<i64>>,
     pub social_links: Option<String>,
     pub webhook_url: Option<String>,
+    pub min_media_tip_amount: Option<i64>,
+    /// Base64 X25519 key; empty string withdraws it
+    pub encryption_pubkey: Option<String>,
+    pub refund_window_seconds: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeUsernameRequest {
+    pub username: String,
+}
+
+/// Mirrors block_tipper once confirmed on-chain
+#[derive(Debug, Deserialize)]
+pub struct BlockTipperRequest {
+    pub wallet_address: String,
+}
+
+/// Mirrors refund_tip once confirmed on-chain
+#[derive(Debug, Deserialize)]
+pub struct RefundTipRequest {
+    pub refund_tx_signature: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -274,6 +416,38 @@ pub struct SendTipRequest {
     pub message: Option<String>,
     pub is_anonymous: Option<bool>,
     pub tx_signature: String,
+    pub media: Option<TipMedia>,
+    pub encrypted_message: Option<EncryptedTipMessage>,
+    pub matching_pool_pda: Option<String>,
+    /// MatchedEvent.matched_amount from the confirmed tx; 0 or absent if
+    /// the pool paid nothing
+    pub matched_amount: Option<i64>,
+    /// Set when the tip took the profile's pinned slot
+    pub pin: Option<TipPinInput>,
+}
+
+/// TipPinnedEvent fields from the confirmed tx
+#[derive(Debug, Deserialize)]
+pub struct TipPinInput {
+    pub timestamp: i64,
+    pub expires_at: i64,
+}
+
+/// Mirrors send_tip_batch: one transaction, several creators
+#[derive(Debug, Deserialize)]
+pub struct SendTipBatchRequest {
+    pub tipper_address: String,
+    pub tx_signature: String,
+    /// In instruction order
+    pub tips: Vec<BatchTipInput>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchTipInput {
+    pub recipient_address: String,
+    pub amount_lamports: i64,
+    pub message: Option<String>,
+    pub is_anonymous: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -285,6 +459,27 @@ pub struct SendTipSplRequest {
     pub message: Option<String>,
     pub is_anonymous: Option<bool>,
     pub tx_signature: String,
+    pub media: Option<TipMedia>,
+    pub encrypted_message: Option<EncryptedTipMessage>,
+}
+
+/// Media attached to a tip; mirrors the on-chain MediaAttachment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TipMedia {
+    /// "image", "gif" or "youtube_clip"
+    pub kind: String,
+    pub url: String,
+    #[serde(default)]
+    pub start_seconds: i32,
+}
+
+/// Tip message sealed to the creator's key; mirrors the on-chain
+/// EncryptedMessage with base64 fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptedTipMessage {
+    pub ephemeral_pubkey: String,
+    pub nonce: String,
+    pub ciphertext: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -304,23 +499,103 @@ pub struct ContributeGoalRequest {
     pub amount_lamports: i64,
     pub message: Option<String>,
     pub tx_signature: String,
+    pub matching_pool_pda: Option<String>,
+}
+
+/// Mirrors create_matching_pool once confirmed on-chain
+#[derive(Debug, Deserialize)]
+pub struct CreateMatchingPoolRequest {
+    pub pool_pda: String,
+    pub profile_pda: String,
+    pub goal_pda: Option<String>,
+    pub match_ratio_bps: i32,
+    pub per_tipper_cap: i64,
+    pub total_cap: i64,
+    pub expires_at: i64,
+    pub tx_signature: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateBountyRequest {
+    pub bounty_pda: String,
+    pub profile_pda: String,
+    pub token_mint: Option<String>,
+    pub amount: i64,
+    pub description: String,
+    pub expires_at: i64,
+    pub tx_signature: String,
+}
+
+/// Mirrors configure_pinned_slot
+#[derive(Debug, Deserialize)]
+pub struct ConfigurePinnedSlotRequest {
+    pub profile_pda: String,
+    pub duration_seconds: i64,
+    pub min_bid: i64,
+    pub min_increment_bps: i32,
+    pub tx_signature: String,
+}
+
+/// Confirmed signature of a bounty transition (accept, fulfill, ...)
+#[derive(Debug, Deserialize)]
+pub struct BountyTxRequest {
+    pub tx_signature: String,
+}
+
+/// Mirrors resolve_bounty_dispute
+#[derive(Debug, Deserialize)]
+pub struct ResolveBountyRequest {
+    pub authority_address: String,
+    /// true pays the creator, false refunds the requester
+    pub release: bool,
+    pub tx_signature: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreateSubscriptionRequest {
     pub subscriber_address: String,
     pub recipient_address: String,
-    pub amount_per_interval: i64,
-    pub interval_seconds: i64,
-    pub is_spl: bool,
-    pub token_mint: String,
+    pub tier_pda: String,
     pub tx_signature: String,
 }
 
+// SubscriptionTier DTOs
+#[derive(Debug, Deserialize)]
+pub struct CreateTierRequest {
+    pub tier_id: i64,
+    pub name: String,
+    pub price: i64,
+    pub interval_seconds: i64,
+    pub token_mint: Option<String>,
+    pub max_members: Option<i32>,
+    pub benefits_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateTierRequest {
+    pub name: Option<String>,
+    pub price: Option<i64>,
+    pub interval_seconds: Option<i64>,
+    pub max_members: Option<i32>,
+    pub benefits_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TierListQuery {
+    pub active_only: Option<bool>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ConfigureSplitRequest {
     pub owner_address: String,
     pub recipients: Vec<SplitRecipientInput>,
+    pub accrue: Option<bool>,
+}
+
+/// Mirrors claim_split_share once confirmed on-chain
+#[derive(Debug, Deserialize)]
+pub struct ClaimSplitShareRequest {
+    pub tx_signature: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -336,6 +611,23 @@ pub struct PauseRequest {
     pub paused: bool,
 }
 
+/// Mirrors set_feature_flags once confirmed on-chain
+#[derive(Debug, Deserialize)]
+pub struct SetFeatureFlagsRequest {
+    pub authority_address: String,
+    pub features: Vec<String>,
+    pub enabled: bool,
+}
+
+/// Mirrors update_platform_config once confirmed on-chain. Authority
+/// changes only go through the on-chain propose/accept transfer.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePlatformConfigRequest {
+    pub authority_address: String,
+    pub platform_fee_bps: Option<i32>,
+    pub treasury_address: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct VerifyCreatorRequest {
     pub authority_address: String,
@@ -427,6 +719,10 @@ pub struct ProfileAccountResponse {
     pub webhook_url: String,
     pub active_polls_count: i32,
     pub active_gates_count: i32,
+    pub closed_at: Option<i64>,
+    pub min_media_tip_amount: String,
+    pub encryption_pubkey: String,
+    pub refund_window_seconds: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -494,6 +790,24 @@ pub struct SubscriptionAccountResponse {
     pub token_mint: String,
     pub created_at: i64,
     pub last_payment_at: Option<i64>,
+    pub tier: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TierResponse {
+    pub public_key: String,
+    pub profile_pda: String,
+    pub tier_id: i64,
+    pub name: String,
+    pub price: String,
+    pub interval_seconds: i64,
+    pub token_mint: String,
+    pub max_members: i32,
+    pub member_count: i64,
+    pub benefits_hash: String,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -508,6 +822,7 @@ pub struct TipSplitAccountResponse {
     pub num_recipients: i32,
     pub recipients: Vec<SplitRecipientResponse>,
     pub is_active: bool,
+    pub accrue: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -515,6 +830,7 @@ pub struct SplitRecipientResponse {
     pub wallet: String,
     pub share_bps: i32,
     pub label: String,
+    pub claimable: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -524,6 +840,8 @@ pub struct PlatformConfigResponse {
     pub platform_fee_bps: i32,
     pub paused: bool,
     pub created_at: i64,
+    pub disabled_features: i32,
+    pub features: BTreeMap<String, bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -538,6 +856,9 @@ pub struct TipResponse {
     pub message: Option<String>,
     pub is_anonymous: bool,
     pub created_at: i64,
+    pub media: Option<TipMedia>,
+    pub encrypted_message: Option<EncryptedTipMessage>,
+    pub refunded_at: Option<i64>,
 }
 
 // Poll Response (v3)
@@ -613,6 +934,69 @@ pub struct PaginatedResponse<T: Serialize> {
     pub has_next_page: bool,
 }
 
+#[derive(Debug, Serialize)]
+pub struct MatchingPoolResponse {
+    pub pool_pda: String,
+    pub profile_pda: String,
+    pub goal_pda: Option<String>,
+    pub sponsor_address: String,
+    pub match_ratio_bps: i32,
+    pub per_tipper_cap: String,
+    pub total_cap: String,
+    pub matched_amount: String,
+    pub progress_pct: f64,
+    pub expires_at: i64,
+    pub closed_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BountyResponse {
+    pub bounty_pda: String,
+    pub profile_pda: String,
+    pub requester_address: String,
+    pub creator_address: String,
+    pub token_mint: Option<String>,
+    pub amount: String,
+    pub description: String,
+    pub status: String,
+    pub expires_at: i64,
+    pub accepted_at: Option<i64>,
+    pub fulfilled_at: Option<i64>,
+    /// End of the requester's approve-or-dispute window, once fulfilled
+    pub review_ends_at: Option<i64>,
+    pub settled_at: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PinnedSlotResponse {
+    pub profile_pda: String,
+    pub duration_seconds: i64,
+    pub min_bid: String,
+    pub min_increment_bps: i32,
+    /// Smallest tip that takes the slot right now
+    pub min_next_bid: String,
+    pub total_pins: i64,
+    /// The live pin, if any
+    pub pinned: Option<PinnedMessageResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PinnedMessageResponse {
+    pub tipper_address: String,
+    pub bid: String,
+    pub message: Option<String>,
+    pub pinned_at: i64,
+    pub expires_at: i64,
+    pub remaining_seconds: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockedTipperResponse {
+    pub wallet_address: String,
+    pub blocked_at: i64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct TxResponse {
     pub success: bool,
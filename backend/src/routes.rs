@@ -13,8 +13,15 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/profiles", web::post().to(handlers::profiles::create_profile))
             .route("/profiles/{address}", web::get().to(handlers::profiles::get_profile))
             .route("/profiles/{address}", web::put().to(handlers::profiles::update_profile))
+            .route("/profiles/{address}/username", web::put().to(handlers::profiles::change_username))
+            .route("/profiles/{address}/close", web::post().to(handlers::profiles::close_profile))
             .route("/profiles/{address}/leaderboard", web::get().to(handlers::profiles::get_leaderboard))
 
+            // Blocklist
+            .route("/blocklist", web::get().to(handlers::blocklist::list_blocked))
+            .route("/blocklist", web::post().to(handlers::blocklist::block_tipper))
+            .route("/blocklist/{wallet_address}", web::delete().to(handlers::blocklist::unblock_tipper))
+
             // Vault
             .route("/vault/{profile_pda}", web::get().to(handlers::vault::get_vault))
             .route("/vault/initialize", web::post().to(handlers::vault::initialize_vault))
@@ -23,8 +30,11 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             // Tips
             .route("/tips", web::post().to(handlers::tips::record_tip))
             .route("/tips/spl", web::post().to(handlers::tips::record_tip_spl))
+            .route("/tips/batch", web::post().to(handlers::tips::record_tip_batch))
             .route("/tips/split", web::post().to(handlers::tips::record_tip_split))
+            .route("/tips/split/spl", web::post().to(handlers::tips::record_tip_split_spl))
             .route("/tips/history/{address}", web::get().to(handlers::tips::get_tip_history))
+            .route("/tips/{tx_signature}/refund", web::post().to(handlers::tips::record_refund))
 
             // Goals
             .route("/goals/{profile_pda}", web::get().to(handlers::goals::list_goals))
@@ -32,14 +42,41 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/goals/{goal_pda}/contribute", web::post().to(handlers::goals::contribute_goal))
             .route("/goals/{goal_pda}", web::delete().to(handlers::goals::close_goal))
 
+            // Matching pools
+            .route("/matching-pools", web::post().to(handlers::matching_pools::create_matching_pool))
+            .route("/matching-pools/{profile_pda}", web::get().to(handlers::matching_pools::list_matching_pools))
+            .route("/matching-pools/{pool_pda}/close", web::post().to(handlers::matching_pools::close_matching_pool))
+
+            // Pinned slot
+            .route("/pinned-slot", web::post().to(handlers::pinned_slots::configure_pinned_slot))
+            .route("/pinned-slot/{profile_pda}", web::get().to(handlers::pinned_slots::get_pinned_slot))
+
+            // Bounties
+            .route("/bounties", web::post().to(handlers::bounties::create_bounty))
+            .route("/bounties/{profile_pda}", web::get().to(handlers::bounties::list_bounties))
+            .route("/bounties/{bounty_pda}/accept", web::post().to(handlers::bounties::accept_bounty))
+            .route("/bounties/{bounty_pda}/fulfill", web::post().to(handlers::bounties::fulfill_bounty))
+            .route("/bounties/{bounty_pda}/dispute", web::post().to(handlers::bounties::dispute_bounty))
+            .route("/bounties/{bounty_pda}/release", web::post().to(handlers::bounties::release_bounty))
+            .route("/bounties/{bounty_pda}/refund", web::post().to(handlers::bounties::refund_bounty))
+            .route("/bounties/{bounty_pda}/resolve", web::post().to(handlers::bounties::resolve_bounty))
+
             // Subscriptions
             .route("/subscriptions", web::post().to(handlers::subscriptions::create_subscription))
             .route("/subscriptions/subscriber/{address}", web::get().to(handlers::subscriptions::get_by_subscriber))
+            .route("/subscriptions/due", web::get().to(handlers::subscriptions::get_due))
             .route("/subscriptions/{subscription_pda}", web::delete().to(handlers::subscriptions::cancel_subscription))
 
+            // Subscription tiers
+            .route("/subscription-tiers", web::post().to(handlers::subscription_tiers::create_tier))
+            .route("/subscription-tiers/{profile_pda}", web::get().to(handlers::subscription_tiers::list_tiers))
+            .route("/subscription-tiers/{tier_pda}", web::put().to(handlers::subscription_tiers::update_tier))
+            .route("/subscription-tiers/{tier_pda}/retire", web::post().to(handlers::subscription_tiers::retire_tier))
+
             // Splits
             .route("/splits/{profile_pda}", web::get().to(handlers::splits::get_split))
             .route("/splits", web::post().to(handlers::splits::configure_split))
+            .route("/splits/{profile_pda}/claim", web::post().to(handlers::splits::claim_split_share))
 
             // Polls (v3)
             .route("/polls", web::post().to(handlers::polls::create_poll))
@@ -70,7 +107,9 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 
             // Admin
             .route("/admin/config", web::get().to(handlers::admin::get_platform_config))
+            .route("/admin/config", web::put().to(handlers::admin::update_platform_config))
             .route("/admin/pause", web::post().to(handlers::admin::pause_platform))
+            .route("/admin/features", web::post().to(handlers::admin::set_feature_flags))
             .route("/admin/verify", web::post().to(handlers::admin::verify_creator))
 
             // Transactions (generic query)
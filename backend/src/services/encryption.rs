@@ -0,0 +1,44 @@
+//! Sealed tip messages: NaCl box (X25519 + XSalsa20-Poly1305).
+//!
+//! The tipper encrypts to the creator's published X25519 key with a
+//! fresh ephemeral key pair. The backend only checks sizes and stores
+//! the blob untouched; only the creator, who holds the secret key,
+//! can open it.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::config::MAX_MESSAGE_LENGTH;
+use crate::models::EncryptedTipMessage;
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 24;
+const TAG_LENGTH: usize = 16;
+/// Mirrors MAX_ENCRYPTED_MESSAGE_LENGTH on-chain
+pub const MAX_CIPHERTEXT_LENGTH: usize = MAX_MESSAGE_LENGTH + TAG_LENGTH;
+
+/// Decode a base64 X25519 public key
+pub fn decode_key(key: &str) -> Result<[u8; KEY_LENGTH], String> {
+    decode_fixed(key, "key")
+}
+
+/// Check that a sealed message has the sizes the program accepts
+pub fn validate_message(message: &EncryptedTipMessage) -> Result<(), String> {
+    decode_fixed::<KEY_LENGTH>(&message.ephemeral_pubkey, "ephemeral_pubkey")?;
+    decode_fixed::<NONCE_LENGTH>(&message.nonce, "nonce")?;
+    let ciphertext = STANDARD
+        .decode(&message.ciphertext)
+        .map_err(|e| format!("Invalid base64 in ciphertext: {}", e))?;
+    if ciphertext.len() <= TAG_LENGTH || ciphertext.len() > MAX_CIPHERTEXT_LENGTH {
+        return Err(format!("ciphertext must be {}-{} bytes", TAG_LENGTH + 1, MAX_CIPHERTEXT_LENGTH));
+    }
+    Ok(())
+}
+
+fn decode_fixed<const N: usize>(value: &str, field: &str) -> Result<[u8; N], String> {
+    let bytes = STANDARD
+        .decode(value)
+        .map_err(|e| format!("Invalid base64 in {}: {}", field, e))?;
+    bytes
+        .try_into()
+        .map_err(|b: Vec<u8>| format!("{} must be {} bytes, got {}", field, N, b.len()))
+}
@@ -1,3 +1,4 @@
+pub mod encryption;
 pub mod price;
 pub mod solana;
 pub mod webhook;
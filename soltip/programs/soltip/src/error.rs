@@ -25,6 +25,14 @@ pub enum ErrorCode {
     EmptyUsername,
     #[msg("Media URL too long (max 200 chars)")]
     MediaUrlTooLong,
+    #[msg("Media URL must be an https URL")]
+    InvalidMediaUrl,
+    #[msg("Start time is only valid for clips")]
+    InvalidMediaStartTime,
+    #[msg("Encrypted message is empty or too long")]
+    InvalidEncryptedMessage,
+    #[msg("Send either a plaintext or an encrypted message, not both")]
+    ConflictingTipMessages,
     #[msg("Poll title too long (max 64 chars)")]
     PollTitleTooLong,
     #[msg("Poll option label too long (max 32 chars)")]
@@ -43,12 +51,26 @@ pub enum ErrorCode {
     TipAmountTooSmall,
     #[msg("Tip amount exceeds maximum (1000 SOL)")]
     TipAmountTooLarge,
+    #[msg("Tip amount exceeds the creator's per-tip maximum")]
+    TipAboveCreatorMaximum,
+    #[msg("First tip from a new wallet is below the creator's minimum")]
+    FirstTipTooSmall,
+    #[msg("Invalid rate limit settings")]
+    InvalidRateLimitSettings,
+    #[msg("Creator does not accept media tips")]
+    MediaTipsDisabled,
+    #[msg("Tip amount below the creator's media minimum")]
+    TipBelowMediaMinimum,
     #[msg("Withdrawal amount below minimum (0.01 SOL)")]
     WithdrawalTooSmall,
     #[msg("Insufficient balance")]
     InsufficientBalance,
     #[msg("Invalid withdrawal fee (0-10%)")]
     InvalidWithdrawalFee,
+    #[msg("Invalid platform fee (max 10%)")]
+    InvalidPlatformFee,
+    #[msg("Invalid treasury address")]
+    InvalidTreasury,
     #[msg("Goal target amount must be > 0")]
     InvalidGoalAmount,
     #[msg("Min tip amount invalid")]
@@ -69,10 +91,32 @@ pub enum ErrorCode {
     NotAdmin,
     #[msg("Profile owner mismatch")]
     ProfileOwnerMismatch,
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Only the pending authority can accept the transfer")]
+    NotPendingAuthority,
+    #[msg("A treasury other than the program treasury PDA must sign withdrawals")]
+    TreasuryNotSigner,
+    #[msg("Only admin council members can perform this action")]
+    NotCouncilMember,
 
     // ========== State ==========
     #[msg("Username already taken")]
     UsernameAlreadyTaken,
+    #[msg("New username must differ from the current one")]
+    UsernameUnchanged,
+    #[msg("Close all active goals before closing the profile")]
+    ProfileHasActiveGoals,
+    #[msg("Close all active polls before closing the profile")]
+    ProfileHasActivePolls,
+    #[msg("Close all content gates before closing the profile")]
+    ProfileHasActiveGates,
+    #[msg("Profile still has active subscribers")]
+    ProfileHasActiveSubscriptions,
+    #[msg("Settle all accepted bounties before closing the profile")]
+    ProfileHasActiveBounties,
     #[msg("Goal already completed")]
     GoalAlreadyCompleted,
     #[msg("Goal deadline expired")]
@@ -87,12 +131,46 @@ pub enum ErrorCode {
     SubscriptionNotActive,
     #[msg("Subscription payment not yet due")]
     SubscriptionNotDue,
+    #[msg("Subscription is still in good standing")]
+    SubscriptionNotLapsed,
+    #[msg("Subscription escrow balance too low")]
+    InsufficientSubscriptionEscrow,
+    #[msg("Subscription grace period out of range (0-14 days)")]
+    InvalidGracePeriod,
+    #[msg("Subscription is lapsed")]
+    SubscriptionLapsed,
+    #[msg("Tier name empty or too long (max 32 chars)")]
+    TierNameTooLong,
+    #[msg("Subscription tier is retired")]
+    SubscriptionTierNotActive,
+    #[msg("Subscription tier is full")]
+    SubscriptionTierFull,
+    #[msg("Max active subscription tiers reached (10)")]
+    MaxActiveTiersReached,
+    #[msg("Subscription tier does not match")]
+    SubscriptionTierMismatch,
+    #[msg("Subscription tier still has members")]
+    SubscriptionTierHasMembers,
     #[msg("Cannot tip yourself")]
     CannotTipSelf,
     #[msg("Vault not initialized – call initialize_vault first")]
     VaultNotInitialized,
     #[msg("Platform is paused")]
     PlatformPaused,
+    #[msg("Escrowed goals require a deadline")]
+    EscrowedGoalRequiresDeadline,
+    #[msg("Escrowed goals currently support SOL only")]
+    EscrowedGoalRequiresSol,
+    #[msg("Goal is not in escrow mode")]
+    GoalNotEscrowed,
+    #[msg("Goal not completed")]
+    GoalNotCompleted,
+    #[msg("Goal deadline has not passed")]
+    GoalNotExpired,
+    #[msg("Goal escrow still holds contributions")]
+    GoalEscrowNotEmpty,
+    #[msg("Nothing to refund")]
+    NothingToRefund,
 
     // ========== Rate Limiting ==========
     #[msg("Too many tips – cooldown period active")]
@@ -129,6 +207,8 @@ pub enum ErrorCode {
     ReferralsDisabled,
     #[msg("Content gates disabled")]
     ContentGatesDisabled,
+    #[msg("Unknown feature flag")]
+    InvalidFeatureFlag,
 
     // ========== Token ==========
     #[msg("Invalid token mint")]
@@ -141,11 +221,15 @@ pub enum ErrorCode {
     InvalidTokenAccount,
     #[msg("Token account owner mismatch")]
     TokenAccountOwnerMismatch,
+    #[msg("SPL subscription requires token accounts and the token program")]
+    MissingTokenAccounts,
+    #[msg("Subscription PDA is not an approved delegate for this amount")]
+    SubscriptionDelegateMissing,
 
     // ========== Splits ==========
     #[msg("Split BPS must sum to 10 000")]
     InvalidSplitBps,
-    #[msg("Too many split recipients (max 5)")]
+    #[msg("Too many split recipients (max 5, or 25 in accrual mode)")]
     TooManySplitRecipients,
     #[msg("Split config not found for this profile")]
     SplitNotFound,
@@ -167,6 +251,10 @@ pub enum ErrorCode {
     InvalidAccountData,
     #[msg("Account size mismatch")]
     AccountSizeMismatch,
+    #[msg("Account type cannot be migrated")]
+    UnsupportedAccountType,
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
 
     // ========== PDA ==========
     #[msg("Invalid PDA")]
@@ -233,6 +321,12 @@ pub enum ErrorCode {
     CannotReferSelf,
     #[msg("Referral not active")]
     ReferralNotActive,
+    #[msg("Referral account does not match the profile's referrer")]
+    ReferralMismatch,
+    #[msg("Profile has a referrer; referral account required")]
+    ReferralAccountMissing,
+    #[msg("No referral earnings to claim")]
+    NothingToClaim,
 
     // ========== Content Gates ==========
     #[msg("Max active content gates reached (10)")]
@@ -249,4 +343,110 @@ pub enum ErrorCode {
     TooManyPresetAmounts,
     #[msg("Preset amount must be > 0")]
     InvalidPresetAmount,
+
+    // ========== Governance ==========
+    #[msg("Invalid council (1-10 unique approvers)")]
+    InvalidCouncil,
+    #[msg("Threshold must be between 1 and the council size")]
+    InvalidThreshold,
+    #[msg("Invalid timelock (max 30 days)")]
+    InvalidTimelock,
+    #[msg("Council member already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal is not pending")]
+    ProposalNotPending,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+    #[msg("Proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Council changed since the proposal was created")]
+    ProposalStale,
+    #[msg("Accounts do not match the proposed action")]
+    ProposalAccountMismatch,
+
+    // ========== Encryption ==========
+    #[msg("Creator has not published an encryption key")]
+    EncryptionKeyNotSet,
+
+    // ========== Blocklist ==========
+    #[msg("This wallet is blocked by the creator")]
+    TipperBlocked,
+    #[msg("Cannot block your own wallet")]
+    CannotBlockSelf,
+
+    // ========== Refunds ==========
+    #[msg("Refund window out of range (0-30 days)")]
+    InvalidRefundWindow,
+    #[msg("Refund window has passed")]
+    RefundWindowExpired,
+    #[msg("Refund exceeds the SOL this tipper sent inside the refund window")]
+    RefundExceedsTipped,
+
+    // ========== Matching Pools ==========
+    #[msg("Match ratio must be between 1 and 50000 bps (5:1)")]
+    InvalidMatchRatio,
+    #[msg("Invalid matching caps (per-tipper cap must be within the total)")]
+    InvalidMatchCap,
+    #[msg("Matching pool expiry must be in the future (max 1 year)")]
+    InvalidMatchExpiry,
+    #[msg("Matching pool does not cover this tip or goal")]
+    MatchingPoolMismatch,
+    #[msg("Matching pool has not expired")]
+    MatchingPoolNotExpired,
+    #[msg("A match record is required with a matching pool")]
+    MatchRecordRequired,
+
+    // ========== Split Accrual ==========
+    #[msg("Split recipient still has an unclaimed balance")]
+    SplitSharesUnclaimed,
+    #[msg("No split balance to claim")]
+    NoSplitShareToClaim,
+    #[msg("Accrual-mode splits only take SOL tips")]
+    SplitAccrualSolOnly,
+
+    // ========== Batch Tips ==========
+    #[msg("A batch must hold 1-5 tips")]
+    InvalidBatchSize,
+    #[msg("Batch accounts do not match the listed tips")]
+    BatchAccountsMismatch,
+    #[msg("A creator appears more than once in the batch")]
+    BatchDuplicateProfile,
+
+    // ========== Bounties ==========
+    #[msg("Bounty amount below minimum")]
+    InvalidBountyAmount,
+    #[msg("Bounty description must be 1-256 characters")]
+    InvalidBountyDescription,
+    #[msg("Bounty expiry must be in the future (max 90 days)")]
+    InvalidBountyExpiry,
+    #[msg("Bounty is not open")]
+    BountyNotOpen,
+    #[msg("Bounty has not been accepted")]
+    BountyNotAccepted,
+    #[msg("Bounty has not been fulfilled")]
+    BountyNotFulfilled,
+    #[msg("Bounty is not disputed")]
+    BountyNotDisputed,
+    #[msg("Bounty has expired")]
+    BountyExpired,
+    #[msg("Only the requester can release before the review window ends")]
+    BountyReviewWindowOpen,
+    #[msg("Bounty review window has closed")]
+    BountyReviewWindowClosed,
+    #[msg("Bounty cannot be refunded in its current state")]
+    BountyNotRefundable,
+    #[msg("Accounts do not match the bounty's escrow")]
+    BountyAccountsMismatch,
+
+    // ========== Pinned Slot ==========
+    #[msg("Pin duration must be between 60 seconds and 1 day")]
+    InvalidPinDuration,
+    #[msg("Minimum pin bid below the minimum tip")]
+    InvalidPinBid,
+    #[msg("Pin increment must be at most 10000 bps")]
+    InvalidPinIncrement,
+    #[msg("Tip does not beat the pinned bid")]
+    PinBidTooLow,
+    #[msg("A pinned tip needs a public message")]
+    PinRequiresMessage,
 }
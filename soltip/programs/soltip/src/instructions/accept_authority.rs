@@ -0,0 +1,45 @@
+// ==========================================================
+// accept_authority – step two of a platform authority transfer
+//
+// Signed by the wallet nominated in propose_authority, so a
+// typo in the new key can never lock the platform.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::instructions::initialize_platform::PlatformConfig;
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub previous_authority: Pubkey,
+    pub new_authority:      Pubkey,
+    pub timestamp:          i64,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let clock    = Clock::get()?;
+    let previous = ctx.accounts.platform_config.authority;
+
+    ctx.accounts.platform_config.accept_authority(ctx.accounts.new_authority.key(), clock.unix_timestamp)?;
+
+    emit!(AuthorityTransferredEvent {
+        previous_authority: previous,
+        new_authority:      ctx.accounts.new_authority.key(),
+        timestamp:          clock.unix_timestamp,
+    });
+
+    msg!("Platform authority transferred: {} -> {}", previous, ctx.accounts.new_authority.key());
+    Ok(())
+}
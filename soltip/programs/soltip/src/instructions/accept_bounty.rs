@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bounty, TipProfile};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct BountyAcceptedEvent {
+    pub bounty:    Pubkey,
+    pub creator:   Pubkey,
+    pub requester: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct AcceptBounty<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [BOUNTY_SEED, bounty.profile.as_ref(), bounty.requester.as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump    = bounty.bump,
+        has_one = creator @ ErrorCode::NotProfileOwner,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// Creator's profile; counts the bounty until it is settled
+    #[account(
+        mut,
+        address = bounty.profile @ ErrorCode::BountyAccountsMismatch,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+}
+
+/// Creator takes on an open bounty; the requester can no longer cancel it.
+pub fn handler(ctx: Context<AcceptBounty>) -> Result<()> {
+    let clock = Clock::get()?;
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.accept(clock.unix_timestamp)?;
+    ctx.accounts.tip_profile.add_bounty()?;
+
+    emit!(BountyAcceptedEvent {
+        bounty:    bounty.key(),
+        creator:   bounty.creator,
+        requester: bounty.requester,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Bounty {} accepted", bounty.bounty_id);
+    Ok(())
+}
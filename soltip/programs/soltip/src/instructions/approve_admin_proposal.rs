@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{Governance, AdminProposal, ProposalStatus};
+use crate::constants::*;
+
+#[event]
+pub struct AdminProposalApprovedEvent {
+    pub proposal:       Pubkey,
+    pub approver:       Pubkey,
+    pub approval_count: u8,
+    /// Set once the threshold is reached; 0 while still pending
+    pub executable_at:  i64,
+    pub timestamp:      i64,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump  = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED, proposal.proposal_id.to_le_bytes().as_ref()],
+        bump  = proposal.bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let index    = ctx.accounts.governance.approver_index(&ctx.accounts.approver.key())?;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approve(&ctx.accounts.governance, index, ts)?;
+
+    emit!(AdminProposalApprovedEvent {
+        proposal:       proposal.key(),
+        approver:       ctx.accounts.approver.key(),
+        approval_count: proposal.approval_count,
+        executable_at:  if proposal.status == ProposalStatus::Approved { proposal.executable_at } else { 0 },
+        timestamp:      ts,
+    });
+
+    msg!(
+        "Admin proposal {} approved by {} ({}/{})",
+        proposal.proposal_id,
+        ctx.accounts.approver.key(),
+        proposal.approval_count,
+        ctx.accounts.governance.threshold
+    );
+    Ok(())
+}
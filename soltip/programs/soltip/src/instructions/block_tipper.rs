@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, BlockedTipper};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct TipperBlockedEvent {
+    pub profile:   Pubkey,
+    pub wallet:    Pubkey,
+    pub blocked:   bool,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct BlockTipper<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = BlockedTipper::LEN,
+        seeds = [BLOCKED_TIPPER_SEED, tip_profile.key().as_ref(), wallet.as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: Account<'info, BlockedTipper>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Block `wallet` from tipping, voting on, contributing to or
+/// subscribing to this profile. The owner pays the entry's rent.
+pub fn handler(ctx: Context<BlockTipper>, wallet: Pubkey) -> Result<()> {
+    require!(wallet != ctx.accounts.owner.key(), ErrorCode::CannotBlockSelf);
+
+    let clock   = Clock::get()?;
+    let profile = ctx.accounts.tip_profile.key();
+    ctx.accounts.blocked_tipper.initialize(profile, wallet, clock.unix_timestamp, ctx.bumps.blocked_tipper);
+
+    emit!(TipperBlockedEvent {
+        profile,
+        wallet,
+        blocked:   true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Blocked {} on @{}", wallet, ctx.accounts.tip_profile.username);
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{Governance, AdminProposal};
+use crate::constants::*;
+
+/// Any council member may cancel an unexecuted proposal, e.g. to veto
+/// a malicious action during its timelock.
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump  = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED, proposal.proposal_id.to_le_bytes().as_ref()],
+        bump  = proposal.bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+pub fn handler(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    ctx.accounts.governance.approver_index(&ctx.accounts.approver.key())?;
+    ctx.accounts.proposal.cancel()?;
+
+    msg!("Admin proposal {} cancelled by {}", ctx.accounts.proposal.proposal_id, ctx.accounts.approver.key());
+    Ok(())
+}
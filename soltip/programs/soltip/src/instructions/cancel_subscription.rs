@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{TipProfile, Subscription};
+use anchor_spl::token::{self, Revoke, Token, TokenAccount};
+use crate::state::{TipProfile, Subscription, SubscriptionTier, release_tier_slot};
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -12,6 +13,7 @@ pub struct CancelSubscription<'info> {
 
     /// The recipient's tip profile
     #[account(
+        mut,
         seeds = [TIP_PROFILE_SEED, recipient_owner.key().as_ref()],
         bump = recipient_profile.bump,
     )]
@@ -31,22 +33,58 @@ pub struct CancelSubscription<'info> {
         close = subscriber,
     )]
     pub subscription: Account<'info, Subscription>,
+
+    /// The tier the subscription belongs to (member count is decremented).
+    /// Omitted for subscriptions created before tiers, which have none.
+    #[account(
+        mut,
+        address = subscription.tier @ ErrorCode::SubscriptionTierMismatch,
+    )]
+    pub subscription_tier: Option<Account<'info, SubscriptionTier>>,
+
+    /// SPL only: subscriber's token account – delegate approval is revoked
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 /// Handler for cancelling a subscription
 pub fn handler(ctx: Context<CancelSubscription>) -> Result<()> {
-    // Validate feature is enabled
-    require!(ENABLE_SUBSCRIPTIONS, ErrorCode::SubscriptionsDisabled);
-
     let subscription = &mut ctx.accounts.subscription;
 
-    subscription.cancel()?;
+    if subscription.cancel() {
+        release_tier_slot(subscription.tier, ctx.accounts.subscription_tier.as_mut())?;
+        ctx.accounts.recipient_profile.remove_subscriber();
+    }
+
+    // Revoke the subscription PDA's delegate approval if it is still the delegate
+    if let (Some(source), Some(token_program)) = (
+        ctx.accounts.subscriber_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) {
+        if subscription.is_spl && source.delegate.contains(&subscription.key()) {
+            let cpi = CpiContext::new(
+                token_program.to_account_info(),
+                Revoke {
+                    source:    source.to_account_info(),
+                    authority: ctx.accounts.subscriber.to_account_info(),
+                },
+            );
+            token::revoke(cpi)?;
+            msg!("Delegate approval revoked");
+        }
+    }
 
     msg!("Subscription cancelled");
     msg!("Subscriber: {}", subscription.subscriber);
     msg!("Recipient: {}", ctx.accounts.recipient_profile.username);
     msg!("Total paid: {} lamports", subscription.total_paid);
     msg!("Payment count: {}", subscription.payment_count);
+    if subscription.escrow_balance > 0 {
+        msg!("Escrow returned: {} lamports", subscription.escrow_balance);
+    }
 
     Ok(())
 }
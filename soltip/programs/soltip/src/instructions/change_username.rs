@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, UsernameRegistry};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct UsernameChangedEvent {
+    pub owner:        Pubkey,
+    pub profile:      Pubkey,
+    pub old_username: String,
+    pub new_username: String,
+    pub timestamp:    i64,
+}
+
+/// Accounts required to move a profile to a new username.
+///
+/// The new name is claimed in its registry PDA and the old registry
+/// entry is closed, returning its rent to the owner and freeing the
+/// handle for others. A profile created before the registry passes
+/// its current username to claim the entry it never had.
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsername<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// CHECK: registry PDA of the current username. May be empty for
+    /// profiles created before the registry; released only if it
+    /// belongs to this profile (see UsernameRegistry::release).
+    #[account(
+        mut,
+        seeds = [USERNAME_REGISTRY_SEED, tip_profile.username.as_bytes()],
+        bump,
+    )]
+    pub old_username_registry: UncheckedAccount<'info>,
+
+    /// Registry PDA of the new username
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = UsernameRegistry::LEN,
+        seeds = [USERNAME_REGISTRY_SEED, new_username.as_bytes()],
+        bump
+    )]
+    pub new_username_registry: Account<'info, UsernameRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for changing a profile's username
+pub fn handler(ctx: Context<ChangeUsername>, new_username: String) -> Result<()> {
+    require!(new_username.len() <= MAX_USERNAME_LENGTH, ErrorCode::UsernameTooLong);
+    require!(!new_username.is_empty(), ErrorCode::EmptyUsername);
+    require!(validate_username(&new_username), ErrorCode::InvalidUsername);
+
+    let clock       = Clock::get()?;
+    let profile_key = ctx.accounts.tip_profile.key();
+    let old_username = ctx.accounts.tip_profile.username.clone();
+    let renaming     = new_username != old_username;
+    require!(
+        renaming || ctx.accounts.new_username_registry.profile != profile_key,
+        ErrorCode::UsernameUnchanged
+    );
+
+    ctx.accounts.new_username_registry.claim(
+        new_username.clone(),
+        profile_key,
+        ctx.accounts.owner.key(),
+        clock.unix_timestamp,
+        ctx.bumps.new_username_registry,
+    )?;
+
+    if !renaming {
+        msg!("Legacy username registered: @{}", new_username);
+        return Ok(());
+    }
+
+    UsernameRegistry::release(
+        &ctx.accounts.old_username_registry.to_account_info(),
+        profile_key,
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    let tip_profile = &mut ctx.accounts.tip_profile;
+    tip_profile.username   = new_username.clone();
+    tip_profile.updated_at = clock.unix_timestamp;
+
+    emit!(UsernameChangedEvent {
+        owner:        ctx.accounts.owner.key(),
+        profile:      profile_key,
+        old_username: old_username.clone(),
+        new_username: new_username.clone(),
+        timestamp:    clock.unix_timestamp,
+    });
+
+    msg!("Username changed: @{} -> @{}", old_username, new_username);
+    Ok(())
+}
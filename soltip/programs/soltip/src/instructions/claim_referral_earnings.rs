@@ -0,0 +1,56 @@
+// ==========================================================
+// claim_referral_earnings – pay out accrued SOL referral fees
+//
+// SOL referral shares accumulate as lamports on the Referral PDA
+// whenever a referred creator's platform fee is charged. The
+// referrer claims them here. SPL shares are paid out at charge
+// time and never accrue.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::Referral;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct ReferralEarningsClaimedEvent {
+    pub referrer:        Pubkey,
+    pub referral:        Pubkey,
+    pub amount:          u64,
+    pub total_earned:    u64,
+    pub timestamp:       i64,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralEarnings<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, referrer.key().as_ref(), referral.referee_profile.as_ref()],
+        bump  = referral.bump,
+        has_one = referrer @ ErrorCode::Unauthorized,
+    )]
+    pub referral: Account<'info, Referral>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+    let amount = ctx.accounts.referral.claim()?;
+
+    // Direct lamport manipulation for PDA accounts with data
+    **ctx.accounts.referral.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let clock = Clock::get()?;
+    emit!(ReferralEarningsClaimedEvent {
+        referrer:     ctx.accounts.referrer.key(),
+        referral:     ctx.accounts.referral.key(),
+        amount,
+        total_earned: ctx.accounts.referral.total_earned,
+        timestamp:    clock.unix_timestamp,
+    });
+
+    msg!("Referral earnings claimed: {} lamports", amount);
+    Ok(())
+}
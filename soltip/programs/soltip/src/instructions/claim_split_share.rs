@@ -0,0 +1,70 @@
+// ==========================================================
+// claim_split_share – recipient pulls their accrued split share
+//
+// In accrual mode split tips sit in the creator's vault with
+// each recipient's share tracked on the TipSplit. Recipients
+// claim on their own schedule; claims are fee-free and work
+// even after the split is deactivated or switched back to push.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, Vault, TipSplit};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct SplitShareClaimedEvent {
+    pub profile:   Pubkey,
+    pub recipient: Pubkey,
+    pub amount:    u64,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSplitShare<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        seeds = [TIP_PROFILE_SEED, tip_profile.owner.as_ref()],
+        bump  = tip_profile.bump,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, tip_profile.key().as_ref()],
+        bump  = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds      = [TIP_SPLIT_SEED, tip_profile.key().as_ref()],
+        bump       = tip_split.bump,
+        constraint = tip_split.profile == tip_profile.key() @ ErrorCode::SplitNotFound,
+    )]
+    pub tip_split: Account<'info, TipSplit>,
+}
+
+pub fn handler(ctx: Context<ClaimSplitShare>) -> Result<()> {
+    let clock     = Clock::get()?;
+    let recipient = ctx.accounts.recipient.key();
+
+    let amount = ctx.accounts.tip_split.claim(&recipient)?;
+    ctx.accounts.vault.release_split(amount)?;
+
+    // Direct lamport manipulation: the vault PDA is program-owned and holds data
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(SplitShareClaimedEvent {
+        profile:   ctx.accounts.tip_profile.key(),
+        recipient,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Split share claimed: {} lamports → {}", amount, recipient);
+    Ok(())
+}
@@ -27,8 +27,6 @@ pub struct CloseContentGate<'info> {
 }
 
 pub fn handler(ctx: Context<CloseContentGate>) -> Result<()> {
-    require!(ENABLE_CONTENT_GATES, ErrorCode::ContentGatesDisabled);
-
     ctx.accounts.tip_profile.decrement_gates()?;
 
     msg!(
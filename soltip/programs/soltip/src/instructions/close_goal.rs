@@ -36,8 +36,8 @@ pub fn handler(ctx: Context<CloseGoal>) -> Result<()> {
     let tip_profile = &mut ctx.accounts.tip_profile;
     let tip_goal = &ctx.accounts.tip_goal;
 
-    // Validate feature is enabled
-    require!(ENABLE_GOALS, ErrorCode::GoalsDisabled);
+    // Escrowed funds must be released or refunded before the goal PDA is closed
+    require!(tip_goal.escrow_balance == 0, ErrorCode::GoalEscrowNotEmpty);
 
     // Decrement active goals count
     tip_profile.decrement_goals()?;
@@ -0,0 +1,111 @@
+// ==========================================================
+// close_lapsed_subscription – creator-side cleanup of dead subs
+//
+// A subscriber who stops paying (or whose one-shot subscription
+// completed) never has to call cancel_subscription, which would
+// leave the profile's active_subscriptions counter stuck and
+// block close_profile. The creator can close such a subscription
+// once it has lapsed past its grace period or ended: the tier
+// slot and subscriber count are released, and the escrow and
+// rent go back to the subscriber.
+//
+// For SPL subscriptions the subscriber's delegate approval to
+// the subscription PDA is NOT revoked: SPL Token only accepts a
+// revoke signed by the token account owner, and the subscriber
+// is not a signer here. The leftover allowance is unusable while
+// the PDA is closed (only this program signs for it, and only
+// for a live subscription), but it comes back into force if the
+// subscriber re-subscribes to the same profile. Subscribers
+// should revoke it themselves, or use cancel_subscription, which
+// revokes it.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, Subscription, SubscriptionStatus, SubscriptionTier, release_tier_slot};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct LapsedSubscriptionClosedEvent {
+    pub recipient_profile: Pubkey,
+    pub subscriber:        Pubkey,
+    pub subscription:      Pubkey,
+    pub escrow_returned:   u64,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct CloseLapsedSubscription<'info> {
+    /// The creator (profile owner)
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = recipient_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub recipient_profile: Account<'info, TipProfile>,
+
+    /// CHECK: must match subscription.subscriber; receives escrow and rent
+    #[account(
+        mut,
+        address = subscription.subscriber @ ErrorCode::NotSubscriber,
+    )]
+    pub subscriber: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, subscriber.key().as_ref(), recipient_profile.key().as_ref()],
+        bump  = subscription.bump,
+        constraint = subscription.recipient_profile == recipient_profile.key() @ ErrorCode::InvalidAccountData,
+        close = subscriber,
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// The tier the subscription belongs to (member count is decremented).
+    /// Omitted for subscriptions created before tiers, which have none.
+    #[account(
+        mut,
+        address = subscription.tier @ ErrorCode::SubscriptionTierMismatch,
+    )]
+    pub subscription_tier: Option<Account<'info, SubscriptionTier>>,
+}
+
+pub fn handler(ctx: Context<CloseLapsedSubscription>) -> Result<()> {
+    let clock = Clock::get()?;
+    let subscription = &mut ctx.accounts.subscription;
+
+    require!(
+        matches!(
+            subscription.current_status(clock.unix_timestamp),
+            SubscriptionStatus::Lapsed | SubscriptionStatus::Cancelled
+        ),
+        ErrorCode::SubscriptionNotLapsed
+    );
+
+    if subscription.cancel() {
+        release_tier_slot(subscription.tier, ctx.accounts.subscription_tier.as_mut())?;
+        ctx.accounts.recipient_profile.remove_subscriber();
+    }
+
+    // Revoking needs the subscriber's signature; see the header
+    if subscription.is_spl {
+        msg!("Delegate approval left in place; the subscriber can revoke it");
+    }
+
+    emit!(LapsedSubscriptionClosedEvent {
+        recipient_profile: ctx.accounts.recipient_profile.key(),
+        subscriber:        subscription.subscriber,
+        subscription:      subscription.key(),
+        escrow_returned:   subscription.escrow_balance,
+        timestamp:         clock.unix_timestamp,
+    });
+
+    msg!(
+        "Lapsed subscription {} closed by creator | escrow returned: {} lamports",
+        subscription.key(),
+        subscription.escrow_balance
+    );
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::MatchingPool;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct MatchingPoolClosedEvent {
+    pub pool:           Pubkey,
+    pub sponsor:        Pubkey,
+    pub matched_amount: u64,
+    /// Unused funds returned to the sponsor
+    pub returned:       u64,
+    pub timestamp:      i64,
+}
+
+#[derive(Accounts)]
+pub struct CloseMatchingPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [MATCHING_POOL_SEED, matching_pool.profile.as_ref(), sponsor.key().as_ref(), matching_pool.pool_id.to_le_bytes().as_ref()],
+        bump    = matching_pool.bump,
+        has_one = sponsor @ ErrorCode::Unauthorized,
+        close   = sponsor,
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+}
+
+/// Close an expired pool. The unmatched balance and the rent go back to
+/// the sponsor.
+pub fn handler(ctx: Context<CloseMatchingPool>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool  = &ctx.accounts.matching_pool;
+    require!(pool.is_expired(clock.unix_timestamp), ErrorCode::MatchingPoolNotExpired);
+
+    emit!(MatchingPoolClosedEvent {
+        pool:           pool.key(),
+        sponsor:        pool.sponsor,
+        matched_amount: pool.matched_amount,
+        returned:       pool.remaining(),
+        timestamp:      clock.unix_timestamp,
+    });
+
+    msg!("Matching pool closed: {} matched, {} returned", pool.matched_amount, pool.remaining());
+    Ok(())
+}
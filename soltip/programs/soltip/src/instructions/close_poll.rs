@@ -27,8 +27,6 @@ pub struct ClosePoll<'info> {
 }
 
 pub fn handler(ctx: Context<ClosePoll>) -> Result<()> {
-    require!(ENABLE_POLLS, ErrorCode::PollsDisabled);
-
     // Deactivate the poll before account closure
     if ctx.accounts.tip_poll.is_active {
         ctx.accounts.tip_poll.deactivate()?;
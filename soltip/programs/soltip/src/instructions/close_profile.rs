@@ -0,0 +1,227 @@
+// ==========================================================
+// close_profile – shut down a creator profile and reclaim rent
+//
+// Requires every dependent account to be wound down first:
+// no active goals, polls, content gates, subscribers or accepted
+// bounties.
+//
+// The vault, tip split and pinned slot are always passed at their
+// PDA addresses and closed whenever they exist, so none can be
+// left behind. The vault's withdrawable balance is paid out
+// through the normal withdraw fee path before it is closed; the
+// rent buffer and the creator-retained part of the fee come back
+// with the vault rent. Accrued split shares must all be claimed
+// first. The username is released. All rent goes to the owner.
+//
+// Subscription tiers are keyed by creator-chosen ids the profile
+// does not record, so they cannot be found here: the owner passes
+// them as remaining_accounts and each (memberless) tier is closed.
+// A tier left out stays open, holding only its rent.
+//
+// SPL vaults are not touched: they stay reachable through
+// withdraw_spl if the owner re-creates the profile.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, Vault, TipSplit, PinnedSlot, SubscriptionTier, UsernameRegistry, Referral, check_referral, referral_share};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct ProfileClosedEvent {
+    pub owner:         Pubkey,
+    pub profile:       Pubkey,
+    pub username:      String,
+    /// Vault balance paid out through the fee path (0 if no vault)
+    pub drained:       u64,
+    pub fee:           u64,
+    pub creator_share: u64,
+    pub timestamp:     i64,
+}
+
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+        close   = owner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// CHECK: registry PDA of the profile's username; released if it
+    /// belongs to this profile (see UsernameRegistry::release)
+    #[account(
+        mut,
+        seeds = [USERNAME_REGISTRY_SEED, tip_profile.username.as_bytes()],
+        bump,
+    )]
+    pub username_registry: UncheckedAccount<'info>,
+
+    /// CHECK: the profile's SOL vault PDA; drained and closed if it exists
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, tip_profile.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: the profile's tip split PDA; closed if it exists
+    #[account(
+        mut,
+        seeds = [TIP_SPLIT_SEED, tip_profile.key().as_ref()],
+        bump,
+    )]
+    pub tip_split: UncheckedAccount<'info>,
+
+    /// CHECK: the profile's pinned-message slot PDA; closed if it exists
+    #[account(
+        mut,
+        seeds = [PINNED_SLOT_SEED, tip_profile.key().as_ref()],
+        bump,
+    )]
+    pub pinned_slot: UncheckedAccount<'info>,
+
+    /// Global platform config – source of the fee BPS and treasury.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury
+    #[account(
+        mut,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseProfile<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    ctx.accounts.tip_profile.ensure_closable()?;
+
+    let mut drained       = 0u64;
+    let mut total_fee     = 0u64;
+    let mut creator_share = 0u64;
+
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let vault_info = ctx.accounts.vault.to_account_info();
+    if let Some(mut vault) = load_existing::<Vault>(&vault_info)? {
+        require!(vault.owner == owner_info.key(), ErrorCode::NotProfileOwner);
+        // Split recipients must claim first; their lamports are in the vault
+        require!(vault.split_reserved == 0, ErrorCode::SplitSharesUnclaimed);
+        drained = vault.withdrawable();
+        if drained > 0 {
+            let fee_bps  = ctx.accounts.tip_profile.withdrawal_fee_bps;
+            total_fee    = calculate_fee(drained, fee_bps)?;
+            let platform_fee = calculate_fee(total_fee, ctx.accounts.platform_config.platform_fee_bps)?;
+            creator_share = drained.checked_sub(total_fee).ok_or(ErrorCode::MathUnderflow)?;
+
+            check_referral(ctx.accounts.tip_profile.referral, ctx.accounts.referral.as_ref())?;
+            let referrer_cut = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+            let treasury_fee = platform_fee.checked_sub(referrer_cut).ok_or(ErrorCode::MathUnderflow)?;
+
+            let actual_out = creator_share
+                .checked_add(platform_fee)
+                .ok_or(ErrorCode::MathOverflow)?;
+            vault.withdraw(actual_out)?;
+
+            // Direct lamport manipulation for PDA accounts with data
+            **vault_info.try_borrow_mut_lamports()? -= creator_share;
+            **owner_info.try_borrow_mut_lamports()? += creator_share;
+
+            if treasury_fee > 0 {
+                **vault_info.try_borrow_mut_lamports()? -= treasury_fee;
+                **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += treasury_fee;
+            }
+
+            if let (true, Some(referral)) = (referrer_cut > 0, ctx.accounts.referral.as_mut()) {
+                **vault_info.try_borrow_mut_lamports()? -= referrer_cut;
+                **referral.to_account_info().try_borrow_mut_lamports()? += referrer_cut;
+                referral.accrue(referrer_cut)?;
+            }
+        }
+        close_account(&vault_info, &owner_info)?;
+    }
+
+    let split_info = ctx.accounts.tip_split.to_account_info();
+    if let Some(split) = load_existing::<TipSplit>(&split_info)? {
+        // Closing the split would erase what recipients are still owed
+        require!(split.claimable.iter().all(|owed| *owed == 0), ErrorCode::SplitSharesUnclaimed);
+        close_account(&split_info, &owner_info)?;
+    }
+
+    let pin_info = ctx.accounts.pinned_slot.to_account_info();
+    if load_existing::<PinnedSlot>(&pin_info)?.is_some() {
+        close_account(&pin_info, &owner_info)?;
+    }
+
+    let profile_key = ctx.accounts.tip_profile.key();
+    for tier_info in ctx.remaining_accounts {
+        let tier = load_existing::<SubscriptionTier>(tier_info)?
+            .ok_or(ErrorCode::SubscriptionTierMismatch)?;
+        require_keys_eq!(tier.profile, profile_key, ErrorCode::SubscriptionTierMismatch);
+        require!(tier.member_count == 0, ErrorCode::SubscriptionTierHasMembers);
+        close_account(tier_info, &owner_info)?;
+    }
+
+    UsernameRegistry::release(
+        &ctx.accounts.username_registry.to_account_info(),
+        ctx.accounts.tip_profile.key(),
+        &owner_info,
+    )?;
+
+    // The profile is closed and its rent returned to the owner via the
+    // close constraint
+
+    let profile = &ctx.accounts.tip_profile;
+    emit!(ProfileClosedEvent {
+        owner:    ctx.accounts.owner.key(),
+        profile:  profile.key(),
+        username: profile.username.clone(),
+        drained,
+        fee:      total_fee,
+        creator_share,
+        timestamp: ts,
+    });
+
+    msg!("Profile closed: @{} | drained: {} | fee: {}", profile.username, drained, total_fee);
+    Ok(())
+}
+
+/// Deserialize a program account passed at its PDA address, or `None`
+/// if it was never created.
+fn load_existing<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(T::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+/// Return all of `info`'s lamports to `destination` and wipe it – the
+/// same steps as Anchor's `close` constraint.
+fn close_account<'info>(info: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = info.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ErrorCode::MathOverflow)?;
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&anchor_lang::system_program::ID);
+    info.realloc(0, false)?;
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, PinnedSlot};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct PinnedSlotConfiguredEvent {
+    pub profile:           Pubkey,
+    pub duration_seconds:  i64,
+    pub min_bid:           u64,
+    pub min_increment_bps: u16,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePinnedSlot<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = PinnedSlot::LEN,
+        seeds = [PINNED_SLOT_SEED, tip_profile.key().as_ref()],
+        bump,
+    )]
+    pub pinned_slot: Account<'info, PinnedSlot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open (or retune) the profile's pinned-message auction. New rules apply
+/// from the next bid; a live pin runs out on its original schedule.
+pub fn handler(
+    ctx: Context<ConfigurePinnedSlot>,
+    duration_seconds: i64,
+    min_bid: u64,
+    min_increment_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let profile = ctx.accounts.tip_profile.key();
+    ctx.accounts.pinned_slot.configure(
+        profile,
+        duration_seconds,
+        min_bid,
+        min_increment_bps,
+        ctx.bumps.pinned_slot,
+    )?;
+
+    emit!(PinnedSlotConfiguredEvent {
+        profile,
+        duration_seconds,
+        min_bid,
+        min_increment_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pinned slot for {}: {}s, min bid {}, +{} bps", ctx.accounts.tip_profile.username, duration_seconds, min_bid, min_increment_bps);
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::{TipProfile, TipSplit, SplitRecipient};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -16,7 +17,7 @@ pub struct ConfigureSplit<'info> {
     pub tip_profile: Account<'info, TipProfile>,
 
     #[account(
-        init_if_needed,
+        init,
         payer  = owner,
         space  = TipSplit::LEN,
         seeds  = [TIP_SPLIT_SEED, tip_profile.key().as_ref()],
@@ -24,26 +25,30 @@ pub struct ConfigureSplit<'info> {
     )]
     pub tip_split: Account<'info, TipSplit>,
 
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<ConfigureSplit>,
     recipients: Vec<SplitRecipient>,
+    accrue: bool,
 ) -> Result<()> {
-    require!(ENABLE_TIP_SPLITS, ErrorCode::TipSplitsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_TIP_SPLITS), ErrorCode::TipSplitsDisabled);
 
     let profile_key = ctx.accounts.tip_profile.key();
-    if ctx.accounts.tip_split.num_recipients == 0 {
-        ctx.accounts.tip_split.initialize(
-            profile_key,
-            recipients,
-            ctx.bumps.tip_split,
-        )?;
-        msg!("Tip split configured for profile: {}", ctx.accounts.tip_profile.username);
-    } else {
-        ctx.accounts.tip_split.update(recipients)?;
-        msg!("Tip split updated for profile: {}", ctx.accounts.tip_profile.username);
-    }
+    ctx.accounts.tip_split.initialize(
+        profile_key,
+        recipients,
+        accrue,
+        ctx.bumps.tip_split,
+    )?;
+    msg!("Tip split configured for profile: {}", ctx.accounts.tip_profile.username);
     Ok(())
 }
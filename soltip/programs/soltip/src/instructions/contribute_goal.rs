@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::{TipProfile, TipGoal};
+use crate::state::{
+    TipProfile, TipGoal, GoalContribution, BlockedTipper, Referral, check_referral, referral_share,
+    MatchingPool, MatchRecord, matching_pool_key, apply_match,
+};
 use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::create_matching_pool::MatchedEvent;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
-/// Emitted when a contributor sends SOL toward a fundraising goal.
+/// Emitted when a contributor sends SOL or SPL tokens toward a fundraising goal.
 /// Indexers use this event to track goal progress and contributor history.
 #[event]
 pub struct GoalContributionEvent {
@@ -19,8 +23,12 @@ pub struct GoalContributionEvent {
     pub tip_goal:           Pubkey,
     /// Numeric goal identifier (used in PDA derivation)
     pub goal_id:            u64,
-    /// Contribution amount in lamports
+    /// Goal token mint (System Program for SOL)
+    pub token_mint:         Pubkey,
+    /// Contribution amount in lamports or token base units
     pub amount:             u64,
+    /// Whether the contribution is held in goal escrow
+    pub escrowed:           bool,
     /// Running total after this contribution
     pub current_amount:     u64,
     /// Whether this contribution completed the goal
@@ -54,11 +62,29 @@ pub struct ContributeGoal<'info> {
     )]
     pub tip_goal: Account<'info, TipGoal>,
 
+    /// Per-(goal, contributor) record: init on first contribution, else mut
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = GoalContribution::LEN,
+        seeds = [GOAL_CONTRIBUTION_SEED, tip_goal.key().as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub goal_contribution: Account<'info, GoalContribution>,
+
     /// The recipient's wallet (profile owner)
     /// CHECK: This is validated by the PDA derivation of recipient_profile
     #[account(mut)]
     pub recipient_owner: UncheckedAccount<'info>,
 
+    /// CHECK: blocklist PDA for this contributor; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
     /// Global platform config – checked for pause state and fee BPS.
     #[account(
         seeds = [PLATFORM_CONFIG_SEED],
@@ -66,19 +92,45 @@ pub struct ContributeGoal<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    /// CHECK: PDA verified by seeds – platform treasury receives platform fee
+    /// CHECK: must match platform_config.treasury
     #[account(
         mut,
-        seeds = [PLATFORM_TREASURY_SEED],
-        bump,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
     )]
     pub platform_treasury: UncheckedAccount<'info>,
 
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// Sponsor pool matching contributions to this goal
+    #[account(
+        mut,
+        constraint = matching_pool.goal == tip_goal.key() @ ErrorCode::MatchingPoolMismatch,
+    )]
+    pub matching_pool: Option<Account<'info, MatchingPool>>,
+
+    /// Per-(pool, contributor) match total; required with matching_pool
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = MatchRecord::LEN,
+        seeds = [MATCH_RECORD_SEED, matching_pool_key(&matching_pool).as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub match_record: Option<Account<'info, MatchRecord>>,
+
     /// System program for transferring SOL
     pub system_program: Program<'info, System>,
 }
 
-/// Handler for contributing to a fundraising goal (SOL only in this example)
+/// Handler for contributing to a fundraising goal (SOL).
+/// Escrowed goals hold the full contribution in the goal PDA; the platform
+/// fee is taken when the escrow is released (see release_goal_escrow).
+/// Non-escrowed goals pay the creator and treasury immediately. A matching
+/// pool for the goal adds its match fee-free and the match counts toward
+/// the goal: straight to the creator, or into escrow for an escrowed goal
+/// so a failed goal can hand it back to the pool (reclaim_goal_match).
 pub fn handler(
     ctx: Context<ContributeGoal>,
     amount: u64,
@@ -94,11 +146,14 @@ pub fn handler(
         ErrorCode::CannotTipSelf
     );
 
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
     // Platform pause check
     require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
 
     // Validate feature is enabled
-    require!(ENABLE_GOALS, ErrorCode::GoalsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_GOALS), ErrorCode::GoalsDisabled);
 
     // Validate contribution amount
     require!(
@@ -117,46 +172,133 @@ pub fn handler(
     // Validate goal can accept contributions
     tip_goal.validate_can_contribute(clock.unix_timestamp)?;
 
-    // Calculate platform fee and creator share
-    let platform_fee = calculate_fee(amount, PLATFORM_FEE_BPS)?;
-    let creator_share = amount
-        .checked_sub(platform_fee)
-        .ok_or(ErrorCode::MathUnderflow)?;
-
-    // Transfer creator share to recipient
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.contributor.to_account_info(),
-            to: ctx.accounts.recipient_owner.to_account_info(),
-        },
-    );
-    transfer(transfer_ctx, creator_share)?;
+    // SPL goals must be funded through contribute_goal_spl
+    require!(tip_goal.is_sol(), ErrorCode::TokenMintMismatch);
+
+    if tip_goal.escrowed {
+        // Hold the full contribution in the goal PDA until completion or refund
+        let escrow_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.contributor.to_account_info(),
+                to: tip_goal.to_account_info(),
+            },
+        );
+        transfer(escrow_ctx, amount)?;
+        tip_goal.deposit_escrow(amount)?;
+    } else {
+        // Calculate platform fee and creator share
+        let platform_fee = calculate_fee(amount, ctx.accounts.platform_config.platform_fee_bps)?;
+        let creator_share = amount
+            .checked_sub(platform_fee)
+            .ok_or(ErrorCode::MathUnderflow)?;
 
-    // Transfer platform fee to treasury
-    if platform_fee > 0 {
-        let fee_ctx = CpiContext::new(
+        // Referrer's share of the platform fee, if the creator was referred
+        check_referral(recipient_profile.referral, ctx.accounts.referral.as_ref())?;
+        let referrer_cut = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+        let treasury_fee = platform_fee
+            .checked_sub(referrer_cut)
+            .ok_or(ErrorCode::MathUnderflow)?;
+
+        // Transfer creator share to recipient
+        let transfer_ctx = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.contributor.to_account_info(),
-                to: ctx.accounts.platform_treasury.to_account_info(),
+                to: ctx.accounts.recipient_owner.to_account_info(),
             },
         );
-        transfer(fee_ctx, platform_fee)?;
+        transfer(transfer_ctx, creator_share)?;
+
+        // Transfer platform fee to treasury
+        if treasury_fee > 0 {
+            let fee_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.contributor.to_account_info(),
+                    to: ctx.accounts.platform_treasury.to_account_info(),
+                },
+            );
+            transfer(fee_ctx, treasury_fee)?;
+        }
+
+        // Referral share accrues on the Referral PDA
+        if let (true, Some(referral)) = (referrer_cut > 0, ctx.accounts.referral.as_mut()) {
+            let ref_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.contributor.to_account_info(),
+                    to: referral.to_account_info(),
+                },
+            );
+            transfer(ref_ctx, referrer_cut)?;
+            referral.accrue(referrer_cut)?;
+        }
     }
 
-    // Record contribution in goal (unique contributor tracking is best-effort;
-    // a full deduplication system would require a per-(contributor, goal) PDA)
-    tip_goal.add_contribution(amount, false, clock.unix_timestamp)?;
+    // Per-contributor record – init or update (same pattern as TipperRecord)
+    let contributor_key = ctx.accounts.contributor.key();
+    let is_new_contributor = ctx.accounts.goal_contribution.contribution_count == 0;
+    if is_new_contributor {
+        ctx.accounts.goal_contribution.initialize(
+            contributor_key,
+            tip_goal.key(),
+            amount,
+            clock.unix_timestamp,
+            ctx.bumps.goal_contribution,
+        );
+    } else {
+        ctx.accounts.goal_contribution.record_contribution(amount, clock.unix_timestamp)?;
+    }
+
+    // Sponsor match → creator (or goal escrow), counted toward the goal
+    let match_to = if tip_goal.escrowed {
+        tip_goal.to_account_info()
+    } else {
+        ctx.accounts.recipient_owner.to_account_info()
+    };
+    let matched = apply_match(
+        ctx.accounts.matching_pool.as_mut(),
+        ctx.accounts.match_record.as_mut(),
+        ctx.bumps.match_record,
+        contributor_key,
+        &match_to,
+        amount,
+        clock.unix_timestamp,
+    )?;
+    if let (true, Some(pool)) = (matched > 0, ctx.accounts.matching_pool.as_ref()) {
+        if tip_goal.escrowed {
+            tip_goal.deposit_match_escrow(matched)?;
+        }
+        recipient_profile.record_matched(matched)?;
+        emit!(MatchedEvent {
+            pool:              pool.key(),
+            sponsor:           pool.sponsor,
+            tipper:            contributor_key,
+            recipient_profile: recipient_profile.key(),
+            goal:              tip_goal.key(),
+            amount,
+            matched_amount:    matched,
+            pool_matched:      pool.matched_amount,
+            pool_remaining:    pool.remaining(),
+            timestamp:         clock.unix_timestamp,
+        });
+        msg!("Matched: {} lamports by {}", matched, pool.sponsor);
+    }
+
+    // Record contribution (and any match) in goal
+    let goal_amount = amount.checked_add(matched).ok_or(ErrorCode::MathOverflow)?;
+    tip_goal.add_contribution(goal_amount, is_new_contributor, clock.unix_timestamp)?;
 
     // Record tip in profile (contributor = tipper for leaderboard)
-    let contributor_key = ctx.accounts.contributor.key();
     recipient_profile.record_tip(contributor_key, amount, false)?;
 
     // Capture values for event before references are released
     let current_amount = tip_goal.current_amount;
     let goal_id        = tip_goal.goal_id;
     let goal_completed = tip_goal.completed;
+    let escrowed       = tip_goal.escrowed;
+    let token_mint     = tip_goal.token_mint;
 
     msg!("Contribution to goal '{}': {} lamports", tip_goal.title, amount);
     msg!("Goal progress: {}/{}  ({}%)",
@@ -175,7 +317,9 @@ pub fn handler(
         recipient_profile: ctx.accounts.recipient_profile.key(),
         tip_goal:          ctx.accounts.tip_goal.key(),
         goal_id,
+        token_mint,
         amount,
+        escrowed,
         current_amount,
         goal_completed,
         message:           message.clone(),
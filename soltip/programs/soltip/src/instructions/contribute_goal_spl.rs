@@ -0,0 +1,260 @@
+// ==========================================================
+// contribute_goal_spl – SPL-token contribution to a goal
+//
+// Stablecoin counterpart of contribute_goal. The contribution
+// mint must match tip_goal.token_mint. The platform fee is
+// taken in tokens and sent to a treasury-owned token account;
+// the creator share goes to the creator's token account.
+//   platform_fee  = amount × platform_fee_bps / 10_000
+//   creator_share = amount − platform_fee
+// If the creator was referred, the referrer's share of
+// platform_fee goes to the referrer's token account.
+// Emits GoalContributionEvent with the mint.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer, TokenAccount};
+use crate::state::{
+    TipProfile, TipGoal, GoalContribution, Referral, BlockedTipper,
+    check_referral, referral_share, referrer_token_account,
+};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::contribute_goal::GoalContributionEvent;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ContributeGoalSpl<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Contributor's token account (source)
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key() @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = contributor_token_account.mint  == token_mint.key()  @ ErrorCode::TokenMintMismatch,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// The recipient's tip profile
+    #[account(
+        mut,
+        seeds = [TIP_PROFILE_SEED, recipient_owner.key().as_ref()],
+        bump  = recipient_profile.bump,
+    )]
+    pub recipient_profile: Account<'info, TipProfile>,
+
+    /// CHECK: validated by PDA derivation of recipient_profile
+    pub recipient_owner: UncheckedAccount<'info>,
+
+    /// The goal being contributed to
+    #[account(
+        mut,
+        seeds = [TIP_GOAL_SEED, recipient_profile.key().as_ref(), tip_goal.goal_id.to_le_bytes().as_ref()],
+        bump  = tip_goal.bump,
+        constraint = tip_goal.profile == recipient_profile.key() @ ErrorCode::InvalidAccountData,
+    )]
+    pub tip_goal: Account<'info, TipGoal>,
+
+    /// Must be the mint the goal was created with
+    #[account(
+        constraint = token_mint.key() == tip_goal.token_mint @ ErrorCode::TokenMintMismatch,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Per-(goal, contributor) record: init on first contribution, else mut
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = GoalContribution::LEN,
+        seeds = [GOAL_CONTRIBUTION_SEED, tip_goal.key().as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub goal_contribution: Account<'info, GoalContribution>,
+
+    /// Creator's token account (receives creator share)
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == recipient_owner.key() @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = recipient_token_account.mint  == token_mint.key()      @ ErrorCode::TokenMintMismatch,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: blocklist PDA for this contributor; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury; owner of the platform fee token account
+    #[account(
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Platform fee SPL token account (receives platform cut)
+    #[account(
+        mut,
+        constraint = platform_fee_token_account.owner == platform_treasury.key() @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = platform_fee_token_account.mint  == token_mint.key()        @ ErrorCode::TokenMintMismatch,
+    )]
+    pub platform_fee_token_account: Account<'info, TokenAccount>,
+
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// Referrer's token account (receives the referral share); required when one is due
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program:  Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ContributeGoalSpl>,
+    amount: u64,
+    message: Option<String>,
+) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_GOALS), ErrorCode::GoalsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    // Platform pause check
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    // Prevent self-contribution
+    require!(
+        ctx.accounts.contributor.key() != ctx.accounts.recipient_owner.key(),
+        ErrorCode::CannotTipSelf
+    );
+
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
+    require!(amount >= MIN_TIP_AMOUNT, ErrorCode::InvalidContributionAmount);
+    if let Some(ref m) = message {
+        require!(m.len() <= MAX_MESSAGE_LENGTH, ErrorCode::MessageTooLong);
+        require!(validate_text_content(m), ErrorCode::UnsafeTextContent);
+    }
+    require!(
+        ctx.accounts.contributor_token_account.amount >= amount,
+        ErrorCode::InsufficientTokenBalance
+    );
+
+    ctx.accounts.tip_goal.validate_can_contribute(ts)?;
+    require!(!ctx.accounts.tip_goal.is_sol(), ErrorCode::TokenMintMismatch);
+
+    let platform_fee  = calculate_fee(amount, ctx.accounts.platform_config.platform_fee_bps)?;
+    let creator_share = amount
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    check_referral(ctx.accounts.recipient_profile.referral, ctx.accounts.referral.as_ref())?;
+    let referrer_cut = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+    let treasury_fee = platform_fee
+        .checked_sub(referrer_cut)
+        .ok_or(ErrorCode::MathUnderflow)?;
+    let referrer_ta = referrer_token_account(
+        ctx.accounts.referral.as_ref(),
+        ctx.accounts.referrer_token_account.as_ref(),
+        ctx.accounts.token_mint.key(),
+        referrer_cut,
+    )?;
+
+    // Creator share: contributor → creator token account
+    let cpi = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SplTransfer {
+            from:      ctx.accounts.contributor_token_account.to_account_info(),
+            to:        ctx.accounts.recipient_token_account.to_account_info(),
+            authority: ctx.accounts.contributor.to_account_info(),
+        },
+    );
+    token::transfer(cpi, creator_share)?;
+
+    // Platform fee: contributor → platform fee token account
+    if treasury_fee > 0 {
+        let cpi_fee = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SplTransfer {
+                from:      ctx.accounts.contributor_token_account.to_account_info(),
+                to:        ctx.accounts.platform_fee_token_account.to_account_info(),
+                authority: ctx.accounts.contributor.to_account_info(),
+            },
+        );
+        token::transfer(cpi_fee, treasury_fee)?;
+    }
+
+    // Referral share: contributor → referrer token account
+    if let Some(referrer_ta) = referrer_ta {
+        let cpi_ref = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SplTransfer {
+                from:      ctx.accounts.contributor_token_account.to_account_info(),
+                to:        referrer_ta.to_account_info(),
+                authority: ctx.accounts.contributor.to_account_info(),
+            },
+        );
+        token::transfer(cpi_ref, referrer_cut)?;
+        if let Some(referral) = ctx.accounts.referral.as_mut() {
+            referral.record_spl_earning(referrer_cut)?;
+        }
+    }
+
+    // Per-contributor record – init or update
+    let contributor_key    = ctx.accounts.contributor.key();
+    let goal_key           = ctx.accounts.tip_goal.key();
+    let is_new_contributor = ctx.accounts.goal_contribution.contribution_count == 0;
+    if is_new_contributor {
+        ctx.accounts.goal_contribution.initialize(
+            contributor_key,
+            goal_key,
+            amount,
+            ts,
+            ctx.bumps.goal_contribution,
+        );
+    } else {
+        ctx.accounts.goal_contribution.record_contribution(amount, ts)?;
+    }
+
+    let tip_goal = &mut ctx.accounts.tip_goal;
+    tip_goal.add_contribution(amount, is_new_contributor, ts)?;
+    ctx.accounts.recipient_profile.record_spl_tip(amount)?;
+
+    let tip_goal = &ctx.accounts.tip_goal;
+    msg!("SPL contribution to goal '{}': {} tokens (mint: {})", tip_goal.title, amount, tip_goal.token_mint);
+    msg!("Goal progress: {}/{}", tip_goal.current_amount, tip_goal.target_amount);
+    if tip_goal.completed {
+        msg!("Goal completed!");
+    }
+
+    emit!(GoalContributionEvent {
+        contributor:       contributor_key,
+        recipient:         ctx.accounts.recipient_owner.key(),
+        recipient_profile: ctx.accounts.recipient_profile.key(),
+        tip_goal:          goal_key,
+        goal_id:           tip_goal.goal_id,
+        token_mint:        tip_goal.token_mint,
+        amount,
+        escrowed:          false,
+        current_amount:    tip_goal.current_amount,
+        goal_completed:    tip_goal.completed,
+        message,
+        timestamp:         ts,
+    });
+
+    Ok(())
+}
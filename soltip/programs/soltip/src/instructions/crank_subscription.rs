@@ -0,0 +1,302 @@
+// ==========================================================
+// crank_subscription – permissionless keeper path for due payments
+//
+// Any wallet may trigger a due subscription payment without the
+// subscriber signing:
+//   • SOL: drawn from the subscriber-prefunded escrow held by the
+//     subscription PDA (see fund_subscription).
+//   • SPL: pulled from the subscriber's token account via the
+//     delegate approval granted at create_subscription.
+//
+// If the escrow or delegate cannot cover the amount due and the
+// grace period has elapsed, the crank records the subscription
+// as lapsed instead of failing, so indexers and gated perks see
+// the missed payment.
+//
+// Fee model: same as process_subscription, with a keeper reward
+// carved out of the platform fee.
+//   platform_fee  = amount × platform_fee_bps / 10_000
+//   keeper_reward = platform_fee × KEEPER_REWARD_BPS / 10_000
+//   creator_share = amount − platform_fee
+// The referrer's share (if any) is also taken from platform_fee.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{
+    TipProfile, Subscription, SubscriptionStatus, SubscriptionTier, release_tier_slot, Referral,
+    check_referral, referral_share, referrer_token_account,
+};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::process_subscription::{SplPaymentAccounts, SubscriptionProcessedEvent};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// Emitted alongside SubscriptionProcessedEvent when a keeper cranks a payment.
+#[event]
+pub struct SubscriptionCrankedEvent {
+    pub keeper:        Pubkey,
+    pub subscription:  Pubkey,
+    pub keeper_reward: u64,
+    pub timestamp:     i64,
+}
+
+/// Emitted when a crank finds a past-grace subscription it cannot charge.
+#[event]
+pub struct SubscriptionLapsedEvent {
+    pub subscription:      Pubkey,
+    pub subscriber:        Pubkey,
+    pub recipient_profile: Pubkey,
+    /// Amount that could not be collected
+    pub amount_due:        u64,
+    /// Whole intervals currently unpaid
+    pub due_intervals:     u32,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct CrankSubscription<'info> {
+    /// Any wallet; receives the keeper reward
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// The recipient's tip profile
+    #[account(
+        mut,
+        seeds = [TIP_PROFILE_SEED, recipient_owner.key().as_ref()],
+        bump  = recipient_profile.bump,
+    )]
+    pub recipient_profile: Account<'info, TipProfile>,
+
+    /// CHECK: validated by PDA derivation of recipient_profile
+    #[account(mut)]
+    pub recipient_owner: UncheckedAccount<'info>,
+
+    /// The subscription being charged
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, subscription.subscriber.as_ref(), recipient_profile.key().as_ref()],
+        bump  = subscription.bump,
+        constraint = subscription.recipient_profile == recipient_profile.key() @ ErrorCode::InvalidAccountData,
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury
+    #[account(
+        mut,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// SPL only: subscriber's token account (subscription PDA is its delegate)
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only: creator's token account (receives creator share)
+    #[account(mut)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only: treasury-owned token account (receives platform fee)
+    #[account(mut)]
+    pub platform_fee_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only: keeper's token account for the reward. If omitted the
+    /// reward stays with the platform.
+    #[account(mut)]
+    pub keeper_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// SPL only: referrer's token account (receives the referral share)
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// The subscription's tier; required when this payment ends a tiered
+    /// subscription, so its member slot is released
+    #[account(
+        mut,
+        address = subscription.tier @ ErrorCode::SubscriptionTierMismatch,
+    )]
+    pub subscription_tier: Option<Account<'info, SubscriptionTier>>,
+}
+
+pub fn handler(ctx: Context<CrankSubscription>) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_SUBSCRIPTIONS), ErrorCode::SubscriptionsDisabled);
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let is_first_payment = ctx.accounts.subscription.payment_count == 0;
+    let amount = ctx.accounts.subscription.amount_due(ts)?;
+    let is_spl = ctx.accounts.subscription.is_spl;
+
+    // Check the payment source before advancing state so an unfunded,
+    // past-grace subscription is recorded as lapsed rather than erroring
+    let funded = if is_spl {
+        require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+        let spl = SplPaymentAccounts::from_options(
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.recipient_token_account.as_ref(),
+            ctx.accounts.platform_fee_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        spl.validate_accounts(
+            &ctx.accounts.subscription,
+            ctx.accounts.recipient_owner.key(),
+            ctx.accounts.platform_treasury.key(),
+        )?;
+        spl.can_cover(&ctx.accounts.subscription, amount)
+    } else {
+        ctx.accounts.subscription.escrow_balance >= amount
+    };
+    if !funded {
+        let status = ctx.accounts.subscription.record_failed_payment(ts)?;
+        require!(
+            status == SubscriptionStatus::Lapsed,
+            if is_spl { ErrorCode::SubscriptionDelegateMissing } else { ErrorCode::InsufficientSubscriptionEscrow }
+        );
+        let subscription = &ctx.accounts.subscription;
+        emit!(SubscriptionLapsedEvent {
+            subscription:      subscription.key(),
+            subscriber:        subscription.subscriber,
+            recipient_profile: subscription.recipient_profile,
+            amount_due:        amount,
+            due_intervals:     subscription.due_intervals(ts),
+            timestamp:         ts,
+        });
+        msg!("Subscription lapsed: {} | unpaid: {}", subscription.key(), amount);
+        return Ok(());
+    }
+
+    let intervals_charged = ctx.accounts.subscription.process_payment(ts)?;
+    if !ctx.accounts.subscription.is_active {
+        release_tier_slot(ctx.accounts.subscription.tier, ctx.accounts.subscription_tier.as_mut())?;
+        ctx.accounts.recipient_profile.remove_subscriber();
+    }
+
+    let platform_fee  = calculate_fee(amount, ctx.accounts.platform_config.platform_fee_bps)?;
+    let creator_share = amount
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCode::MathUnderflow)?;
+    let mut keeper_reward = calculate_fee(platform_fee, KEEPER_REWARD_BPS)?;
+
+    check_referral(ctx.accounts.recipient_profile.referral, ctx.accounts.referral.as_ref())?;
+    let referrer_cut = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+
+    if is_spl {
+        let spl = SplPaymentAccounts::from_options(
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.recipient_token_account.as_ref(),
+            ctx.accounts.platform_fee_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        spl.validate(
+            &ctx.accounts.subscription,
+            ctx.accounts.recipient_owner.key(),
+            ctx.accounts.platform_treasury.key(),
+            amount,
+        )?;
+
+        match ctx.accounts.keeper_token_account.as_ref() {
+            Some(keeper_ta) => {
+                require!(keeper_ta.owner == ctx.accounts.keeper.key(), ErrorCode::TokenAccountOwnerMismatch);
+                require!(keeper_ta.mint == ctx.accounts.subscription.token_mint, ErrorCode::TokenMintMismatch);
+            }
+            None => keeper_reward = 0,
+        }
+
+        let referrer_ta = referrer_token_account(
+            ctx.accounts.referral.as_ref(),
+            ctx.accounts.referrer_token_account.as_ref(),
+            ctx.accounts.subscription.token_mint,
+            referrer_cut,
+        )?;
+
+        let platform_net = platform_fee
+            .checked_sub(keeper_reward)
+            .and_then(|f| f.checked_sub(referrer_cut))
+            .ok_or(ErrorCode::MathUnderflow)?;
+        spl.transfer(&ctx.accounts.subscription, creator_share, platform_net)?;
+        if let (true, Some(keeper_ta)) = (keeper_reward > 0, ctx.accounts.keeper_token_account.as_ref()) {
+            spl.pull(&ctx.accounts.subscription, keeper_ta, keeper_reward)?;
+        }
+        if let Some(referrer_ta) = referrer_ta {
+            spl.pull(&ctx.accounts.subscription, referrer_ta, referrer_cut)?;
+        }
+    } else {
+        // Draw from the prefunded escrow held by the subscription PDA
+        ctx.accounts.subscription.withdraw_escrow(amount)?;
+
+        let platform_net = platform_fee
+            .checked_sub(keeper_reward)
+            .and_then(|f| f.checked_sub(referrer_cut))
+            .ok_or(ErrorCode::MathUnderflow)?;
+
+        // Direct lamport manipulation for PDA accounts with data
+        let sub_info = ctx.accounts.subscription.to_account_info();
+        **sub_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient_owner.to_account_info().try_borrow_mut_lamports()? += creator_share;
+        **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_net;
+        **ctx.accounts.keeper.to_account_info().try_borrow_mut_lamports()? += keeper_reward;
+        if let (true, Some(referral)) = (referrer_cut > 0, ctx.accounts.referral.as_ref()) {
+            **referral.to_account_info().try_borrow_mut_lamports()? += referrer_cut;
+        }
+    }
+
+    if referrer_cut > 0 {
+        if let Some(referral) = ctx.accounts.referral.as_mut() {
+            if is_spl {
+                referral.record_spl_earning(referrer_cut)?;
+            } else {
+                referral.accrue(referrer_cut)?;
+            }
+        }
+    }
+
+    let subscriber_key = ctx.accounts.subscription.subscriber;
+    if is_spl {
+        ctx.accounts.recipient_profile.record_spl_tip(amount)?;
+    } else {
+        ctx.accounts.recipient_profile.record_tip(subscriber_key, amount, is_first_payment)?;
+    }
+
+    let subscription = &ctx.accounts.subscription;
+    emit!(SubscriptionProcessedEvent {
+        subscriber:        subscriber_key,
+        recipient:         ctx.accounts.recipient_owner.key(),
+        recipient_profile: ctx.accounts.recipient_profile.key(),
+        subscription:      subscription.key(),
+        amount,
+        intervals_charged,
+        missed_intervals:  subscription.missed_intervals,
+        is_spl,
+        token_mint:        subscription.token_mint,
+        total_paid:        subscription.total_paid,
+        payment_count:     subscription.payment_count,
+        is_active:         subscription.is_active,
+        next_payment_due:  subscription.next_payment_due,
+        timestamp:         ts,
+    });
+    emit!(SubscriptionCrankedEvent {
+        keeper:        ctx.accounts.keeper.key(),
+        subscription:  subscription.key(),
+        keeper_reward,
+        timestamp:     ts,
+    });
+
+    msg!("Subscription cranked by {} | amount: {} | keeper reward: {}", ctx.accounts.keeper.key(), amount, keeper_reward);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// ==========================================================
+// create_admin_proposal – council member proposes an admin action
+//
+// The proposer's approval is counted immediately, so a 1-of-N
+// council (or a non-timelocked action reaching threshold) can be
+// executed right away.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{Governance, AdminProposal, AdminAction};
+use crate::constants::*;
+
+#[event]
+pub struct AdminProposalCreatedEvent {
+    pub proposal:    Pubkey,
+    pub proposal_id: u64,
+    pub proposer:    Pubkey,
+    pub action:      AdminAction,
+    pub timestamp:   i64,
+}
+
+#[derive(Accounts)]
+pub struct CreateAdminProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump  = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::LEN,
+        seeds = [ADMIN_PROPOSAL_SEED, governance.proposal_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateAdminProposal>, action: AdminAction) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let index       = ctx.accounts.governance.approver_index(&ctx.accounts.proposer.key())?;
+    let proposal_id = ctx.accounts.governance.next_proposal_id()?;
+
+    let governance = &ctx.accounts.governance;
+    let proposal   = &mut ctx.accounts.proposal;
+    proposal.initialize(
+        proposal_id,
+        ctx.accounts.proposer.key(),
+        action.clone(),
+        governance.council_nonce,
+        ts,
+        ctx.bumps.proposal,
+    );
+    proposal.approve(governance, index, ts)?;
+
+    emit!(AdminProposalCreatedEvent {
+        proposal:  proposal.key(),
+        proposal_id,
+        proposer:  ctx.accounts.proposer.key(),
+        action,
+        timestamp: ts,
+    });
+
+    msg!("Admin proposal {} created by {}", proposal_id, ctx.accounts.proposer.key());
+    Ok(())
+}
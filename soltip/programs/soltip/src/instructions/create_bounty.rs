@@ -0,0 +1,132 @@
+// ==========================================================
+// create_bounty – viewer escrows SOL against a request
+//
+// The full amount moves into the Bounty PDA up front. The
+// creator's vault must exist so a release always has
+// somewhere to land. See state/bounty.rs for the lifecycle.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{TipProfile, Vault, Bounty, BlockedTipper};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// Emitted by create_bounty and create_bounty_spl.
+#[event]
+pub struct BountyCreatedEvent {
+    pub bounty:      Pubkey,
+    pub requester:   Pubkey,
+    pub profile:     Pubkey,
+    pub bounty_id:   u64,
+    /// system_program::ID for SOL
+    pub token_mint:  Pubkey,
+    pub amount:      u64,
+    pub description: String,
+    pub expires_at:  i64,
+    pub timestamp:   i64,
+}
+
+#[derive(Accounts)]
+#[instruction(bounty_id: u64)]
+pub struct CreateBounty<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [TIP_PROFILE_SEED, tip_profile.owner.as_ref()],
+        bump  = tip_profile.bump,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// Creator's vault; a released bounty is paid here
+    #[account(
+        seeds = [VAULT_SEED, tip_profile.key().as_ref()],
+        bump  = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = Bounty::LEN,
+        seeds = [BOUNTY_SEED, tip_profile.key().as_ref(), requester.key().as_ref(), bounty_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: blocklist PDA for this requester; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, tip_profile.key().as_ref(), requester.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateBounty>,
+    bounty_id: u64,
+    amount: u64,
+    description: String,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(
+        ctx.accounts.requester.key() != ctx.accounts.tip_profile.owner,
+        ErrorCode::CannotTipSelf
+    );
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
+    ctx.accounts.bounty.initialize(
+        ctx.accounts.requester.key(),
+        ctx.accounts.tip_profile.key(),
+        ctx.accounts.tip_profile.owner,
+        bounty_id,
+        anchor_lang::system_program::ID,
+        Pubkey::default(),
+        amount,
+        description,
+        expires_at,
+        ts,
+        ctx.bumps.bounty,
+    )?;
+
+    let cpi = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.requester.to_account_info(),
+            to:   ctx.accounts.bounty.to_account_info(),
+        },
+    );
+    transfer(cpi, amount)?;
+
+    let bounty = &ctx.accounts.bounty;
+    emit!(BountyCreatedEvent {
+        bounty:      bounty.key(),
+        requester:   bounty.requester,
+        profile:     bounty.profile,
+        bounty_id,
+        token_mint:  bounty.token_mint,
+        amount,
+        description: bounty.description.clone(),
+        expires_at,
+        timestamp:   ts,
+    });
+
+    msg!("Bounty {}: {} lamports for {}", bounty_id, amount, ctx.accounts.tip_profile.username);
+    Ok(())
+}
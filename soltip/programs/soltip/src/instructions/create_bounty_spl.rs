@@ -0,0 +1,148 @@
+// ==========================================================
+// create_bounty_spl – viewer escrows SPL tokens against a request
+//
+// Token counterpart of create_bounty. The escrow is the Bounty
+// PDA's associated token account; the creator must already
+// have an SplVault for the mint to be paid into.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer, TokenAccount};
+use crate::state::{TipProfile, SplVault, Bounty, BlockedTipper};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::create_bounty::BountyCreatedEvent;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(bounty_id: u64)]
+pub struct CreateBountySpl<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// Requester's token account (source)
+    #[account(
+        mut,
+        constraint = requester_token_account.owner == requester.key()  @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = requester_token_account.mint  == token_mint.key() @ ErrorCode::TokenMintMismatch,
+    )]
+    pub requester_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [TIP_PROFILE_SEED, tip_profile.owner.as_ref()],
+        bump  = tip_profile.bump,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    /// Creator's vault for this mint; a released bounty is paid here
+    #[account(
+        seeds = [SPL_VAULT_SEED, tip_profile.key().as_ref(), token_mint.key().as_ref()],
+        bump  = spl_vault.bump,
+    )]
+    pub spl_vault: Account<'info, SplVault>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = Bounty::LEN,
+        seeds = [BOUNTY_SEED, tip_profile.key().as_ref(), requester.key().as_ref(), bounty_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// Escrow token account (ATA of the Bounty PDA)
+    #[account(
+        init,
+        payer = requester,
+        associated_token::mint      = token_mint,
+        associated_token::authority = bounty,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: blocklist PDA for this requester; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, tip_profile.key().as_ref(), requester.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub token_program:            Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program:           Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateBountySpl>,
+    bounty_id: u64,
+    amount: u64,
+    description: String,
+    expires_at: i64,
+) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(
+        ctx.accounts.requester.key() != ctx.accounts.tip_profile.owner,
+        ErrorCode::CannotTipSelf
+    );
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+    require!(
+        ctx.accounts.requester_token_account.amount >= amount,
+        ErrorCode::InsufficientTokenBalance
+    );
+
+    let mint = ctx.accounts.token_mint.key();
+    ctx.accounts.bounty.initialize(
+        ctx.accounts.requester.key(),
+        ctx.accounts.tip_profile.key(),
+        ctx.accounts.tip_profile.owner,
+        bounty_id,
+        mint,
+        ctx.accounts.escrow_token_account.key(),
+        amount,
+        description,
+        expires_at,
+        ts,
+        ctx.bumps.bounty,
+    )?;
+
+    let cpi = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SplTransfer {
+            from:      ctx.accounts.requester_token_account.to_account_info(),
+            to:        ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.requester.to_account_info(),
+        },
+    );
+    token::transfer(cpi, amount)?;
+
+    let bounty = &ctx.accounts.bounty;
+    emit!(BountyCreatedEvent {
+        bounty:      bounty.key(),
+        requester:   bounty.requester,
+        profile:     bounty.profile,
+        bounty_id,
+        token_mint:  mint,
+        amount,
+        description: bounty.description.clone(),
+        expires_at,
+        timestamp:   ts,
+    });
+
+    msg!("Bounty {}: {} tokens (mint: {}) for {}", bounty_id, amount, mint, ctx.accounts.tip_profile.username);
+    Ok(())
+}
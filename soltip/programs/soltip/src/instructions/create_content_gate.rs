@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::{TipProfile, ContentGate};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -26,6 +27,13 @@ pub struct CreateContentGate<'info> {
     )]
     pub content_gate: Account<'info, ContentGate>,
 
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -36,7 +44,7 @@ pub fn handler(
     content_url_hash: [u8; 32],
     required_amount: u64,
 ) -> Result<()> {
-    require!(ENABLE_CONTENT_GATES, ErrorCode::ContentGatesDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_CONTENT_GATES), ErrorCode::ContentGatesDisabled);
 
     // Validate text content
     require!(validate_text_content(&title), ErrorCode::UnsafeTextContent);
@@ -1,8 +1,22 @@
 use anchor_lang::prelude::*;
 use crate::state::{TipProfile, TipGoal};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
+/// Goal terms supplied by the creator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateGoalParams {
+    pub title: String,
+    pub description: String,
+    pub target_amount: u64,
+    /// System Program id for SOL goals
+    pub token_mint: Pubkey,
+    pub deadline: Option<i64>,
+    /// Hold contributions in the goal PDA until it completes (requires a deadline)
+    pub escrowed: bool,
+}
+
 /// Accounts required to create a fundraising goal
 #[derive(Accounts)]
 #[instruction(goal_id: u64)]
@@ -30,6 +44,13 @@ pub struct CreateGoal<'info> {
     )]
     pub tip_goal: Account<'info, TipGoal>,
 
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     /// System program for account creation
     pub system_program: Program<'info, System>,
 }
@@ -38,14 +59,12 @@ pub struct CreateGoal<'info> {
 pub fn handler(
     ctx: Context<CreateGoal>,
     goal_id: u64,
-    title: String,
-    description: String,
-    target_amount: u64,
-    token_mint: Pubkey,
-    deadline: Option<i64>,
+    params: CreateGoalParams,
 ) -> Result<()> {
+    let CreateGoalParams { title, description, target_amount, token_mint, deadline, escrowed } = params;
+
     // Validate feature is enabled
-    require!(ENABLE_GOALS, ErrorCode::GoalsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_GOALS), ErrorCode::GoalsDisabled);
 
     // Validate text content on all user-provided strings
     require!(validate_text_content(&title), ErrorCode::UnsafeTextContent);
@@ -67,6 +86,7 @@ pub fn handler(
         target_amount,
         token_mint,
         deadline,
+        escrowed,
         clock.unix_timestamp,
         ctx.bumps.tip_goal,
     )?;
@@ -76,6 +96,9 @@ pub fn handler(
     if let Some(deadline_ts) = deadline {
         msg!("Deadline: {}", deadline_ts);
     }
+    if escrowed {
+        msg!("Escrowed: contributions held until goal completes");
+    }
 
     Ok(())
 }
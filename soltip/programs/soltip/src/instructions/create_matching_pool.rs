@@ -0,0 +1,145 @@
+// ==========================================================
+// create_matching_pool – sponsor escrows SOL to match tips
+//
+// The full `total_cap` moves into the pool PDA up front, so a
+// match can never fail for lack of funds. Pass a goal to match
+// contributions to that goal instead of tips. Matches on an
+// escrowed goal stay in its escrow and go back to the pool
+// through reclaim_goal_match if the goal fails.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{TipProfile, TipGoal, MatchingPool};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct MatchingPoolCreatedEvent {
+    pub pool:            Pubkey,
+    pub sponsor:         Pubkey,
+    pub profile:         Pubkey,
+    /// TipGoal PDA, or default when the pool matches tips
+    pub goal:            Pubkey,
+    pub match_ratio_bps: u16,
+    pub per_tipper_cap:  u64,
+    pub total_cap:       u64,
+    pub expires_at:      i64,
+    pub timestamp:       i64,
+}
+
+/// Emitted by send_tip and contribute_goal when a pool matches a payment.
+#[event]
+pub struct MatchedEvent {
+    pub pool:              Pubkey,
+    pub sponsor:           Pubkey,
+    pub tipper:            Pubkey,
+    pub recipient_profile: Pubkey,
+    /// TipGoal PDA, or default for a tip
+    pub goal:              Pubkey,
+    /// The tipper's own amount
+    pub amount:            u64,
+    pub matched_amount:    u64,
+    /// Pool totals after this match
+    pub pool_matched:      u64,
+    pub pool_remaining:    u64,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+#[instruction(pool_id: u64)]
+pub struct CreateMatchingPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [TIP_PROFILE_SEED, tip_profile.owner.as_ref()],
+        bump  = tip_profile.bump,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// Goal to match instead of tips
+    #[account(
+        constraint = tip_goal.profile == tip_profile.key() @ ErrorCode::InvalidAccountData,
+    )]
+    pub tip_goal: Option<Account<'info, TipGoal>>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = MatchingPool::LEN,
+        seeds = [MATCHING_POOL_SEED, tip_profile.key().as_ref(), sponsor.key().as_ref(), pool_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateMatchingPool>,
+    pool_id: u64,
+    match_ratio_bps: u16,
+    per_tipper_cap: u64,
+    total_cap: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    let goal = match ctx.accounts.tip_goal.as_ref() {
+        Some(goal) => {
+            require!(goal.is_sol(), ErrorCode::TokenMintMismatch);
+            goal.validate_can_contribute(ts)?;
+            goal.key()
+        }
+        None => Pubkey::default(),
+    };
+
+    ctx.accounts.matching_pool.initialize(
+        ctx.accounts.sponsor.key(),
+        ctx.accounts.tip_profile.key(),
+        goal,
+        pool_id,
+        match_ratio_bps,
+        per_tipper_cap,
+        total_cap,
+        expires_at,
+        ts,
+        ctx.bumps.matching_pool,
+    )?;
+
+    let cpi = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.sponsor.to_account_info(),
+            to:   ctx.accounts.matching_pool.to_account_info(),
+        },
+    );
+    transfer(cpi, total_cap)?;
+
+    emit!(MatchingPoolCreatedEvent {
+        pool:            ctx.accounts.matching_pool.key(),
+        sponsor:         ctx.accounts.sponsor.key(),
+        profile:         ctx.accounts.tip_profile.key(),
+        goal,
+        match_ratio_bps,
+        per_tipper_cap,
+        total_cap,
+        expires_at,
+        timestamp:       ts,
+    });
+
+    msg!("Matching pool: {} lamports for {} at {} bps", total_cap, ctx.accounts.tip_profile.username, match_ratio_bps);
+    Ok(())
+}
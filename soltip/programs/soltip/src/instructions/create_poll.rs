@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::{TipProfile, TipPoll};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -26,6 +27,13 @@ pub struct CreatePoll<'info> {
     )]
     pub tip_poll: Account<'info, TipPoll>,
 
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -37,7 +45,7 @@ pub fn handler(
     options: Vec<String>,
     deadline: Option<i64>,
 ) -> Result<()> {
-    require!(ENABLE_POLLS, ErrorCode::PollsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_POLLS), ErrorCode::PollsDisabled);
 
     // Validate text content on all user-provided strings
     require!(validate_text_content(&title), ErrorCode::UnsafeTextContent);
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::TipProfile;
+use crate::state::{TipProfile, UsernameRegistry};
 use crate::constants::*;
 use crate::error::ErrorCode;
 
 /// Accounts required to create a new creator tip profile.
 ///
 /// The profile PDA is derived from `["tip_profile", owner.pubkey]`, so
-/// each wallet can have exactly one profile. The owner pays the rent.
+/// each wallet can have exactly one profile. The username is claimed in
+/// the `["username", username]` registry in the same instruction, so no
+/// two profiles can hold the same handle. The owner pays the rent.
 #[derive(Accounts)]
 #[instruction(username: String)]
 pub struct CreateProfile<'info> {
@@ -25,6 +27,16 @@ pub struct CreateProfile<'info> {
     )]
     pub tip_profile: Account<'info, TipProfile>,
 
+    /// Username claim; already naming a profile means the handle is taken
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = UsernameRegistry::LEN,
+        seeds = [USERNAME_REGISTRY_SEED, username.as_bytes()],
+        bump
+    )]
+    pub username_registry: Account<'info, UsernameRegistry>,
+
     /// System program for account creation
     pub system_program: Program<'info, System>,
 }
@@ -55,6 +67,14 @@ pub fn handler(
         ctx.bumps.tip_profile,
     )?;
 
+    ctx.accounts.username_registry.claim(
+        tip_profile.username.clone(),
+        tip_profile.key(),
+        tip_profile.owner,
+        clock.unix_timestamp,
+        ctx.bumps.username_registry,
+    )?;
+
     msg!("Tip profile created for user: {}", tip_profile.username);
     msg!("Profile owner: {}", tip_profile.owner);
 
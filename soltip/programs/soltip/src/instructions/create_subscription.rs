@@ -1,6 +1,17 @@
 // create_subscription – v2 with SPL token support
+//
+// SPL subscriptions: the subscriber approves the subscription PDA as
+// delegate on their token account for SUBSCRIPTION_DELEGATE_INTERVALS
+// payments, so process_subscription can pull each payment via a
+// delegated token::transfer. A token account holds a single delegate,
+// so one source account backs one SPL subscription at a time.
+//
+// Price, interval and mint come from the creator's SubscriptionTier;
+// the tier's member count is enforced against its cap.
 use anchor_lang::prelude::*;
-use crate::state::{TipProfile, Subscription};
+use anchor_spl::token::{self, Approve, Token, TokenAccount};
+use crate::state::{TipProfile, Subscription, SubscriptionTier, BlockedTipper};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -10,6 +21,7 @@ pub struct CreateSubscription<'info> {
     pub subscriber: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [TIP_PROFILE_SEED, recipient_owner.key().as_ref()],
         bump  = recipient_profile.bump,
     )]
@@ -18,6 +30,15 @@ pub struct CreateSubscription<'info> {
     /// CHECK: validated by PDA derivation of recipient_profile
     pub recipient_owner: UncheckedAccount<'info>,
 
+    /// The creator's tier being joined
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_TIER_SEED, recipient_profile.key().as_ref(), subscription_tier.tier_id.to_le_bytes().as_ref()],
+        bump  = subscription_tier.bump,
+        constraint = subscription_tier.profile == recipient_profile.key() @ ErrorCode::SubscriptionTierMismatch,
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
+
     #[account(
         init,
         payer = subscriber,
@@ -27,26 +48,54 @@ pub struct CreateSubscription<'info> {
     )]
     pub subscription: Account<'info, Subscription>,
 
+    /// SPL only: subscriber's token account; the subscription PDA is approved as delegate
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: blocklist PDA for this subscriber; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), subscriber.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// SPL only
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<CreateSubscription>,
-    amount_per_interval: u64,
-    interval_seconds: i64,
-    is_spl: bool,
-    token_mint: Pubkey,
-) -> Result<()> {
-    require!(ENABLE_SUBSCRIPTIONS, ErrorCode::SubscriptionsDisabled);
+pub fn handler(ctx: Context<CreateSubscription>) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_SUBSCRIPTIONS), ErrorCode::SubscriptionsDisabled);
     require!(
         ctx.accounts.subscriber.key() != ctx.accounts.recipient_owner.key(),
         ErrorCode::CannotTipSelf
     );
 
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
+    let tier = &mut ctx.accounts.subscription_tier;
+    tier.add_member()?;
+    ctx.accounts.recipient_profile.add_subscriber()?;
+    let amount_per_interval = tier.price;
+    let interval_seconds    = tier.interval_seconds;
+    let is_spl              = !tier.is_sol();
+    let token_mint          = tier.token_mint;
+
     let clock = Clock::get()?;
     ctx.accounts.subscription.initialize(
         ctx.accounts.subscriber.key(),
         ctx.accounts.recipient_profile.key(),
+        tier.key(),
         amount_per_interval,
         interval_seconds,
         is_spl,
@@ -54,7 +103,40 @@ pub fn handler(
         clock.unix_timestamp,
         ctx.bumps.subscription,
     )?;
+    ctx.accounts.subscription.set_payment_policy(
+        ctx.accounts.recipient_profile.subscription_grace_seconds,
+        ctx.accounts.recipient_profile.subscription_backcharge,
+    );
+
+    if is_spl {
+        require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+        let (Some(source), Some(token_program)) = (
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingTokenAccounts);
+        };
+        require!(source.owner == ctx.accounts.subscriber.key(), ErrorCode::TokenAccountOwnerMismatch);
+        require!(source.mint == token_mint, ErrorCode::TokenMintMismatch);
 
-    msg!("Subscription created | amount: {} | interval: {}s | SPL: {}", amount_per_interval, interval_seconds, is_spl);
+        let allowance = amount_per_interval
+            .checked_mul(SUBSCRIPTION_DELEGATE_INTERVALS)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let cpi = CpiContext::new(
+            token_program.to_account_info(),
+            Approve {
+                to:        source.to_account_info(),
+                delegate:  ctx.accounts.subscription.to_account_info(),
+                authority: ctx.accounts.subscriber.to_account_info(),
+            },
+        );
+        token::approve(cpi, allowance)?;
+        msg!("Subscription PDA approved as delegate for {} tokens", allowance);
+    }
+
+    msg!(
+        "Subscription created | tier: {} | amount: {} | interval: {}s | SPL: {}",
+        ctx.accounts.subscription_tier.name, amount_per_interval, interval_seconds, is_spl
+    );
     Ok(())
 }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, SubscriptionTier, SubscriptionTierTerms};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(tier_id: u64)]
+pub struct CreateSubscriptionTier<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SubscriptionTier::LEN,
+        seeds = [SUBSCRIPTION_TIER_SEED, tip_profile.key().as_ref(), tier_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSubscriptionTier>,
+    tier_id: u64,
+    terms: SubscriptionTierTerms,
+) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_SUBSCRIPTIONS), ErrorCode::SubscriptionsDisabled);
+
+    let tip_profile = &mut ctx.accounts.tip_profile;
+    let tier = &mut ctx.accounts.subscription_tier;
+    let clock = Clock::get()?;
+
+    tip_profile.increment_tiers()?;
+
+    tier.initialize(
+        tip_profile.key(),
+        tier_id,
+        terms,
+        clock.unix_timestamp,
+        ctx.bumps.subscription_tier,
+    )?;
+    if !tier.is_sol() {
+        require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+    }
+
+    msg!(
+        "Subscription tier created: {} | price: {} every {}s | max members: {}",
+        tier.name, tier.price, tier.interval_seconds, tier.max_members
+    );
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::Bounty;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct BountyDisputedEvent {
+    pub bounty:    Pubkey,
+    pub creator:   Pubkey,
+    pub requester: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct DisputeBounty<'info> {
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [BOUNTY_SEED, bounty.profile.as_ref(), requester.key().as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump    = bounty.bump,
+        has_one = requester @ ErrorCode::Unauthorized,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+/// Requester contests a fulfilled bounty inside the review window. The
+/// escrow is then frozen until the platform authority resolves it.
+pub fn handler(ctx: Context<DisputeBounty>) -> Result<()> {
+    let clock = Clock::get()?;
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.dispute(clock.unix_timestamp)?;
+
+    emit!(BountyDisputedEvent {
+        bounty:    bounty.key(),
+        creator:   bounty.creator,
+        requester: bounty.requester,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Bounty {} disputed", bounty.bounty_id);
+    Ok(())
+}
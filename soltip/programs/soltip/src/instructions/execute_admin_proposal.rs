@@ -0,0 +1,243 @@
+// ==========================================================
+// execute_admin_proposal – run an approved admin action
+//
+// Callable by anyone once the proposal has reached the council
+// threshold and, for sensitive actions, its timelock has passed.
+// Accounts the action touches are passed as optional accounts
+// and must match the parameters recorded in the proposal:
+//   • VerifyCreator / ResetReentrancyGuard: tip_profile
+//   • WithdrawTreasury:                     platform_treasury, destination, system_program
+//   • WithdrawTreasurySpl:                  platform_treasury, treasury_token_account,
+//                                           destination_token_account, token_program
+//   • ResolveBountyDispute:                 bounty, requester, tip_profile, then the payout
+//                                           accounts of resolve_bounty_dispute
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Governance, AdminProposal, AdminAction, TipProfile, Bounty, Vault, SplVault};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::resolve_bounty_dispute::settle_dispute;
+use crate::instructions::withdraw_treasury::pay_from_treasury;
+use crate::instructions::withdraw_treasury_spl::pay_spl_from_treasury;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct AdminProposalExecutedEvent {
+    pub proposal:    Pubkey,
+    pub proposal_id: u64,
+    pub executor:    Pubkey,
+    pub action:      AdminAction,
+    pub timestamp:   i64,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump  = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_SEED, proposal.proposal_id.to_le_bytes().as_ref()],
+        bump  = proposal.bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+        constraint = platform_config.authority == governance.key() @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// VerifyCreator / ResetReentrancyGuard: the target profile;
+    /// ResolveBountyDispute: the bounty's profile
+    #[account(
+        mut,
+        seeds = [TIP_PROFILE_SEED, tip_profile.owner.as_ref()],
+        bump  = tip_profile.bump,
+    )]
+    pub tip_profile: Option<Account<'info, TipProfile>>,
+
+    /// CHECK: WithdrawTreasury / WithdrawTreasurySpl only – must match platform_config.treasury
+    #[account(
+        mut,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: WithdrawTreasury only – must match the proposed destination
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    /// WithdrawTreasurySpl only: treasury-owned fee token account
+    #[account(mut)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// WithdrawTreasurySpl only: must match the proposed destination
+    #[account(mut)]
+    pub destination_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    /// ResolveBountyDispute only: the disputed bounty (closed to the requester)
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, bounty.profile.as_ref(), bounty.requester.as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump  = bounty.bump,
+    )]
+    pub bounty: Option<Account<'info, Bounty>>,
+
+    /// CHECK: ResolveBountyDispute only – must be bounty.requester
+    #[account(mut)]
+    pub requester: Option<UncheckedAccount<'info>>,
+
+    /// ResolveBountyDispute only: creator's vault, to release a SOL bounty
+    #[account(mut)]
+    pub vault: Option<Account<'info, Vault>>,
+
+    /// ResolveBountyDispute only: creator's vault for the bounty mint
+    #[account(mut)]
+    pub spl_vault: Option<Account<'info, SplVault>>,
+
+    /// ResolveBountyDispute only: SplVault token account
+    #[account(mut)]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// ResolveBountyDispute only: requester's token account
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// ResolveBountyDispute only: bounty escrow token account
+    #[account(mut)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    ctx.accounts.proposal.mark_executed(&ctx.accounts.governance, ts)?;
+    let action = ctx.accounts.proposal.action.clone();
+
+    match &action {
+        AdminAction::Pause { paused } => {
+            ctx.accounts.platform_config.paused = *paused;
+        }
+        AdminAction::VerifyCreator { profile, verified } => {
+            let tip_profile = target_profile(&mut ctx.accounts.tip_profile, profile)?;
+            tip_profile.is_verified = *verified;
+        }
+        AdminAction::ResetReentrancyGuard { profile } => {
+            let tip_profile = target_profile(&mut ctx.accounts.tip_profile, profile)?;
+            tip_profile.reentrancy_guard = false;
+        }
+        AdminAction::WithdrawTreasury { amount, destination } => {
+            let accounts = &ctx.accounts;
+            let (Some(treasury), Some(dest), Some(system_program)) = (
+                accounts.platform_treasury.as_ref(),
+                accounts.destination.as_ref(),
+                accounts.system_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::ProposalAccountMismatch);
+            };
+            require!(dest.key() == *destination, ErrorCode::ProposalAccountMismatch);
+            pay_from_treasury(system_program, &treasury.to_account_info(), &dest.to_account_info(), *amount)?;
+        }
+        AdminAction::WithdrawTreasurySpl { amount, destination } => {
+            let accounts = &ctx.accounts;
+            let (Some(treasury), Some(source), Some(dest), Some(token_program)) = (
+                accounts.platform_treasury.as_ref(),
+                accounts.treasury_token_account.as_ref(),
+                accounts.destination_token_account.as_ref(),
+                accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::ProposalAccountMismatch);
+            };
+            require!(dest.key() == *destination, ErrorCode::ProposalAccountMismatch);
+            require!(source.owner == treasury.key(), ErrorCode::TokenAccountOwnerMismatch);
+            require!(dest.mint == source.mint, ErrorCode::TokenMintMismatch);
+            pay_spl_from_treasury(token_program, &treasury.to_account_info(), source, dest, *amount)?;
+        }
+        AdminAction::ResolveBountyDispute { bounty, award_to_creator } => {
+            let accounts = &mut *ctx.accounts;
+            let (Some(bounty_account), Some(requester)) = (accounts.bounty.as_ref(), accounts.requester.as_ref()) else {
+                return err!(ErrorCode::ProposalAccountMismatch);
+            };
+            require!(bounty_account.key() == *bounty, ErrorCode::ProposalAccountMismatch);
+            require!(requester.key() == bounty_account.requester, ErrorCode::Unauthorized);
+
+            // The vaults cannot be seed-checked in the constraints since the
+            // bounty is optional too
+            let profile = bounty_account.profile;
+            if let Some(vault) = accounts.vault.as_ref() {
+                let (expected, _) = Pubkey::find_program_address(&[VAULT_SEED, profile.as_ref()], &crate::ID);
+                require_keys_eq!(vault.key(), expected, ErrorCode::BountyAccountsMismatch);
+            }
+            if let Some(spl_vault) = accounts.spl_vault.as_ref() {
+                let (expected, _) = Pubkey::find_program_address(
+                    &[SPL_VAULT_SEED, profile.as_ref(), bounty_account.token_mint.as_ref()],
+                    &crate::ID,
+                );
+                require_keys_eq!(spl_vault.key(), expected, ErrorCode::BountyAccountsMismatch);
+            }
+
+            let requester = requester.to_account_info();
+            settle_dispute(
+                bounty_account,
+                *award_to_creator,
+                &requester,
+                accounts.vault.as_mut(),
+                accounts.spl_vault.as_mut(),
+                accounts.vault_token_account.as_ref(),
+                accounts.requester_token_account.as_ref(),
+                accounts.escrow_token_account.as_ref(),
+                accounts.token_program.as_ref(),
+            )?;
+            bounty_account.close(requester)?;
+            target_profile(&mut accounts.tip_profile, &profile)?.remove_bounty();
+        }
+        AdminAction::UpdatePlatformConfig { platform_fee_bps, treasury } => {
+            ctx.accounts.platform_config.update(*platform_fee_bps, *treasury, ts)?;
+        }
+        AdminAction::SetFeatureFlags { features, enabled } => {
+            ctx.accounts.platform_config.set_features(*features, *enabled, ts)?;
+        }
+        AdminAction::TransferAuthority { new_authority } => {
+            ctx.accounts.platform_config.propose_authority(*new_authority, ts)?;
+        }
+        AdminAction::UpdateCouncil { approvers, threshold, timelock_seconds } => {
+            ctx.accounts.governance.set_council(approvers.clone(), *threshold, *timelock_seconds)?;
+        }
+    }
+
+    emit!(AdminProposalExecutedEvent {
+        proposal:    ctx.accounts.proposal.key(),
+        proposal_id: ctx.accounts.proposal.proposal_id,
+        executor:    ctx.accounts.executor.key(),
+        action,
+        timestamp:   ts,
+    });
+
+    msg!("Admin proposal {} executed", ctx.accounts.proposal.proposal_id);
+    Ok(())
+}
+
+fn target_profile<'a, 'info>(
+    tip_profile: &'a mut Option<Account<'info, TipProfile>>,
+    expected: &Pubkey,
+) -> Result<&'a mut Account<'info, TipProfile>> {
+    let tip_profile = tip_profile.as_mut().ok_or(ErrorCode::ProposalAccountMismatch)?;
+    require!(tip_profile.key() == *expected, ErrorCode::ProposalAccountMismatch);
+    Ok(tip_profile)
+}
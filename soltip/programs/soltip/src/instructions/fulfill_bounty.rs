@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::Bounty;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct BountyFulfilledEvent {
+    pub bounty:         Pubkey,
+    pub creator:        Pubkey,
+    pub requester:      Pubkey,
+    /// Requester can approve or dispute until then
+    pub review_ends_at: i64,
+    pub timestamp:      i64,
+}
+
+#[derive(Accounts)]
+pub struct FulfillBounty<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [BOUNTY_SEED, bounty.profile.as_ref(), bounty.requester.as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump    = bounty.bump,
+        has_one = creator @ ErrorCode::NotProfileOwner,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+/// Creator marks an accepted bounty done, starting the review window.
+pub fn handler(ctx: Context<FulfillBounty>) -> Result<()> {
+    let clock = Clock::get()?;
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.fulfill(clock.unix_timestamp)?;
+
+    emit!(BountyFulfilledEvent {
+        bounty:         bounty.key(),
+        creator:        bounty.creator,
+        requester:      bounty.requester,
+        review_ends_at: bounty.review_ends_at(),
+        timestamp:      clock.unix_timestamp,
+    });
+
+    msg!("Bounty {} fulfilled; review until {}", bounty.bounty_id, bounty.review_ends_at());
+    Ok(())
+}
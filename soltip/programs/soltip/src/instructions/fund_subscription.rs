@@ -0,0 +1,60 @@
+// ==========================================================
+// fund_subscription – prefund a SOL subscription for keepers
+//
+// The subscriber deposits lamports into the subscription PDA.
+// crank_subscription draws each due payment from this escrow,
+// so recurring payments no longer need the subscriber to sign.
+// Any unused balance is returned when the subscription is
+// cancelled (the PDA is closed to the subscriber).
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::Subscription;
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct FundSubscription<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [SUBSCRIPTION_SEED, subscriber.key().as_ref(), subscription.recipient_profile.as_ref()],
+        bump    = subscription.bump,
+        has_one = subscriber @ ErrorCode::NotSubscriber,
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundSubscription>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_SUBSCRIPTIONS), ErrorCode::SubscriptionsDisabled);
+    require!(amount > 0, ErrorCode::InvalidSubscriptionAmount);
+    require!(ctx.accounts.subscription.is_active, ErrorCode::SubscriptionNotActive);
+    // SPL subscriptions are funded through the token delegate approval
+    require!(!ctx.accounts.subscription.is_spl, ErrorCode::InvalidTokenMint);
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.subscriber.to_account_info(),
+            to:   ctx.accounts.subscription.to_account_info(),
+        },
+    );
+    transfer(cpi_ctx, amount)?;
+    ctx.accounts.subscription.deposit_escrow(amount)?;
+
+    msg!("Subscription funded: {} lamports | escrow: {}", amount, ctx.accounts.subscription.escrow_balance);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// ==========================================================
+// initialize_governance – hand platform admin to a council
+//
+// Creates the Governance PDA with an M-of-N approver set and a
+// timelock, then makes that PDA the PlatformConfig authority.
+// From then on the single-key admin instructions cannot be
+// signed; admin actions run through admin proposals.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::Governance;
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct GovernanceInitializedEvent {
+    pub governance:       Pubkey,
+    pub approvers:        Vec<Pubkey>,
+    pub threshold:        u8,
+    pub timelock_seconds: i64,
+    pub timestamp:        i64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [PLATFORM_CONFIG_SEED],
+        bump    = platform_config.bump,
+        has_one = authority @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Governance::LEN,
+        seeds = [GOVERNANCE_SEED],
+        bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeGovernance>,
+    approvers: Vec<Pubkey>,
+    threshold: u8,
+    timelock_seconds: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.set_council(approvers, threshold, timelock_seconds)?;
+    governance.proposal_count = 0;
+    governance.created_at     = ts;
+    governance.bump           = ctx.bumps.governance;
+
+    let cfg = &mut ctx.accounts.platform_config;
+    cfg.authority         = governance.key();
+    cfg.pending_authority = Pubkey::default();
+    cfg.updated_at        = ts;
+
+    emit!(GovernanceInitializedEvent {
+        governance:       governance.key(),
+        approvers:        governance.approvers.clone(),
+        threshold:        governance.threshold,
+        timelock_seconds: governance.timelock_seconds,
+        timestamp:        ts,
+    });
+
+    msg!(
+        "Governance initialized: {}-of-{} | timelock: {}s",
+        governance.threshold,
+        governance.approvers.len(),
+        governance.timelock_seconds
+    );
+    Ok(())
+}
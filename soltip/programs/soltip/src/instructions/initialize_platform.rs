@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 use crate::constants::*;
+use crate::error::ErrorCode;
 
 // ---- PlatformConfig account (owned here, re-exported) --------
 
@@ -12,10 +13,71 @@ pub struct PlatformConfig {
     pub paused:           bool,
     pub created_at:       i64,
     pub bump:             u8,
+    /// Authority nominated by propose_authority; default = none pending
+    pub pending_authority: Pubkey,
+    pub updated_at:       i64,
+    /// FEATURE_* bits switched off by the admin; zero = all enabled
+    pub disabled_features: u32,
 }
 
 impl PlatformConfig {
     pub const LEN: usize = PLATFORM_CONFIG_SIZE;
+
+    /// Apply admin updates to the fee and treasury. The fee is bounded by
+    /// MAX_PLATFORM_FEE_BPS; the treasury must be a real address.
+    pub fn update(
+        &mut self,
+        platform_fee_bps: Option<u16>,
+        treasury: Option<Pubkey>,
+        timestamp: i64,
+    ) -> Result<()> {
+        if let Some(v) = platform_fee_bps {
+            require!(v <= MAX_PLATFORM_FEE_BPS, ErrorCode::InvalidPlatformFee);
+            self.platform_fee_bps = v;
+        }
+        if let Some(v) = treasury {
+            require!(v != Pubkey::default(), ErrorCode::InvalidTreasury);
+            self.treasury = v;
+        }
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    /// Whether a FEATURE_* flag is currently enabled.
+    pub fn is_enabled(&self, feature: u32) -> bool {
+        self.disabled_features & feature == 0
+    }
+
+    /// Enable or disable every FEATURE_* bit set in `features`.
+    pub fn set_features(&mut self, features: u32, enabled: bool, timestamp: i64) -> Result<()> {
+        require!(features != 0 && features & !ALL_FEATURES == 0, ErrorCode::InvalidFeatureFlag);
+        if enabled {
+            self.disabled_features &= !features;
+        } else {
+            self.disabled_features |= features;
+        }
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    /// Nominate a new authority. Passing the default pubkey cancels
+    /// any pending nomination.
+    pub fn propose_authority(&mut self, new_authority: Pubkey, timestamp: i64) -> Result<()> {
+        require!(new_authority != self.authority, ErrorCode::InvalidAuthority);
+        self.pending_authority = new_authority;
+        self.updated_at        = timestamp;
+        Ok(())
+    }
+
+    /// Complete a two-step transfer; `signer` must be the pending authority.
+    pub fn accept_authority(&mut self, signer: Pubkey, timestamp: i64) -> Result<()> {
+        require!(self.pending_authority != Pubkey::default(), ErrorCode::NoPendingAuthority);
+        require!(self.pending_authority == signer, ErrorCode::NotPendingAuthority);
+        self.authority         = signer;
+        self.pending_authority = Pubkey::default();
+        self.updated_at        = timestamp;
+        Ok(())
+    }
 }
 
 // ---- InitializePlatform instruction --------------------------
@@ -55,6 +117,9 @@ pub fn handler(ctx: Context<InitializePlatform>) -> Result<()> {
     cfg.paused           = false;
     cfg.created_at       = clock.unix_timestamp;
     cfg.bump             = ctx.bumps.platform_config;
+    cfg.pending_authority = Pubkey::default();
+    cfg.updated_at       = clock.unix_timestamp;
+    cfg.disabled_features = 0;
 
     // Seed treasury with a small lamport amount to keep it rent-exempt alive
     let cpi = CpiContext::new(
@@ -0,0 +1,79 @@
+// ==========================================================
+// initialize_spl_vault – create a per-(creator, mint) SPL escrow
+//
+// Must be called once per mint before send_tip_spl can deposit
+// that mint. Creates the SplVault PDA
+// [SPL_VAULT_SEED, tip_profile, mint] and its associated token
+// account, whose authority is the SplVault PDA itself.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{TipProfile, SplVault};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeSplVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump  = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SplVault::LEN,
+        seeds = [SPL_VAULT_SEED, tip_profile.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub spl_vault: Account<'info, SplVault>,
+
+    /// Token account holding the escrowed tokens (ATA of the SplVault PDA)
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint      = token_mint,
+        associated_token::authority = spl_vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub token_program:            Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program:           Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeSplVault>) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+
+    let clock = Clock::get()?;
+
+    ctx.accounts.spl_vault.initialize(
+        ctx.accounts.owner.key(),
+        ctx.accounts.tip_profile.key(),
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.vault_token_account.key(),
+        clock.unix_timestamp,
+        ctx.bumps.spl_vault,
+    );
+
+    msg!("SPL vault initialized for profile: {}", ctx.accounts.tip_profile.username);
+    msg!("Mint: {} | SPL vault PDA: {}", ctx.accounts.token_mint.key(), ctx.accounts.spl_vault.key());
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// ==========================================================
+// migrate_account – upgrade an account to its current layout
+//
+// Accounts created under an earlier layout may be smaller than
+// the current size, so they fail to deserialize as the current
+// type. This reallocs the account to the current size (the payer
+// covers the extra rent), zero-fills the new bytes and runs the
+// type's Versioned::upgrade.
+//
+// Supported: TipProfile, TipperRecord, TipGoal, Subscription.
+// The account type is taken from its discriminator. Migration is
+// deterministic, so anyone may pay for it.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::{TipProfile, TipperRecord, TipGoal, Subscription, Versioned};
+use crate::error::ErrorCode;
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account:      Pubkey,
+    pub payer:        Pubkey,
+    pub from_version: u8,
+    pub to_version:   u8,
+    pub old_size:     u32,
+    pub new_size:     u32,
+    pub timestamp:    i64,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Pays any additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: must be owned by this program; its type is read from the
+    /// discriminator and it is fully deserialized after the realloc
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::InvalidAccountData,
+    )]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let info = ctx.accounts.account.to_account_info();
+    let old_size = info.data_len();
+    require!(old_size >= 8, ErrorCode::InvalidAccountData);
+
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&info.try_borrow_data()?[..8]);
+
+    let (from_version, to_version, new_size) = match discriminator {
+        d if d == TipProfile::DISCRIMINATOR   => migrate::<TipProfile>(&ctx)?,
+        d if d == TipperRecord::DISCRIMINATOR => migrate::<TipperRecord>(&ctx)?,
+        d if d == TipGoal::DISCRIMINATOR      => migrate::<TipGoal>(&ctx)?,
+        d if d == Subscription::DISCRIMINATOR => migrate::<Subscription>(&ctx)?,
+        _ => return err!(ErrorCode::UnsupportedAccountType),
+    };
+
+    emit!(AccountMigratedEvent {
+        account:   info.key(),
+        payer:     ctx.accounts.payer.key(),
+        from_version,
+        to_version,
+        old_size:  old_size as u32,
+        new_size:  new_size as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Account {} migrated: v{} -> v{} | size {} -> {}", info.key(), from_version, to_version, old_size, new_size);
+    Ok(())
+}
+
+/// Grow the account to `T::SPACE` if needed, then upgrade and rewrite it.
+fn migrate<T: Versioned>(ctx: &Context<MigrateAccount>) -> Result<(u8, u8, usize)> {
+    let info = ctx.accounts.account.to_account_info();
+
+    if info.data_len() < T::SPACE {
+        let required = Rent::get()?
+            .minimum_balance(T::SPACE)
+            .saturating_sub(info.lamports());
+        if required > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to:   info.clone(),
+                    },
+                ),
+                required,
+            )?;
+        }
+        info.realloc(T::SPACE, true)?;
+    }
+
+    let mut account = T::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let from_version = account.version();
+    require!(from_version < T::VERSION, ErrorCode::AccountAlreadyMigrated);
+
+    account.upgrade()?;
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok((from_version, T::VERSION, info.data_len()))
+}
@@ -1,19 +1,51 @@
 pub mod create_profile;
 pub mod update_profile;
+pub mod close_profile;
+pub mod change_username;
+pub mod block_tipper;
+pub mod unblock_tipper;
+pub mod set_encryption_key;
 pub mod send_tip;
 pub mod send_tip_spl;
+pub mod send_tip_batch;
+pub mod refund_tip;
 pub mod withdraw;
 pub mod withdraw_spl;
 pub mod create_goal;
 pub mod contribute_goal;
+pub mod contribute_goal_spl;
 pub mod close_goal;
+pub mod refund_goal_contribution;
+pub mod release_goal_escrow;
+pub mod create_matching_pool;
+pub mod close_matching_pool;
+pub mod reclaim_goal_match;
+pub mod create_bounty;
+pub mod create_bounty_spl;
+pub mod accept_bounty;
+pub mod fulfill_bounty;
+pub mod dispute_bounty;
+pub mod release_bounty;
+pub mod refund_bounty;
+pub mod resolve_bounty_dispute;
+pub mod configure_pinned_slot;
 pub mod create_subscription;
 pub mod cancel_subscription;
+pub mod close_lapsed_subscription;
 pub mod process_subscription;
+pub mod fund_subscription;
+pub mod crank_subscription;
+pub mod create_subscription_tier;
+pub mod update_subscription_tier;
+pub mod retire_subscription_tier;
 pub mod initialize_vault;
+pub mod initialize_spl_vault;
 // Split instructions: one #[derive(Accounts)] per file (Anchor requirement)
 pub mod configure_split;
+pub mod update_split;
 pub mod send_tip_split;
+pub mod send_tip_split_spl;
+pub mod claim_split_share;
 // Admin instructions: one #[derive(Accounts)] per file (Anchor requirement)
 pub mod initialize_platform;
 pub mod verify_creator;
@@ -28,29 +60,77 @@ pub mod verify_content_access;
 pub mod close_content_gate;
 // v3: Referral instruction
 pub mod register_referral;
+pub mod claim_referral_earnings;
 // v3: Extended profile update (presets, social links, webhook)
 pub mod update_profile_extended;
 // Admin: treasury withdrawal
 pub mod withdraw_treasury;
+pub mod withdraw_treasury_spl;
 // Admin: reentrancy guard reset
 pub mod reset_reentrancy_guard;
+// Admin: config updates and two-step authority transfer
+pub mod update_platform_config;
+pub mod propose_authority;
+pub mod accept_authority;
+// Admin: runtime feature toggles
+pub mod set_feature_flags;
+// Admin: council governance (M-of-N approvals + timelock)
+pub mod initialize_governance;
+pub mod create_admin_proposal;
+pub mod approve_admin_proposal;
+pub mod execute_admin_proposal;
+pub mod cancel_admin_proposal;
+// Maintenance: in-place account layout upgrades
+pub mod migrate_account;
 
 // Export Accounts structs and events explicitly (avoids handler name collisions)
 pub use create_profile::CreateProfile;
 pub use update_profile::UpdateProfile;
-pub use send_tip::{SendTip, TipSentEvent};
+pub use close_profile::{CloseProfile, ProfileClosedEvent};
+pub use change_username::{ChangeUsername, UsernameChangedEvent};
+pub use block_tipper::{BlockTipper, TipperBlockedEvent};
+pub use unblock_tipper::UnblockTipper;
+pub use set_encryption_key::{SetEncryptionKey, EncryptionKeyUpdatedEvent};
+pub use send_tip::{SendTip, TipSentEvent, TipPinnedEvent};
 pub use send_tip_spl::{SendTipSpl, SplTipSentEvent};
+pub use send_tip_batch::{SendTipBatch, BatchTip};
+pub use refund_tip::{RefundTip, TipRefundedEvent};
 pub use withdraw::{Withdraw, WithdrawalEvent};
 pub use withdraw_spl::{WithdrawSpl, SplWithdrawalEvent};
-pub use create_goal::CreateGoal;
+pub use create_goal::{CreateGoal, CreateGoalParams};
 pub use contribute_goal::{ContributeGoal, GoalContributionEvent};
+pub use contribute_goal_spl::ContributeGoalSpl;
 pub use close_goal::CloseGoal;
+pub use refund_goal_contribution::{RefundGoalContribution, GoalRefundEvent};
+pub use release_goal_escrow::{ReleaseGoalEscrow, GoalEscrowReleasedEvent};
+pub use create_matching_pool::{CreateMatchingPool, MatchingPoolCreatedEvent, MatchedEvent};
+pub use close_matching_pool::{CloseMatchingPool, MatchingPoolClosedEvent};
+pub use reclaim_goal_match::{ReclaimGoalMatch, GoalMatchReclaimedEvent};
+pub use create_bounty::{CreateBounty, BountyCreatedEvent};
+pub use create_bounty_spl::{CreateBountySpl};
+pub use accept_bounty::{AcceptBounty, BountyAcceptedEvent};
+pub use fulfill_bounty::{FulfillBounty, BountyFulfilledEvent};
+pub use dispute_bounty::{DisputeBounty, BountyDisputedEvent};
+pub use release_bounty::{ReleaseBounty, BountyReleasedEvent};
+pub use refund_bounty::{RefundBounty, BountyRefundedEvent};
+pub use resolve_bounty_dispute::{ResolveBountyDispute, BountyDisputeResolvedEvent};
+pub use configure_pinned_slot::{ConfigurePinnedSlot, PinnedSlotConfiguredEvent};
 pub use create_subscription::CreateSubscription;
 pub use cancel_subscription::CancelSubscription;
+pub use close_lapsed_subscription::{CloseLapsedSubscription, LapsedSubscriptionClosedEvent};
 pub use process_subscription::{ProcessSubscription, SubscriptionProcessedEvent};
+pub use fund_subscription::FundSubscription;
+pub use crank_subscription::{CrankSubscription, SubscriptionCrankedEvent};
+pub use create_subscription_tier::CreateSubscriptionTier;
+pub use update_subscription_tier::UpdateSubscriptionTier;
+pub use retire_subscription_tier::RetireSubscriptionTier;
 pub use initialize_vault::InitializeVault;
+pub use initialize_spl_vault::InitializeSplVault;
 pub use configure_split::ConfigureSplit;
+pub use update_split::UpdateSplit;
 pub use send_tip_split::{SendTipSplit, TipSplitSentEvent};
+pub use send_tip_split_spl::{SendTipSplitSpl, SplTipSplitSentEvent};
+pub use claim_split_share::{ClaimSplitShare, SplitShareClaimedEvent};
 pub use initialize_platform::{InitializePlatform, PlatformConfig};
 pub use verify_creator::VerifyCreator;
 pub use pause_platform::PausePlatform;
@@ -62,6 +142,18 @@ pub use create_content_gate::CreateContentGate;
 pub use verify_content_access::{VerifyContentAccess, ContentAccessEvent};
 pub use close_content_gate::CloseContentGate;
 pub use register_referral::{RegisterReferral, ReferralCreatedEvent};
-pub use update_profile_extended::UpdateProfileExtended;
+pub use claim_referral_earnings::{ClaimReferralEarnings, ReferralEarningsClaimedEvent};
+pub use update_profile_extended::{UpdateProfileExtended, UpdateProfileExtendedParams};
 pub use withdraw_treasury::WithdrawTreasury;
+pub use withdraw_treasury_spl::WithdrawTreasurySpl;
 pub use reset_reentrancy_guard::ResetReentrancyGuard;
+pub use update_platform_config::{UpdatePlatformConfig, PlatformConfigUpdatedEvent};
+pub use propose_authority::ProposeAuthority;
+pub use accept_authority::{AcceptAuthority, AuthorityTransferredEvent};
+pub use set_feature_flags::{SetFeatureFlags, FeatureFlagsUpdatedEvent};
+pub use initialize_governance::{InitializeGovernance, GovernanceInitializedEvent};
+pub use create_admin_proposal::{CreateAdminProposal, AdminProposalCreatedEvent};
+pub use approve_admin_proposal::{ApproveAdminProposal, AdminProposalApprovedEvent};
+pub use execute_admin_proposal::{ExecuteAdminProposal, AdminProposalExecutedEvent};
+pub use cancel_admin_proposal::CancelAdminProposal;
+pub use migrate_account::{MigrateAccount, AccountMigratedEvent};
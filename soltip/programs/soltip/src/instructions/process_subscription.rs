@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::{TipProfile, Subscription};
+use anchor_spl::token::{self, Token, Transfer as SplTransfer, TokenAccount};
+use crate::state::{TipProfile, Subscription, SubscriptionTier, release_tier_slot, Referral, check_referral, referral_share, referrer_token_account};
 use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
@@ -17,8 +18,16 @@ pub struct SubscriptionProcessedEvent {
     pub recipient_profile:  Pubkey,
     /// The Subscription PDA
     pub subscription:       Pubkey,
-    /// Amount transferred in lamports
+    /// Amount transferred in lamports or token base units
     pub amount:             u64,
+    /// Intervals covered by this payment (>1 when missed intervals are back-charged)
+    pub intervals_charged:  u32,
+    /// Cumulative intervals that went unpaid past their due date
+    pub missed_intervals:   u32,
+    /// Whether the payment was made in an SPL token
+    pub is_spl:             bool,
+    /// SPL token mint (meaningful only when `is_spl`)
+    pub token_mint:         Pubkey,
     /// Cumulative total paid so far (after this payment)
     pub total_paid:         u64,
     /// Number of payments made so far (after this one)
@@ -67,73 +76,279 @@ pub struct ProcessSubscription<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    /// CHECK: PDA verified by seeds – platform treasury receives platform fee
+    /// CHECK: must match platform_config.treasury
     #[account(
         mut,
-        seeds = [PLATFORM_TREASURY_SEED],
-        bump,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
     )]
     pub platform_treasury: UncheckedAccount<'info>,
 
+    /// SPL only: subscriber's token account (subscription PDA is its delegate)
+    #[account(mut)]
+    pub subscriber_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only: creator's token account (receives creator share)
+    #[account(mut)]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only: treasury-owned token account (receives platform fee)
+    #[account(mut)]
+    pub platform_fee_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// SPL only: referrer's token account (receives the referral share)
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL only
+    pub token_program: Option<Program<'info, Token>>,
+
     /// System program for transferring SOL
     pub system_program: Program<'info, System>,
+
+    /// The subscription's tier; required when this payment ends a tiered
+    /// subscription, so its member slot is released
+    #[account(
+        mut,
+        address = subscription.tier @ ErrorCode::SubscriptionTierMismatch,
+    )]
+    pub subscription_tier: Option<Account<'info, SubscriptionTier>>,
+}
+
+/// Token accounts used by a delegated SPL subscription payment.
+pub(crate) struct SplPaymentAccounts<'a, 'info> {
+    pub source:        &'a Account<'info, TokenAccount>,
+    pub recipient:     &'a Account<'info, TokenAccount>,
+    pub platform_fee:  &'a Account<'info, TokenAccount>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> SplPaymentAccounts<'a, 'info> {
+    /// Unwrap the optional SPL accounts, failing if any are missing.
+    pub fn from_options(
+        source:        Option<&'a Account<'info, TokenAccount>>,
+        recipient:     Option<&'a Account<'info, TokenAccount>>,
+        platform_fee:  Option<&'a Account<'info, TokenAccount>>,
+        token_program: Option<&'a Program<'info, Token>>,
+    ) -> Result<Self> {
+        match (source, recipient, platform_fee, token_program) {
+            (Some(source), Some(recipient), Some(platform_fee), Some(token_program)) => Ok(Self {
+                source, recipient, platform_fee, token_program,
+            }),
+            _ => err!(ErrorCode::MissingTokenAccounts),
+        }
+    }
+
+    /// Validate ownership, mint and delegate approval for a payment of `amount`.
+    pub fn validate(
+        &self,
+        subscription: &Account<'info, Subscription>,
+        recipient_owner: Pubkey,
+        platform_treasury: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        self.validate_accounts(subscription, recipient_owner, platform_treasury)?;
+        require!(
+            self.source.delegate.contains(&subscription.key()) && self.source.delegated_amount >= amount,
+            ErrorCode::SubscriptionDelegateMissing
+        );
+        require!(self.source.amount >= amount, ErrorCode::InsufficientTokenBalance);
+        Ok(())
+    }
+
+    /// Whether the delegate approval and token balance cover `amount`.
+    pub fn can_cover(&self, subscription: &Account<'info, Subscription>, amount: u64) -> bool {
+        self.source.delegate.contains(&subscription.key())
+            && self.source.delegated_amount >= amount
+            && self.source.amount >= amount
+    }
+
+    /// Validate token account ownership and mints (no balance checks).
+    pub fn validate_accounts(
+        &self,
+        subscription: &Account<'info, Subscription>,
+        recipient_owner: Pubkey,
+        platform_treasury: Pubkey,
+    ) -> Result<()> {
+        let mint = subscription.token_mint;
+        require!(self.source.owner == subscription.subscriber, ErrorCode::TokenAccountOwnerMismatch);
+        require!(self.source.mint == mint, ErrorCode::TokenMintMismatch);
+        require!(self.recipient.owner == recipient_owner, ErrorCode::TokenAccountOwnerMismatch);
+        require!(self.recipient.mint == mint, ErrorCode::TokenMintMismatch);
+        require!(self.platform_fee.owner == platform_treasury, ErrorCode::TokenAccountOwnerMismatch);
+        require!(self.platform_fee.mint == mint, ErrorCode::TokenMintMismatch);
+        Ok(())
+    }
+
+    /// Pull `creator_share` and `platform_fee` from the subscriber's token account,
+    /// signing as the subscription PDA (the approved delegate).
+    pub fn transfer(
+        &self,
+        subscription: &Account<'info, Subscription>,
+        creator_share: u64,
+        platform_fee: u64,
+    ) -> Result<()> {
+        self.pull(subscription, self.recipient, creator_share)?;
+        if platform_fee > 0 {
+            self.pull(subscription, self.platform_fee, platform_fee)?;
+        }
+        Ok(())
+    }
+
+    /// Delegated transfer of `amount` from the subscriber's token account to `to`.
+    pub fn pull(
+        &self,
+        subscription: &Account<'info, Subscription>,
+        to: &Account<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        let bump = [subscription.bump];
+        let seeds: &[&[u8]] = &[
+            SUBSCRIPTION_SEED,
+            subscription.subscriber.as_ref(),
+            subscription.recipient_profile.as_ref(),
+            &bump,
+        ];
+        let signer = &[seeds];
+        let cpi = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            SplTransfer {
+                from:      self.source.to_account_info(),
+                to:        to.to_account_info(),
+                authority: subscription.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(cpi, amount)
+    }
 }
 
 /// Handler for processing a subscription payment
 /// This should be called when a subscription payment is due
 pub fn handler(ctx: Context<ProcessSubscription>) -> Result<()> {
     // Validate feature is enabled
-    require!(ENABLE_SUBSCRIPTIONS, ErrorCode::SubscriptionsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_SUBSCRIPTIONS), ErrorCode::SubscriptionsDisabled);
 
     // Platform pause check
     require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
 
-    let subscription = &mut ctx.accounts.subscription;
-    let recipient_profile = &mut ctx.accounts.recipient_profile;
     let clock = Clock::get()?;
 
     // Capture payments_made before processing to determine if this is first payment
-    let is_first_payment = subscription.payment_count == 0;
+    let is_first_payment = ctx.accounts.subscription.payment_count == 0;
+
+    // Amount due depends on how many intervals were missed and the
+    // creator's back-charge policy; compute it before state advances
+    let amount = ctx.accounts.subscription.amount_due(clock.unix_timestamp)?;
 
     // Process the payment (this validates payment is due and updates state)
-    subscription.process_payment(clock.unix_timestamp)?;
+    let intervals_charged = ctx.accounts.subscription.process_payment(clock.unix_timestamp)?;
+    if !ctx.accounts.subscription.is_active {
+        release_tier_slot(ctx.accounts.subscription.tier, ctx.accounts.subscription_tier.as_mut())?;
+        ctx.accounts.recipient_profile.remove_subscriber();
+    }
 
-    let amount = subscription.amount_per_interval;
+    let is_spl = ctx.accounts.subscription.is_spl;
 
     // Calculate platform fee and creator share
-    let platform_fee = calculate_fee(amount, PLATFORM_FEE_BPS)?;
+    let platform_fee = calculate_fee(amount, ctx.accounts.platform_config.platform_fee_bps)?;
     let creator_share = amount
         .checked_sub(platform_fee)
         .ok_or(ErrorCode::MathUnderflow)?;
 
-    // Transfer creator share to recipient
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.subscriber.to_account_info(),
-            to: ctx.accounts.recipient_owner.to_account_info(),
-        },
-    );
-    transfer(transfer_ctx, creator_share)?;
-
-    // Transfer platform fee to treasury
-    if platform_fee > 0 {
-        let fee_ctx = CpiContext::new(
+    // Referrer's share of the platform fee, if the creator was referred
+    check_referral(ctx.accounts.recipient_profile.referral, ctx.accounts.referral.as_ref())?;
+    let referrer_cut = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+    let treasury_fee = platform_fee
+        .checked_sub(referrer_cut)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    if is_spl {
+        require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+        let spl = SplPaymentAccounts::from_options(
+            ctx.accounts.subscriber_token_account.as_ref(),
+            ctx.accounts.recipient_token_account.as_ref(),
+            ctx.accounts.platform_fee_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        spl.validate(
+            &ctx.accounts.subscription,
+            ctx.accounts.recipient_owner.key(),
+            ctx.accounts.platform_treasury.key(),
+            amount,
+        )?;
+        let referrer_ta = referrer_token_account(
+            ctx.accounts.referral.as_ref(),
+            ctx.accounts.referrer_token_account.as_ref(),
+            ctx.accounts.subscription.token_mint,
+            referrer_cut,
+        )?;
+        spl.transfer(&ctx.accounts.subscription, creator_share, treasury_fee)?;
+        if let Some(referrer_ta) = referrer_ta {
+            spl.pull(&ctx.accounts.subscription, referrer_ta, referrer_cut)?;
+        }
+    } else {
+        // Transfer creator share to recipient
+        let transfer_ctx = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.subscriber.to_account_info(),
-                to: ctx.accounts.platform_treasury.to_account_info(),
+                to: ctx.accounts.recipient_owner.to_account_info(),
             },
         );
-        transfer(fee_ctx, platform_fee)?;
+        transfer(transfer_ctx, creator_share)?;
+
+        // Transfer platform fee to treasury
+        if treasury_fee > 0 {
+            let fee_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.subscriber.to_account_info(),
+                    to: ctx.accounts.platform_treasury.to_account_info(),
+                },
+            );
+            transfer(fee_ctx, treasury_fee)?;
+        }
+
+        // Referral share accrues on the Referral PDA
+        if let (true, Some(referral)) = (referrer_cut > 0, ctx.accounts.referral.as_ref()) {
+            let ref_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.subscriber.to_account_info(),
+                    to: referral.to_account_info(),
+                },
+            );
+            transfer(ref_ctx, referrer_cut)?;
+        }
     }
 
-    // Record tip in profile (only first payment counts as new tipper)
+    if referrer_cut > 0 {
+        if let Some(referral) = ctx.accounts.referral.as_mut() {
+            if is_spl {
+                referral.record_spl_earning(referrer_cut)?;
+            } else {
+                referral.accrue(referrer_cut)?;
+            }
+        }
+    }
+
+    // Record payment in profile stats (only first payment counts as new tipper;
+    // SPL amounts are tracked separately and stay off the lamport leaderboard)
     let subscriber_key = ctx.accounts.subscriber.key();
-    recipient_profile.record_tip(subscriber_key, amount, is_first_payment)?;
+    if is_spl {
+        ctx.accounts.recipient_profile.record_spl_tip(amount)?;
+    } else {
+        ctx.accounts.recipient_profile.record_tip(subscriber_key, amount, is_first_payment)?;
+    }
+
+    let subscription = &ctx.accounts.subscription;
 
     // Capture values for event (subscription is already mutably borrowed above)
-    let amount_paid    = subscription.amount_per_interval;
+    let amount_paid    = amount;
     let total_paid     = subscription.total_paid;
     let payment_count  = subscription.payment_count;
     let is_active      = subscription.is_active;
@@ -145,6 +360,10 @@ pub fn handler(ctx: Context<ProcessSubscription>) -> Result<()> {
         recipient_profile: ctx.accounts.recipient_profile.key(),
         subscription:      ctx.accounts.subscription.key(),
         amount:            amount_paid,
+        intervals_charged,
+        missed_intervals:  subscription.missed_intervals,
+        is_spl,
+        token_mint:        subscription.token_mint,
         total_paid,
         payment_count,
         is_active,
@@ -153,8 +372,8 @@ pub fn handler(ctx: Context<ProcessSubscription>) -> Result<()> {
     });
 
     msg!("Subscription payment processed");
-    msg!("Amount: {} lamports", amount_paid);
-    msg!("Total paid: {} lamports", total_paid);
+    msg!("Amount: {} ({})", amount_paid, if is_spl { "SPL" } else { "lamports" });
+    msg!("Total paid: {}", total_paid);
     msg!("Payment count: {}", payment_count);
 
     if is_active {
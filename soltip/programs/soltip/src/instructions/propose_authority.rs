@@ -0,0 +1,33 @@
+// ==========================================================
+// propose_authority – step one of a platform authority transfer
+//
+// The current authority nominates a successor, who must then
+// call accept_authority. Proposing the default pubkey cancels
+// a pending nomination.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::instructions::initialize_platform::PlatformConfig;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [PLATFORM_CONFIG_SEED],
+        bump    = platform_config.bump,
+        has_one = authority @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.platform_config.propose_authority(new_authority, clock.unix_timestamp)?;
+
+    msg!("Platform authority proposed: {}", new_authority);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+// ==========================================================
+// reclaim_goal_match – return a failed goal's match to its pool
+//
+// Matches on an escrowed goal are held in the goal PDA with the
+// contributions. If the deadline passes unmet, contributors take
+// their pledges back through refund_goal_contribution and the
+// sponsor takes the pool's match back here. The lamports return
+// to the pool (its matched total drops accordingly), so
+// close_matching_pool pays them out with the rest after expiry.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, TipGoal, MatchingPool};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct GoalMatchReclaimedEvent {
+    pub pool:      Pubkey,
+    pub sponsor:   Pubkey,
+    pub tip_goal:  Pubkey,
+    pub goal_id:   u64,
+    pub amount:    u64,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimGoalMatch<'info> {
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds      = [MATCHING_POOL_SEED, matching_pool.profile.as_ref(), sponsor.key().as_ref(), matching_pool.pool_id.to_le_bytes().as_ref()],
+        bump       = matching_pool.bump,
+        has_one    = sponsor @ ErrorCode::Unauthorized,
+        constraint = matching_pool.goal == tip_goal.key() @ ErrorCode::MatchingPoolMismatch,
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    #[account(
+        mut,
+        seeds = [TIP_GOAL_SEED, tip_goal.profile.as_ref(), tip_goal.goal_id.to_le_bytes().as_ref()],
+        bump  = tip_goal.bump,
+    )]
+    pub tip_goal: Account<'info, TipGoal>,
+
+    /// Creator's profile; the match is taken back out of its stats
+    #[account(
+        mut,
+        address = tip_goal.profile @ ErrorCode::InvalidAccountData,
+    )]
+    pub recipient_profile: Account<'info, TipProfile>,
+}
+
+pub fn handler(ctx: Context<ReclaimGoalMatch>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    ctx.accounts.tip_goal.validate_can_refund(ts)?;
+
+    let amount = ctx.accounts.matching_pool.matched_amount;
+    require!(amount > 0, ErrorCode::NothingToRefund);
+
+    ctx.accounts.tip_goal.withdraw_match_escrow(amount)?;
+
+    // Direct lamport manipulation: both PDAs are program-owned and hold data
+    **ctx.accounts.tip_goal.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.matching_pool.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    ctx.accounts.matching_pool.release_match(amount)?;
+    ctx.accounts.recipient_profile.record_match_reclaim(amount)?;
+
+    emit!(GoalMatchReclaimedEvent {
+        pool:      ctx.accounts.matching_pool.key(),
+        sponsor:   ctx.accounts.sponsor.key(),
+        tip_goal:  ctx.accounts.tip_goal.key(),
+        goal_id:   ctx.accounts.tip_goal.goal_id,
+        amount,
+        timestamp: ts,
+    });
+
+    msg!("Goal match reclaimed: {} lamports → pool", amount);
+    Ok(())
+}
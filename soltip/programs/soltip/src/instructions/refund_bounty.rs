@@ -0,0 +1,94 @@
+// ==========================================================
+// refund_bounty – return an unaccepted or expired bounty
+//
+// The requester may cancel an open bounty at any time; once a
+// bounty expires without being fulfilled anyone may crank the
+// refund. The escrow and all rent go back to the requester.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Bounty, BountyStatus, TipProfile, refund_bounty_escrow};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct BountyRefundedEvent {
+    pub bounty:     Pubkey,
+    pub creator:    Pubkey,
+    pub requester:  Pubkey,
+    pub token_mint: Pubkey,
+    pub amount:     u64,
+    /// false when cancelled by the requester before expiry
+    pub expired:    bool,
+    pub timestamp:  i64,
+}
+
+#[derive(Accounts)]
+pub struct RefundBounty<'info> {
+    /// The requester, or anyone once the bounty has expired
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, bounty.profile.as_ref(), bounty.requester.as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump  = bounty.bump,
+        close = requester,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: receives the SOL escrow and rent
+    #[account(mut, address = bounty.requester @ ErrorCode::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+
+    /// Creator's profile; required unless the bounty is still open
+    #[account(
+        mut,
+        address = bounty.profile @ ErrorCode::BountyAccountsMismatch,
+    )]
+    pub tip_profile: Option<Account<'info, TipProfile>>,
+
+    /// Requester's token account; required for SPL bounties
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Bounty escrow token account
+    #[account(mut)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<RefundBounty>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    ctx.accounts.bounty.ensure_refundable(ctx.accounts.caller.key(), ts)?;
+
+    refund_bounty_escrow(
+        &ctx.accounts.bounty,
+        ctx.accounts.requester_token_account.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.requester.to_account_info(),
+    )?;
+
+    // An open bounty was never counted, and its profile may be closed
+    if ctx.accounts.bounty.status != BountyStatus::Open {
+        ctx.accounts.tip_profile.as_mut().ok_or(ErrorCode::BountyAccountsMismatch)?.remove_bounty();
+    }
+
+    let bounty = &ctx.accounts.bounty;
+    emit!(BountyRefundedEvent {
+        bounty:     bounty.key(),
+        creator:    bounty.creator,
+        requester:  bounty.requester,
+        token_mint: bounty.token_mint,
+        amount:     bounty.amount,
+        expired:    bounty.is_expired(ts),
+        timestamp:  ts,
+    });
+
+    msg!("Bounty {} refunded: {} to requester", bounty.bounty_id, bounty.amount);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// ==========================================================
+// refund_goal_contribution – reclaim an escrowed contribution
+//
+// All-or-nothing goals hold contributions in the TipGoal PDA.
+// Once the deadline passes and the goal did not complete, each
+// contributor may reclaim exactly what their GoalContribution
+// record shows. The record is closed and its rent returned, and
+// the contribution is taken back out of the creator's tip stats
+// and leaderboard.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, TipGoal, GoalContribution};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct GoalRefundEvent {
+    pub contributor: Pubkey,
+    pub tip_goal:    Pubkey,
+    pub goal_id:     u64,
+    pub amount:      u64,
+    pub timestamp:   i64,
+}
+
+#[derive(Accounts)]
+pub struct RefundGoalContribution<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TIP_GOAL_SEED, tip_goal.profile.as_ref(), tip_goal.goal_id.to_le_bytes().as_ref()],
+        bump  = tip_goal.bump,
+    )]
+    pub tip_goal: Account<'info, TipGoal>,
+
+    /// Creator's profile; the contribution is reversed in its stats
+    #[account(
+        mut,
+        address = tip_goal.profile @ ErrorCode::InvalidAccountData,
+    )]
+    pub recipient_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds   = [GOAL_CONTRIBUTION_SEED, tip_goal.key().as_ref(), contributor.key().as_ref()],
+        bump    = goal_contribution.bump,
+        has_one = contributor @ ErrorCode::Unauthorized,
+        has_one = tip_goal    @ ErrorCode::InvalidAccountData,
+        close   = contributor,
+    )]
+    pub goal_contribution: Account<'info, GoalContribution>,
+}
+
+pub fn handler(ctx: Context<RefundGoalContribution>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    ctx.accounts.tip_goal.validate_can_refund(ts)?;
+
+    let amount = ctx.accounts.goal_contribution.amount;
+    require!(amount > 0, ErrorCode::NothingToRefund);
+
+    ctx.accounts.tip_goal.withdraw_escrow(amount)?;
+
+    // Direct lamport manipulation: the goal PDA is program-owned and holds data
+    **ctx.accounts.tip_goal.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.contributor.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let contributions = ctx.accounts.goal_contribution.contribution_count;
+    ctx.accounts.recipient_profile.record_goal_refund(
+        ctx.accounts.contributor.key(),
+        amount,
+        contributions,
+    )?;
+
+    emit!(GoalRefundEvent {
+        contributor: ctx.accounts.contributor.key(),
+        tip_goal:    ctx.accounts.tip_goal.key(),
+        goal_id:     ctx.accounts.tip_goal.goal_id,
+        amount,
+        timestamp:   ts,
+    });
+
+    msg!("Goal refund: {} lamports → {}", amount, ctx.accounts.contributor.key());
+    msg!("Escrow remaining: {}", ctx.accounts.tip_goal.escrow_balance);
+    Ok(())
+}
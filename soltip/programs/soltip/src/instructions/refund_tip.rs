@@ -0,0 +1,95 @@
+// ==========================================================
+// refund_tip – creator returns SOL from the vault to a tipper
+//
+// For accidental or fraudulent tips. Only SOL tips into the vault
+// that fall inside the creator's refund window can be returned
+// (TipperRecord::refundable_amount). SPL tips and tips a sponsor
+// matched never can, so a refund cannot drain a matching pool. The
+// refund is reversed everywhere the tip was counted: vault,
+// TipperRecord, profile stats and the on-chain leaderboard.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, Vault, TipperRecord};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct TipRefundedEvent {
+    pub tipper:            Pubkey,
+    pub recipient:         Pubkey,
+    pub recipient_profile: Pubkey,
+    pub amount:            u64,
+    /// What the tipper has left on record after the refund
+    pub remaining_total:   u64,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct RefundTip<'info> {
+    /// Creator signs; the refund leaves their vault
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds      = [VAULT_SEED, tip_profile.key().as_ref()],
+        bump       = vault.bump,
+        constraint = vault.owner == owner.key() @ ErrorCode::NotProfileOwner,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds      = [TIPPER_RECORD_SEED, tipper.key().as_ref(), tip_profile.key().as_ref()],
+        bump       = tipper_record.bump,
+        constraint = tipper_record.tipper == tipper.key() @ ErrorCode::InvalidAccountData,
+    )]
+    pub tipper_record: Account<'info, TipperRecord>,
+
+    /// CHECK: receives the refund; bound to tipper_record by its seeds
+    #[account(mut)]
+    pub tipper: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RefundTip>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let window = ctx.accounts.tip_profile.refund_window_seconds;
+    ctx.accounts.tipper_record.ensure_refundable(amount, window, ts)?;
+
+    ctx.accounts.tip_profile.acquire_guard()?;
+
+    ctx.accounts.vault.refund(amount)?;
+
+    // Direct lamport manipulation: the vault PDA is program-owned and holds data
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.tipper.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    ctx.accounts.tipper_record.record_refund(amount)?;
+    let tipper_gone = ctx.accounts.tipper_record.is_fully_refunded();
+    let tipper_key  = ctx.accounts.tipper.key();
+    ctx.accounts.tip_profile.record_refund(tipper_key, amount, tipper_gone)?;
+
+    emit!(TipRefundedEvent {
+        tipper:            tipper_key,
+        recipient:         ctx.accounts.owner.key(),
+        recipient_profile: ctx.accounts.tip_profile.key(),
+        amount,
+        remaining_total:   ctx.accounts.tipper_record.total_amount,
+        timestamp:         ts,
+    });
+
+    msg!("Refund: {} lamports → {}", amount, tipper_key);
+
+    ctx.accounts.tip_profile.release_guard();
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::{TipProfile, Referral};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -25,15 +26,19 @@ pub struct RegisterReferral<'info> {
     )]
     pub referrer_profile: Account<'info, TipProfile>,
 
-    /// The referred creator's profile
+    /// The referred creator's profile (records its referrer; one per profile)
     #[account(
+        mut,
         seeds = [TIP_PROFILE_SEED, referee_owner.key().as_ref()],
         bump = referee_profile.bump,
     )]
     pub referee_profile: Account<'info, TipProfile>,
 
-    /// CHECK: validated by PDA derivation of referee_profile
-    pub referee_owner: UncheckedAccount<'info>,
+    /// The referred creator – must consent to the referral
+    #[account(
+        constraint = referee_profile.owner == referee_owner.key() @ ErrorCode::NotProfileOwner,
+    )]
+    pub referee_owner: Signer<'info>,
 
     /// The referral PDA linking referrer → referee
     #[account(
@@ -45,6 +50,13 @@ pub struct RegisterReferral<'info> {
     )]
     pub referral: Account<'info, Referral>,
 
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -52,7 +64,7 @@ pub fn handler(
     ctx: Context<RegisterReferral>,
     fee_share_bps: u16,
 ) -> Result<()> {
-    require!(ENABLE_REFERRALS, ErrorCode::ReferralsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_REFERRALS), ErrorCode::ReferralsDisabled);
 
     // Cannot refer yourself
     require!(
@@ -60,6 +72,12 @@ pub fn handler(
         ErrorCode::CannotReferSelf
     );
 
+    // A profile has at most one referrer, who earns on its platform fees
+    require!(
+        ctx.accounts.referee_profile.referral == Pubkey::default(),
+        ErrorCode::ReferralAlreadyExists
+    );
+
     let clock = Clock::get()?;
     let referral = &mut ctx.accounts.referral;
 
@@ -71,6 +89,8 @@ pub fn handler(
         ctx.bumps.referral,
     )?;
 
+    ctx.accounts.referee_profile.referral = referral.key();
+
     emit!(ReferralCreatedEvent {
         referrer:        ctx.accounts.referrer.key(),
         referee_profile: ctx.accounts.referee_profile.key(),
@@ -0,0 +1,122 @@
+// ==========================================================
+// release_bounty – pay a fulfilled bounty to the creator
+//
+// The requester may approve at any point after fulfillment;
+// once the review window passes without a dispute anyone may
+// crank it. SOL is credited to the creator's Vault, tokens to
+// their SplVault, both withdrawn like tips. The bounty (and an
+// SPL escrow account) are closed and the rent goes back to the
+// requester.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Bounty, TipProfile, Vault, SplVault, pay_bounty_to_creator};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct BountyReleasedEvent {
+    pub bounty:     Pubkey,
+    pub creator:    Pubkey,
+    pub requester:  Pubkey,
+    pub token_mint: Pubkey,
+    pub amount:     u64,
+    /// false when released after the review window lapsed
+    pub approved:   bool,
+    pub timestamp:  i64,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseBounty<'info> {
+    /// The requester, or anyone once the review window has passed
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, bounty.profile.as_ref(), bounty.requester.as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump  = bounty.bump,
+        close = requester,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: receives the bounty's rent
+    #[account(mut, address = bounty.requester @ ErrorCode::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+
+    /// Creator's profile; counts the bounty until it is settled
+    #[account(
+        mut,
+        address = bounty.profile @ ErrorCode::BountyAccountsMismatch,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// Creator's vault; required for SOL bounties
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bounty.profile.as_ref()],
+        bump  = vault.bump,
+    )]
+    pub vault: Option<Account<'info, Vault>>,
+
+    /// Creator's vault for the bounty mint; required for SPL bounties
+    #[account(
+        mut,
+        seeds = [SPL_VAULT_SEED, bounty.profile.as_ref(), bounty.token_mint.as_ref()],
+        bump  = spl_vault.bump,
+    )]
+    pub spl_vault: Option<Account<'info, SplVault>>,
+
+    /// SplVault token account (receives the tokens)
+    #[account(mut)]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Bounty escrow token account
+    #[account(mut)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<ReleaseBounty>) -> Result<()> {
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let caller = ctx.accounts.caller.key();
+    ctx.accounts.bounty.ensure_releasable(caller, ts)?;
+
+    pay_bounty_to_creator(
+        &ctx.accounts.bounty,
+        ctx.accounts.vault.as_mut(),
+        ctx.accounts.spl_vault.as_mut(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.requester.to_account_info(),
+    )?;
+    ctx.accounts.tip_profile.remove_bounty();
+
+    let bounty = &ctx.accounts.bounty;
+    emit!(BountyReleasedEvent {
+        bounty:     bounty.key(),
+        creator:    bounty.creator,
+        requester:  bounty.requester,
+        token_mint: bounty.token_mint,
+        amount:     bounty.amount,
+        approved:   caller == bounty.requester,
+        timestamp:  ts,
+    });
+
+    msg!("Bounty {} released: {} to creator", bounty.bounty_id, bounty.amount);
+    Ok(())
+}
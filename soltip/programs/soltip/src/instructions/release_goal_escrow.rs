@@ -0,0 +1,131 @@
+// ==========================================================
+// release_goal_escrow – pay out a completed escrowed goal
+//
+// Permissionless: anyone may crank once the goal has completed.
+// The platform fee goes to the treasury and the creator share
+// is credited to the creator's Vault (withdrawn via withdraw).
+//   platform_fee  = (escrow_balance − match_escrow) × platform_fee_bps / 10_000
+//   creator_share = escrow_balance − platform_fee
+// Sponsor matches held in escrow are fee-free.
+// If the creator was referred, the referrer's share of the
+// platform fee accrues on the Referral PDA.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, TipGoal, Vault, Referral, check_referral, referral_share};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct GoalEscrowReleasedEvent {
+    pub tip_goal:      Pubkey,
+    pub profile:       Pubkey,
+    pub goal_id:       u64,
+    pub amount:        u64,
+    pub platform_fee:  u64,
+    pub creator_share: u64,
+    pub timestamp:     i64,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseGoalEscrow<'info> {
+    /// Anyone may release a completed goal's escrow
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TIP_GOAL_SEED, tip_goal.profile.as_ref(), tip_goal.goal_id.to_le_bytes().as_ref()],
+        bump  = tip_goal.bump,
+    )]
+    pub tip_goal: Account<'info, TipGoal>,
+
+    /// Creator's profile (read for its referral)
+    #[account(address = tip_goal.profile @ ErrorCode::InvalidAccountData)]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// Creator's vault receives the creator share
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, tip_goal.profile.as_ref()],
+        bump  = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury
+    #[account(
+        mut,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+}
+
+pub fn handler(ctx: Context<ReleaseGoalEscrow>) -> Result<()> {
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    let tip_goal = &mut ctx.accounts.tip_goal;
+    require!(tip_goal.escrowed, ErrorCode::GoalNotEscrowed);
+    require!(tip_goal.completed, ErrorCode::GoalNotCompleted);
+
+    let amount = tip_goal.escrow_balance;
+    require!(amount > 0, ErrorCode::NothingToRefund);
+
+    // Sponsor matches are fee-free, as they are on non-escrowed goals
+    let contributed   = amount.checked_sub(tip_goal.match_escrow).ok_or(ErrorCode::MathUnderflow)?;
+    let platform_fee  = calculate_fee(contributed, ctx.accounts.platform_config.platform_fee_bps)?;
+    let creator_share = amount
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    check_referral(ctx.accounts.tip_profile.referral, ctx.accounts.referral.as_ref())?;
+    let referrer_cut = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+    let treasury_fee = platform_fee
+        .checked_sub(referrer_cut)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    tip_goal.withdraw_escrow(amount)?;
+    tip_goal.match_escrow = 0;
+
+    // Direct lamport manipulation for PDA accounts with data
+    **tip_goal.to_account_info().try_borrow_mut_lamports()? -= creator_share;
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? += creator_share;
+    ctx.accounts.vault.deposit(creator_share)?;
+
+    if treasury_fee > 0 {
+        **tip_goal.to_account_info().try_borrow_mut_lamports()? -= treasury_fee;
+        **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += treasury_fee;
+    }
+
+    if let (true, Some(referral)) = (referrer_cut > 0, ctx.accounts.referral.as_mut()) {
+        **tip_goal.to_account_info().try_borrow_mut_lamports()? -= referrer_cut;
+        **referral.to_account_info().try_borrow_mut_lamports()? += referrer_cut;
+        referral.accrue(referrer_cut)?;
+    }
+
+    emit!(GoalEscrowReleasedEvent {
+        tip_goal:      tip_goal.key(),
+        profile:       tip_goal.profile,
+        goal_id:       tip_goal.goal_id,
+        amount,
+        platform_fee,
+        creator_share,
+        timestamp:     ts,
+    });
+
+    msg!("Goal escrow released: {} | fee: {} | creator: {}", amount, platform_fee, creator_share);
+    Ok(())
+}
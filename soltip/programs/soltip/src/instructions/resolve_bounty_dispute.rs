@@ -0,0 +1,160 @@
+// ==========================================================
+// resolve_bounty_dispute – platform authority settles a dispute
+//
+// Pays a disputed bounty to the creator (as release_bounty)
+// or back to the requester (as refund_bounty). Either way the
+// bounty is closed and its rent returned to the requester.
+// Once a council governs the platform, disputes are settled
+// through AdminAction::ResolveBountyDispute instead.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Bounty, BountyStatus, TipProfile, Vault, SplVault, pay_bounty_to_creator, refund_bounty_escrow};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct BountyDisputeResolvedEvent {
+    pub bounty:     Pubkey,
+    pub creator:    Pubkey,
+    pub requester:  Pubkey,
+    pub token_mint: Pubkey,
+    pub amount:     u64,
+    /// true: paid to the creator; false: refunded to the requester
+    pub released:   bool,
+    pub timestamp:  i64,
+}
+
+#[derive(Accounts)]
+pub struct ResolveBountyDispute<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds   = [PLATFORM_CONFIG_SEED],
+        bump    = platform_config.bump,
+        has_one = authority @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, bounty.profile.as_ref(), bounty.requester.as_ref(), bounty.bounty_id.to_le_bytes().as_ref()],
+        bump  = bounty.bump,
+        close = requester,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: receives the rent (and a refunded SOL escrow)
+    #[account(mut, address = bounty.requester @ ErrorCode::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+
+    /// Creator's profile; counts the bounty until it is settled
+    #[account(
+        mut,
+        address = bounty.profile @ ErrorCode::BountyAccountsMismatch,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    /// Creator's vault; required to release a SOL bounty
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bounty.profile.as_ref()],
+        bump  = vault.bump,
+    )]
+    pub vault: Option<Account<'info, Vault>>,
+
+    /// Creator's vault for the bounty mint; required to release an SPL bounty
+    #[account(
+        mut,
+        seeds = [SPL_VAULT_SEED, bounty.profile.as_ref(), bounty.token_mint.as_ref()],
+        bump  = spl_vault.bump,
+    )]
+    pub spl_vault: Option<Account<'info, SplVault>>,
+
+    /// SplVault token account (receives released tokens)
+    #[account(mut)]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Requester's token account (receives refunded tokens)
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Bounty escrow token account
+    #[account(mut)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<ResolveBountyDispute>, release: bool) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    settle_dispute(
+        &accounts.bounty,
+        release,
+        &accounts.requester.to_account_info(),
+        accounts.vault.as_mut(),
+        accounts.spl_vault.as_mut(),
+        accounts.vault_token_account.as_ref(),
+        accounts.requester_token_account.as_ref(),
+        accounts.escrow_token_account.as_ref(),
+        accounts.token_program.as_ref(),
+    )?;
+    accounts.tip_profile.remove_bounty();
+    Ok(())
+}
+
+/// Pay a disputed bounty to the creator or refund it to the requester.
+/// The caller closes the bounty to the requester. Shared with
+/// execute_admin_proposal.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn settle_dispute<'info>(
+    bounty: &Account<'info, Bounty>,
+    release: bool,
+    requester: &AccountInfo<'info>,
+    vault: Option<&mut Account<'info, Vault>>,
+    spl_vault: Option<&mut Account<'info, SplVault>>,
+    vault_token_account: Option<&Account<'info, TokenAccount>>,
+    requester_token_account: Option<&Account<'info, TokenAccount>>,
+    escrow_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    require!(bounty.status == BountyStatus::Disputed, ErrorCode::BountyNotDisputed);
+
+    if release {
+        pay_bounty_to_creator(
+            bounty,
+            vault,
+            spl_vault,
+            vault_token_account,
+            escrow_token_account,
+            token_program,
+            requester,
+        )?;
+    } else {
+        refund_bounty_escrow(
+            bounty,
+            requester_token_account,
+            escrow_token_account,
+            token_program,
+            requester,
+        )?;
+    }
+
+    emit!(BountyDisputeResolvedEvent {
+        bounty:     bounty.key(),
+        creator:    bounty.creator,
+        requester:  bounty.requester,
+        token_mint: bounty.token_mint,
+        amount:     bounty.amount,
+        released:   release,
+        timestamp:  ts,
+    });
+
+    msg!("Bounty {} dispute resolved: {}", bounty.bounty_id, if release { "released" } else { "refunded" });
+    Ok(())
+}
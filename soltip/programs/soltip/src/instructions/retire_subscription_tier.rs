@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, SubscriptionTier};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RetireSubscriptionTier<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_TIER_SEED, tip_profile.key().as_ref(), subscription_tier.tier_id.to_le_bytes().as_ref()],
+        bump = subscription_tier.bump,
+        constraint = subscription_tier.profile == tip_profile.key() @ ErrorCode::NotProfileOwner,
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
+}
+
+/// Stop accepting new members. Existing subscriptions continue until
+/// cancelled, so the tier account stays open to track them.
+pub fn handler(ctx: Context<RetireSubscriptionTier>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.subscription_tier.retire(clock.unix_timestamp)?;
+    ctx.accounts.tip_profile.decrement_tiers()?;
+
+    msg!(
+        "Subscription tier retired: {} | remaining members: {}",
+        ctx.accounts.subscription_tier.name,
+        ctx.accounts.subscription_tier.member_count
+    );
+    Ok(())
+}
@@ -1,22 +1,26 @@
 // ==========================================================
 // send_tip – SOL tip with vault escrow  (v2)
 //
-// Flow:
+// Flow (after the self-tip, blocklist, pause and anonymous-tip
+// pre-checks):
 //  1. Rate-limit check (cooldown + daily cap)
 //  2. Reentrancy guard acquire
-//  3. Validate amount & message
+//  3. Validate amount, message & media
 //  4. Transfer SOL: tipper → vault PDA
 //  5. Credit vault balance tracking
 //  6. Update / create TipperRecord
 //  7. Update TipProfile stats + leaderboard
-//  8. Emit TipSentEvent
-//  9. Release reentrancy guard
+//  8. Sponsor match (optional MatchingPool) → vault
+//  9. Pinned-slot bid (optional PinnedSlot)
+// 10. Emit TipSentEvent
+// 11. Release reentrancy guard
 // ==========================================================
 
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::{TipProfile, Vault, TipperRecord, RateLimit};
+use crate::state::{TipProfile, Vault, TipperRecord, RateLimit, BlockedTipper, MediaAttachment, EncryptedMessage, MatchingPool, MatchRecord, PinnedSlot, matching_pool_key, apply_match};
 use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::create_matching_pool::MatchedEvent;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -30,10 +34,26 @@ pub struct TipSentEvent {
     pub recipient_profile: Pubkey,
     pub amount:            u64,
     pub message:           Option<String>,
+    pub media:             Option<MediaAttachment>,
+    pub encrypted_message: Option<EncryptedMessage>,
     pub is_new_tipper:     bool,
     pub timestamp:         i64,
 }
 
+/// Emitted when a tip takes the profile's pinned slot.
+#[event]
+pub struct TipPinnedEvent {
+    pub profile:       Pubkey,
+    pub tipper:        Pubkey,
+    pub amount:        u64,
+    pub message:       String,
+    pub expires_at:    i64,
+    /// Live pin this tip displaced, if any
+    pub outbid_tipper: Option<Pubkey>,
+    pub outbid_amount: u64,
+    pub timestamp:     i64,
+}
+
 // ------------------------------------------------------------------
 // Accounts
 // ------------------------------------------------------------------
@@ -85,6 +105,14 @@ pub struct SendTip<'info> {
     )]
     pub rate_limit: Account<'info, RateLimit>,
 
+    /// CHECK: blocklist PDA for this tipper; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), tipper.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
     /// Global platform config – checked for pause state.
     #[account(
         seeds = [PLATFORM_CONFIG_SEED],
@@ -92,6 +120,32 @@ pub struct SendTip<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
+    /// Sponsor pool matching tips to this profile
+    #[account(
+        mut,
+        constraint = matching_pool.profile == recipient_profile.key()
+            && matching_pool.is_for_tips() @ ErrorCode::MatchingPoolMismatch,
+    )]
+    pub matching_pool: Option<Account<'info, MatchingPool>>,
+
+    /// Per-(pool, tipper) match total; required with matching_pool
+    #[account(
+        init_if_needed,
+        payer  = tipper,
+        space  = MatchRecord::LEN,
+        seeds  = [MATCH_RECORD_SEED, matching_pool_key(&matching_pool).as_ref(), tipper.key().as_ref()],
+        bump,
+    )]
+    pub match_record: Option<Account<'info, MatchRecord>>,
+
+    /// Profile's pinned-message slot; pass it to bid for the pin with this tip
+    #[account(
+        mut,
+        seeds = [PINNED_SLOT_SEED, recipient_profile.key().as_ref()],
+        bump  = pinned_slot.bump,
+    )]
+    pub pinned_slot: Option<Account<'info, PinnedSlot>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -102,26 +156,31 @@ pub fn handler(
     ctx: Context<SendTip>,
     amount: u64,
     message: Option<String>,
+    media: Option<MediaAttachment>,
+    encrypted_message: Option<EncryptedMessage>,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let ts    = clock.unix_timestamp;
 
-    // ── 1. Self-tip prevention ──────────────────────────────────────
+    // ── Pre-check: self-tip prevention and blocklist ───────────────
     require!(
         ctx.accounts.tipper.key() != ctx.accounts.recipient_owner.key(),
         ErrorCode::CannotTipSelf
     );
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
 
-    // ── 2. Platform pause check ─────────────────────────────────────
+    // ── Pre-check: platform pause ───────────────────────────────────
     require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
 
-    // ── 3. Anonymous tip check ─────────────────────────────────────
-    // If the creator has disabled anonymous tips, a message must be provided.
-    if !ctx.accounts.recipient_profile.accept_anonymous {
-        require!(message.is_some(), ErrorCode::AnonymousTipsDisabled);
+    // ── Pre-check: anonymous tips ───────────────────────────────────
+    // If the creator or the platform has disabled anonymous tips, a message must be provided.
+    if !ctx.accounts.recipient_profile.accept_anonymous
+        || !ctx.accounts.platform_config.is_enabled(FEATURE_ANONYMOUS_TIPS)
+    {
+        require!(message.is_some() || encrypted_message.is_some(), ErrorCode::AnonymousTipsDisabled);
     }
 
-    // ── 4. Rate-limit ───────────────────────────────────────────────
+    // ── 1. Rate-limit ───────────────────────────────────────────────
     let is_new_rl = ctx.accounts.rate_limit.last_tip_at == 0;
     if is_new_rl {
         // First tip: initialize the rate limit (sets last_tip_at and tip_count_today=1)
@@ -131,21 +190,25 @@ pub fn handler(
             ts,
             ctx.bumps.rate_limit,
         );
-    } else {
-        ctx.accounts.rate_limit.check_and_record(ts, DEFAULT_TIP_COOLDOWN_SECONDS)?;
+    } else if ctx.accounts.platform_config.is_enabled(FEATURE_RATE_LIMITING) {
+        let limits = ctx.accounts.recipient_profile.rate_limit_settings;
+        ctx.accounts.rate_limit.check_and_record(ts, limits.cooldown(), limits.daily_cap())?;
     }
 
-    // ── 5. Reentrancy guard ─────────────────────────────────────────
+    // ── 2. Reentrancy guard ─────────────────────────────────────────
     ctx.accounts.recipient_profile.acquire_guard()?;
 
-    // ── 6. Amount & message validation ─────────────────────────────
+    // ── 3. Amount, message & media validation ──────────────────────
     ctx.accounts.recipient_profile.validate_tip_amount(amount)?;
+    ctx.accounts.recipient_profile.rate_limit_settings.check_amount(amount, is_new_rl)?;
+    ctx.accounts.recipient_profile.validate_media(Some(amount), media.as_ref())?;
+    ctx.accounts.recipient_profile.validate_encrypted_message(encrypted_message.as_ref(), message.is_some())?;
     if let Some(ref m) = message {
         require!(m.len() <= MAX_MESSAGE_LENGTH, ErrorCode::MessageTooLong);
         require!(validate_text_content(m), ErrorCode::UnsafeTextContent);
     }
 
-    // ── 7. Transfer SOL tipper → vault ─────────────────────────────
+    // ── 4. Transfer SOL tipper → vault ─────────────────────────────
     let cpi = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         Transfer {
@@ -154,9 +217,11 @@ pub fn handler(
         },
     );
     transfer(cpi, amount)?;
+
+    // ── 5. Credit vault balance ────────────────────────────────────
     ctx.accounts.vault.deposit(amount)?;
 
-    // ── 7. TipperRecord – init or update ───────────────────────────
+    // ── 6. TipperRecord – init or update ───────────────────────────
     let is_new_tipper = ctx.accounts.tipper_record.tip_count == 0;
     if is_new_tipper {
         ctx.accounts.tipper_record.initialize(
@@ -170,17 +235,72 @@ pub fn handler(
         ctx.accounts.tipper_record.record_tip(amount, ts)?;
     }
 
-    // ── 8. Profile stats + leaderboard ─────────────────────────────
+    // ── 7. Profile stats + leaderboard ─────────────────────────────
     let tipper_key = ctx.accounts.tipper.key();
     ctx.accounts.recipient_profile.record_tip(tipper_key, amount, is_new_tipper)?;
 
-    // ── 9. Emit event ───────────────────────────────────────────────
+    // ── 8. Sponsor match → vault ───────────────────────────────────
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let matched = apply_match(
+        ctx.accounts.matching_pool.as_mut(),
+        ctx.accounts.match_record.as_mut(),
+        ctx.bumps.match_record,
+        tipper_key,
+        &vault_info,
+        amount,
+        ts,
+    )?;
+    if let (true, Some(pool)) = (matched > 0, ctx.accounts.matching_pool.as_ref()) {
+        ctx.accounts.vault.deposit(matched)?;
+        ctx.accounts.recipient_profile.record_matched(matched)?;
+        emit!(MatchedEvent {
+            pool:              pool.key(),
+            sponsor:           pool.sponsor,
+            tipper:            tipper_key,
+            recipient_profile: ctx.accounts.recipient_profile.key(),
+            goal:              Pubkey::default(),
+            amount,
+            matched_amount:    matched,
+            pool_matched:      pool.matched_amount,
+            pool_remaining:    pool.remaining(),
+            timestamp:         ts,
+        });
+        msg!("Matched: {} lamports by {}", matched, pool.sponsor);
+    }
+
+    // A matched tip is final: refunding it would leave the sponsor's
+    // match in the vault.
+    if matched == 0 {
+        let refund_window = ctx.accounts.recipient_profile.refund_window_seconds;
+        ctx.accounts.tipper_record.record_refundable(amount, refund_window, ts)?;
+    }
+
+    // ── 9. Pinned-slot bid ─────────────────────────────────────────
+    if let Some(slot) = ctx.accounts.pinned_slot.as_mut() {
+        let pinned = message.clone().ok_or(ErrorCode::PinRequiresMessage)?;
+        let outbid = slot.pin(tipper_key, amount, pinned.clone(), ts)?;
+        emit!(TipPinnedEvent {
+            profile:       slot.profile,
+            tipper:        tipper_key,
+            amount,
+            message:       pinned,
+            expires_at:    slot.expires_at,
+            outbid_tipper: outbid.map(|(tipper, _)| tipper),
+            outbid_amount: outbid.map(|(_, bid)| bid).unwrap_or(0),
+            timestamp:     ts,
+        });
+        msg!("Pinned until {}", slot.expires_at);
+    }
+
+    // ── 10. Emit event ──────────────────────────────────────────────
     emit!(TipSentEvent {
         tipper:            ctx.accounts.tipper.key(),
         recipient:         ctx.accounts.recipient_owner.key(),
         recipient_profile: ctx.accounts.recipient_profile.key(),
         amount,
         message:           message.clone(),
+        media,
+        encrypted_message,
         is_new_tipper,
         timestamp:         ts,
     });
@@ -190,7 +310,7 @@ pub fn handler(
         msg!("Message: {}", m);
     }
 
-    // ── 10. Release reentrancy guard ───────────────────────────────
+    // ── 11. Release reentrancy guard ───────────────────────────────
     ctx.accounts.recipient_profile.release_guard();
 
     Ok(())
@@ -0,0 +1,267 @@
+// ==========================================================
+// send_tip_batch – SOL tips to several creators, one signature
+//
+// For multi-creator events (charity streams, tournaments). Each
+// entry goes through the same checks and bookkeeping as
+// send_tip – blocklist, anonymous policy, rate limit, amount and
+// message validation, vault deposit, TipperRecord, stats and
+// leaderboard, TipSentEvent – against its own account group in
+// remaining_accounts. Any failing entry fails the whole batch.
+//
+// remaining_accounts, BATCH_TIP_ACCOUNTS per entry, in order:
+//   recipient_profile (mut), recipient_owner, vault (mut),
+//   tipper_record (mut), rate_limit (mut), blocked_tipper
+// TipperRecord and RateLimit PDAs are created on the first tip,
+// as init_if_needed would. Media, sealed messages and sponsor
+// matching still go through send_tip.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use crate::state::{TipProfile, Vault, TipperRecord, RateLimit, BlockedTipper};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::send_tip::TipSentEvent;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// One entry of a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchTip {
+    /// Recipient TipProfile PDA
+    pub profile: Pubkey,
+    pub amount: u64,
+    pub message: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct SendTipBatch<'info> {
+    /// Tipper signs and pays for every entry
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SendTipBatch<'info>>,
+    tips: Vec<BatchTip>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(!tips.is_empty() && tips.len() <= MAX_BATCH_TIPS, ErrorCode::InvalidBatchSize);
+    require!(
+        ctx.remaining_accounts.len() == tips.len() * BATCH_TIP_ACCOUNTS,
+        ErrorCode::BatchAccountsMismatch
+    );
+
+    // Each creator once: their accounts are written back per entry
+    let mut seen = std::collections::BTreeSet::new();
+    for tip in &tips {
+        require!(seen.insert(tip.profile), ErrorCode::BatchDuplicateProfile);
+    }
+
+    let mut total: u64 = 0;
+    for (tip, accounts) in tips.iter().zip(ctx.remaining_accounts.chunks(BATCH_TIP_ACCOUNTS)) {
+        process_tip(ctx.accounts, accounts, tip, ts)?;
+        total = total.checked_add(tip.amount).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    msg!("Batch: {} tips, {} lamports", tips.len(), total);
+    Ok(())
+}
+
+/// send_tip for one batch entry
+fn process_tip<'info>(
+    batch: &SendTipBatch<'info>,
+    accounts: &'info [AccountInfo<'info>],
+    tip: &BatchTip,
+    ts: i64,
+) -> Result<()> {
+    let [profile_info, owner_info, vault_info, record_info, rate_info, blocked_info] = accounts else {
+        return err!(ErrorCode::BatchAccountsMismatch);
+    };
+    let tipper_key  = batch.tipper.key();
+    let owner_key   = owner_info.key();
+    let profile_key = profile_info.key();
+    require_keys_eq!(profile_key, tip.profile, ErrorCode::BatchAccountsMismatch);
+
+    // ── Accounts, checked against their PDA seeds ──────────────────
+    expect_pda(profile_info, &[TIP_PROFILE_SEED, owner_key.as_ref()])?;
+    let mut profile = Account::<TipProfile>::try_from(profile_info)?;
+
+    expect_pda(vault_info, &[VAULT_SEED, profile_key.as_ref()])?;
+    let mut vault = Account::<Vault>::try_from(vault_info)?;
+    require!(vault.owner == owner_key, ErrorCode::VaultNotInitialized);
+
+    expect_pda(blocked_info, &[BLOCKED_TIPPER_SEED, profile_key.as_ref(), tipper_key.as_ref()])?;
+
+    let rl_seeds: [&[u8]; 3] = [RATE_LIMIT_SEED, tipper_key.as_ref(), profile_key.as_ref()];
+    let rl_bump = expect_pda(rate_info, &rl_seeds)?;
+    let mut rate_limit = load_or_create::<RateLimit>(batch, rate_info, &rl_seeds, rl_bump, RateLimit::LEN)?;
+
+    let tr_seeds: [&[u8]; 3] = [TIPPER_RECORD_SEED, tipper_key.as_ref(), profile_key.as_ref()];
+    let tr_bump = expect_pda(record_info, &tr_seeds)?;
+    let mut tipper_record = load_or_create::<TipperRecord>(batch, record_info, &tr_seeds, tr_bump, TipperRecord::LEN)?;
+
+    // ── Self-tip prevention and blocklist ──────────────────────────
+    require!(tipper_key != owner_key, ErrorCode::CannotTipSelf);
+    BlockedTipper::ensure_not_blocked(blocked_info)?;
+
+    // ── Anonymous tip check ────────────────────────────────────────
+    if !profile.accept_anonymous || !batch.platform_config.is_enabled(FEATURE_ANONYMOUS_TIPS) {
+        require!(tip.message.is_some(), ErrorCode::AnonymousTipsDisabled);
+    }
+
+    // ── Rate-limit ─────────────────────────────────────────────────
+    let is_new_rl = rate_limit.last_tip_at == 0;
+    if is_new_rl {
+        rate_limit.initialize(tipper_key, profile_key, ts, rl_bump);
+    } else if batch.platform_config.is_enabled(FEATURE_RATE_LIMITING) {
+        let limits = profile.rate_limit_settings;
+        rate_limit.check_and_record(ts, limits.cooldown(), limits.daily_cap())?;
+    }
+
+    // ── Reentrancy guard ───────────────────────────────────────────
+    profile.acquire_guard()?;
+
+    // ── Amount & message validation ────────────────────────────────
+    profile.validate_tip_amount(tip.amount)?;
+    profile.rate_limit_settings.check_amount(tip.amount, is_new_rl)?;
+    if let Some(ref m) = tip.message {
+        require!(m.len() <= MAX_MESSAGE_LENGTH, ErrorCode::MessageTooLong);
+        require!(validate_text_content(m), ErrorCode::UnsafeTextContent);
+    }
+
+    // ── Transfer SOL tipper → vault ────────────────────────────────
+    let cpi = CpiContext::new(
+        batch.system_program.to_account_info(),
+        Transfer {
+            from: batch.tipper.to_account_info(),
+            to:   vault_info.clone(),
+        },
+    );
+    transfer(cpi, tip.amount)?;
+    vault.deposit(tip.amount)?;
+
+    // ── TipperRecord – init or update ──────────────────────────────
+    let is_new_tipper = tipper_record.tip_count == 0;
+    if is_new_tipper {
+        tipper_record.initialize(tipper_key, profile_key, tip.amount, ts, tr_bump)?;
+    } else {
+        tipper_record.record_tip(tip.amount, ts)?;
+    }
+    tipper_record.record_refundable(tip.amount, profile.refund_window_seconds, ts)?;
+
+    // ── Profile stats + leaderboard ────────────────────────────────
+    profile.record_tip(tipper_key, tip.amount, is_new_tipper)?;
+
+    emit!(TipSentEvent {
+        tipper:            tipper_key,
+        recipient:         owner_key,
+        recipient_profile: profile_key,
+        amount:            tip.amount,
+        message:           tip.message.clone(),
+        media:             None,
+        encrypted_message: None,
+        is_new_tipper,
+        timestamp:         ts,
+    });
+
+    msg!("Tip: {} lamports → {} (vault)", tip.amount, profile.username);
+
+    profile.release_guard();
+
+    // Remaining accounts are not written back by Anchor
+    profile.exit(&crate::ID)?;
+    vault.exit(&crate::ID)?;
+    tipper_record.exit(&crate::ID)?;
+    rate_limit.exit(&crate::ID)?;
+    Ok(())
+}
+
+/// Require `info` to be this program's PDA for `seeds`; returns its bump
+fn expect_pda(info: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (key, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(info.key(), key, ErrorCode::BatchAccountsMismatch);
+    Ok(bump)
+}
+
+/// Load a per-(tipper, profile) PDA, creating it zeroed (tipper pays the
+/// rent) if this is the first tip. Creation follows Anchor's `init`: an
+/// address someone has already sent lamports to is topped up, allocated
+/// and assigned instead, so a pre-funded PDA cannot block the tip.
+fn load_or_create<'info, T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+    batch: &SendTipBatch<'info>,
+    info: &'info AccountInfo<'info>,
+    seeds: &[&[u8]],
+    bump: u8,
+    space: usize,
+) -> Result<Account<'info, T>> {
+    if info.owner == &crate::ID {
+        return Account::try_from(info);
+    }
+
+    let bump = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump);
+    let signer = &[&signer_seeds[..]];
+    let system_program = batch.system_program.to_account_info();
+    let rent = Rent::get()?.minimum_balance(space);
+
+    let current = info.lamports();
+    if current == 0 {
+        create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: batch.tipper.to_account_info(),
+                    to:   info.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(current);
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: batch.tipper.to_account_info(),
+                        to:   info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate { account_to_allocate: info.clone() },
+                signer,
+            ),
+            space as u64,
+        )?;
+        assign(
+            CpiContext::new_with_signer(
+                system_program,
+                Assign { account_to_assign: info.clone() },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+    Account::try_from_unchecked(info)
+}
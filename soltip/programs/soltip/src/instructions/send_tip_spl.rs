@@ -2,13 +2,14 @@
 // send_tip_spl – SPL-token tip with events  (v2)
 //
 // Supports USDC, USDT, or any SPL token.
-// Transfers directly to recipient's token account.
+// Deposits into the creator's per-mint SplVault token account;
+// the creator withdraws later via withdraw_spl (fee split).
 // Emits SplTipSentEvent for indexers.
 // ==========================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer as SplTransfer, TokenAccount};
-use crate::state::{TipProfile, TipperRecord, RateLimit};
+use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer, TokenAccount};
+use crate::state::{TipProfile, TipperRecord, RateLimit, SplVault, BlockedTipper, MediaAttachment, EncryptedMessage};
 use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
@@ -21,6 +22,8 @@ pub struct SplTipSentEvent {
     pub token_mint:        Pubkey,
     pub amount:            u64,
     pub message:           Option<String>,
+    pub media:             Option<MediaAttachment>,
+    pub encrypted_message: Option<EncryptedMessage>,
     pub timestamp:         i64,
 }
 
@@ -32,7 +35,8 @@ pub struct SendTipSpl<'info> {
     /// Tipper's token account (source)
     #[account(
         mut,
-        constraint = tipper_token_account.owner == tipper.key() @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = tipper_token_account.owner == tipper.key()      @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = tipper_token_account.mint  == token_mint.key()  @ ErrorCode::TokenMintMismatch,
     )]
     pub tipper_token_account: Account<'info, TokenAccount>,
 
@@ -47,13 +51,22 @@ pub struct SendTipSpl<'info> {
     /// CHECK: validated by PDA derivation of recipient_profile
     pub recipient_owner: UncheckedAccount<'info>,
 
-    /// Recipient's token account (destination)
+    pub token_mint: Account<'info, Mint>,
+
+    /// Recipient's per-mint SPL vault (must be initialized by the creator)
+    #[account(
+        mut,
+        seeds = [SPL_VAULT_SEED, recipient_profile.key().as_ref(), token_mint.key().as_ref()],
+        bump  = spl_vault.bump,
+    )]
+    pub spl_vault: Account<'info, SplVault>,
+
+    /// Vault token account (destination)
     #[account(
         mut,
-        constraint = recipient_token_account.owner    == recipient_owner.key()          @ ErrorCode::TokenAccountOwnerMismatch,
-        constraint = recipient_token_account.mint     == tipper_token_account.mint      @ ErrorCode::TokenMintMismatch,
+        address = spl_vault.token_account @ ErrorCode::InvalidTokenAccount,
     )]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: Account<'info, TokenAccount>,
 
     /// Per-(tipper, profile) record: init if first tip, else mut
     #[account(
@@ -75,6 +88,14 @@ pub struct SendTipSpl<'info> {
     )]
     pub rate_limit: Account<'info, RateLimit>,
 
+    /// CHECK: blocklist PDA for this tipper; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), tipper.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
     /// Global platform config – checked for pause state.
     #[account(
         seeds = [PLATFORM_CONFIG_SEED],
@@ -90,8 +111,10 @@ pub fn handler(
     ctx: Context<SendTipSpl>,
     amount: u64,
     message: Option<String>,
+    media: Option<MediaAttachment>,
+    encrypted_message: Option<EncryptedMessage>,
 ) -> Result<()> {
-    require!(ENABLE_MULTI_TOKEN, ErrorCode::MultiTokenDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
 
     let clock = Clock::get()?;
     let ts    = clock.unix_timestamp;
@@ -105,9 +128,14 @@ pub fn handler(
         ErrorCode::CannotTipSelf
     );
 
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
     // Anonymous tip check
-    if !ctx.accounts.recipient_profile.accept_anonymous {
-        require!(message.is_some(), ErrorCode::AnonymousTipsDisabled);
+    if !ctx.accounts.recipient_profile.accept_anonymous
+        || !ctx.accounts.platform_config.is_enabled(FEATURE_ANONYMOUS_TIPS)
+    {
+        require!(message.is_some() || encrypted_message.is_some(), ErrorCode::AnonymousTipsDisabled);
     }
 
     // Rate limiting
@@ -119,8 +147,9 @@ pub fn handler(
             ts,
             ctx.bumps.rate_limit,
         );
-    } else {
-        ctx.accounts.rate_limit.check_and_record(ts, DEFAULT_TIP_COOLDOWN_SECONDS)?;
+    } else if ctx.accounts.platform_config.is_enabled(FEATURE_RATE_LIMITING) {
+        let limits = ctx.accounts.recipient_profile.rate_limit_settings;
+        ctx.accounts.rate_limit.check_and_record(ts, limits.cooldown(), limits.daily_cap())?;
     }
 
     // Reentrancy guard (consistent with send_tip and send_tip_split)
@@ -128,6 +157,8 @@ pub fn handler(
 
     // Validate
     ctx.accounts.recipient_profile.validate_tip_amount(amount)?;
+    ctx.accounts.recipient_profile.validate_media(None, media.as_ref())?;
+    ctx.accounts.recipient_profile.validate_encrypted_message(encrypted_message.as_ref(), message.is_some())?;
     if let Some(ref m) = message {
         require!(m.len() <= MAX_MESSAGE_LENGTH, ErrorCode::MessageTooLong);
         require!(validate_text_content(m), ErrorCode::UnsafeTextContent);
@@ -137,16 +168,17 @@ pub fn handler(
         ErrorCode::InsufficientTokenBalance
     );
 
-    // SPL transfer: tipper → recipient token account
+    // SPL transfer: tipper → vault token account
     let cpi = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
         SplTransfer {
             from:      ctx.accounts.tipper_token_account.to_account_info(),
-            to:        ctx.accounts.recipient_token_account.to_account_info(),
+            to:        ctx.accounts.vault_token_account.to_account_info(),
             authority: ctx.accounts.tipper.to_account_info(),
         },
     );
     token::transfer(cpi, amount)?;
+    ctx.accounts.spl_vault.deposit(amount)?;
 
     // TipperRecord – init or update (same pattern as send_tip)
     let is_new_tipper = ctx.accounts.tipper_record.tip_count == 0;
@@ -166,7 +198,7 @@ pub fn handler(
     ctx.accounts.recipient_profile.record_spl_tip(amount)?;
 
     // Emit event
-    let mint = ctx.accounts.tipper_token_account.mint;
+    let mint = ctx.accounts.token_mint.key();
     emit!(SplTipSentEvent {
         tipper:            ctx.accounts.tipper.key(),
         recipient:         ctx.accounts.recipient_owner.key(),
@@ -174,6 +206,8 @@ pub fn handler(
         token_mint:        mint,
         amount,
         message:           message.clone(),
+        media,
+        encrypted_message,
         timestamp:         ts,
     });
 
@@ -1,16 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction};
-use crate::state::{TipProfile, Vault, TipSplit, RateLimit};
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{TipProfile, Vault, TipSplit, RateLimit, BlockedTipper, MediaAttachment, EncryptedMessage};
 use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
 #[event]
 pub struct TipSplitSentEvent {
-    pub tipper:    Pubkey,
-    pub profile:   Pubkey,
-    pub amount:    u64,
-    pub timestamp: i64,
+    pub tipper:            Pubkey,
+    pub profile:           Pubkey,
+    pub amount:            u64,
+    /// Shares were credited to claimable balances rather than paid out
+    pub accrued:           bool,
+    pub media:             Option<MediaAttachment>,
+    pub encrypted_message: Option<EncryptedMessage>,
+    pub timestamp:         i64,
 }
 
 #[derive(Accounts)]
@@ -29,7 +34,7 @@ pub struct SendTipSplit<'info> {
     #[account(mut)]
     pub profile_owner: UncheckedAccount<'info>,
 
-    /// Vault for the primary profile (receives remaining after splits)
+    /// Vault for the primary profile (holds the whole tip in accrual mode)
     #[account(
         mut,
         seeds      = [VAULT_SEED, recipient_profile.key().as_ref()],
@@ -39,6 +44,7 @@ pub struct SendTipSplit<'info> {
     pub vault: Account<'info, Vault>,
 
     #[account(
+        mut,
         seeds      = [TIP_SPLIT_SEED, recipient_profile.key().as_ref()],
         bump       = tip_split.bump,
         constraint = tip_split.profile == recipient_profile.key() @ ErrorCode::SplitNotFound,
@@ -55,6 +61,14 @@ pub struct SendTipSplit<'info> {
     )]
     pub rate_limit: Account<'info, RateLimit>,
 
+    /// CHECK: blocklist PDA for this tipper; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), tipper.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
     /// Global platform config – checked for pause state.
     #[account(
         seeds = [PLATFORM_CONFIG_SEED],
@@ -66,13 +80,16 @@ pub struct SendTipSplit<'info> {
 }
 
 /// Send a SOL tip distributed across recipients.
-/// Recipient wallets must be passed as remaining_accounts in order matching split config.
+/// Recipient wallets must be passed as remaining_accounts in order matching split config,
+/// except in accrual mode, where the tip goes to the vault and no recipients are passed.
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, SendTipSplit<'info>>,
     amount: u64,
     message: Option<String>,
+    media: Option<MediaAttachment>,
+    encrypted_message: Option<EncryptedMessage>,
 ) -> Result<()> {
-    require!(ENABLE_TIP_SPLITS, ErrorCode::TipSplitsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_TIP_SPLITS), ErrorCode::TipSplitsDisabled);
 
     let clock = Clock::get()?;
     let ts    = clock.unix_timestamp;
@@ -86,6 +103,9 @@ pub fn handler<'info>(
         ErrorCode::CannotTipSelf
     );
 
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
     // Rate limit
     let is_new_rl = ctx.accounts.rate_limit.last_tip_at == 0;
     if is_new_rl {
@@ -95,25 +115,74 @@ pub fn handler<'info>(
             ts,
             ctx.bumps.rate_limit,
         );
-    } else {
-        ctx.accounts.rate_limit.check_and_record(ts, DEFAULT_TIP_COOLDOWN_SECONDS)?;
+    } else if ctx.accounts.platform_config.is_enabled(FEATURE_RATE_LIMITING) {
+        let limits = ctx.accounts.recipient_profile.rate_limit_settings;
+        ctx.accounts.rate_limit.check_and_record(ts, limits.cooldown(), limits.daily_cap())?;
     }
 
     // Anonymous tip check
-    if !ctx.accounts.recipient_profile.accept_anonymous {
-        require!(message.is_some(), ErrorCode::AnonymousTipsDisabled);
+    if !ctx.accounts.recipient_profile.accept_anonymous
+        || !ctx.accounts.platform_config.is_enabled(FEATURE_ANONYMOUS_TIPS)
+    {
+        require!(message.is_some() || encrypted_message.is_some(), ErrorCode::AnonymousTipsDisabled);
     }
 
     // Reentrancy guard
     ctx.accounts.recipient_profile.acquire_guard()?;
 
-    // Validate amount and message
+    // Validate amount, message and media
     ctx.accounts.recipient_profile.validate_tip_amount(amount)?;
+    ctx.accounts.recipient_profile.rate_limit_settings.check_amount(amount, is_new_rl)?;
+    ctx.accounts.recipient_profile.validate_media(Some(amount), media.as_ref())?;
+    ctx.accounts.recipient_profile.validate_encrypted_message(encrypted_message.as_ref(), message.is_some())?;
     if let Some(ref m) = message {
         require!(m.len() <= MAX_MESSAGE_LENGTH, ErrorCode::MessageTooLong);
         require!(validate_text_content(m), ErrorCode::UnsafeTextContent);
     }
 
+    // Determine if this is the first split tip from this tipper to this profile.
+    // We use the rate_limit account as a proxy: if it was just initialized (last_tip_at == ts),
+    // this is the first interaction.
+    let is_new_tipper = is_new_rl;
+    let accrued = ctx.accounts.tip_split.accrue;
+
+    let num_recipients = if accrued {
+        accrue_to_vault(ctx.accounts, amount)?
+    } else {
+        push_to_recipients(&ctx, amount)?
+    };
+
+    // Update profile stats
+    ctx.accounts.recipient_profile.record_tip(
+        ctx.accounts.tipper.key(),
+        amount,
+        is_new_tipper,
+    )?;
+
+    emit!(TipSplitSentEvent {
+        tipper:    ctx.accounts.tipper.key(),
+        profile:   ctx.accounts.recipient_profile.key(),
+        amount,
+        accrued,
+        media,
+        encrypted_message,
+        timestamp: ts,
+    });
+
+    msg!("Split tip {} lamports across {} recipients{}", amount, num_recipients, if accrued { " (accrued)" } else { "" });
+    if let Some(ref m) = message { msg!("Message: {}", m); }
+
+    // Release reentrancy guard
+    ctx.accounts.recipient_profile.release_guard();
+
+    Ok(())
+}
+
+/// Pay every share straight to its recipient wallet
+fn push_to_recipients<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, SendTipSplit<'info>>,
+    amount: u64,
+) -> Result<usize> {
     // Calculate per-recipient shares
     let shares = ctx.accounts.tip_split.calculate_shares(amount)?;
     let num_recipients = shares.len();
@@ -131,11 +200,6 @@ pub fn handler<'info>(
         );
     }
 
-    // Determine if this is the first split tip from this tipper to this profile.
-    // We use the rate_limit account as a proxy: if it was just initialized (last_tip_at == ts),
-    // this is the first interaction.
-    let is_new_tipper = is_new_rl;
-
     // Transfer SOL from tipper to each recipient via system_program invoke
     let tipper_key = ctx.accounts.tipper.key();
     for (i, (wallet, share)) in shares.iter().enumerate() {
@@ -151,25 +215,23 @@ pub fn handler<'info>(
         }
     }
 
-    // Update profile stats
-    ctx.accounts.recipient_profile.record_tip(
-        ctx.accounts.tipper.key(),
-        amount,
-        is_new_tipper,
-    )?;
-
-    emit!(TipSplitSentEvent {
-        tipper:    ctx.accounts.tipper.key(),
-        profile:   ctx.accounts.recipient_profile.key(),
-        amount,
-        timestamp: ts,
-    });
-
-    msg!("Split tip {} lamports across {} recipients", amount, num_recipients);
-    if let Some(ref m) = message { msg!("Message: {}", m); }
+    Ok(num_recipients)
+}
 
-    // Release reentrancy guard
-    ctx.accounts.recipient_profile.release_guard();
+/// Deposit the whole tip into the vault and credit each recipient's
+/// claimable balance; the vault holds it back from withdrawals
+fn accrue_to_vault(accounts: &mut SendTipSplit, amount: u64) -> Result<usize> {
+    let cpi = CpiContext::new(
+        accounts.system_program.to_account_info(),
+        Transfer {
+            from: accounts.tipper.to_account_info(),
+            to:   accounts.vault.to_account_info(),
+        },
+    );
+    transfer(cpi, amount)?;
+    accounts.vault.deposit(amount)?;
+    accounts.vault.reserve_split(amount)?;
 
-    Ok(())
+    let shares = accounts.tip_split.accrue_shares(amount)?;
+    Ok(shares.len())
 }
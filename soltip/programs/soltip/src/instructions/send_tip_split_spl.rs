@@ -0,0 +1,239 @@
+// ==========================================================
+// send_tip_split_spl – SPL-token tip split across recipients
+//
+// The token counterpart of send_tip_split. Shares come from
+// TipSplit::calculate_shares; each one is a direct transfer
+// from the tipper's token account to the recipient's token
+// account, passed as remaining_accounts in split order. Each
+// destination must be owned by the configured wallet and hold
+// the tipper's mint.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer, TokenAccount};
+use crate::state::{
+    TipProfile, TipSplit, TipperRecord, RateLimit, BlockedTipper, SplitShare,
+    MediaAttachment, EncryptedMessage,
+};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct SplTipSplitSentEvent {
+    pub tipper:            Pubkey,
+    pub profile:           Pubkey,
+    pub token_mint:        Pubkey,
+    pub amount:            u64,
+    /// Per-recipient amounts, in split order
+    pub shares:            Vec<SplitShare>,
+    pub media:             Option<MediaAttachment>,
+    pub encrypted_message: Option<EncryptedMessage>,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct SendTipSplitSpl<'info> {
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+
+    /// Tipper's token account (source)
+    #[account(
+        mut,
+        constraint = tipper_token_account.owner == tipper.key()      @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = tipper_token_account.mint  == token_mint.key()  @ ErrorCode::TokenMintMismatch,
+    )]
+    pub tipper_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [TIP_PROFILE_SEED, profile_owner.key().as_ref()],
+        bump  = recipient_profile.bump,
+    )]
+    pub recipient_profile: Account<'info, TipProfile>,
+
+    /// CHECK: validated by profile PDA seeds
+    pub profile_owner: UncheckedAccount<'info>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds      = [TIP_SPLIT_SEED, recipient_profile.key().as_ref()],
+        bump       = tip_split.bump,
+        constraint = tip_split.profile == recipient_profile.key() @ ErrorCode::SplitNotFound,
+        constraint = tip_split.is_active                          @ ErrorCode::SplitNotFound,
+    )]
+    pub tip_split: Account<'info, TipSplit>,
+
+    /// Per-(tipper, profile) record: init if first tip, else mut
+    #[account(
+        init_if_needed,
+        payer  = tipper,
+        space  = TipperRecord::LEN,
+        seeds  = [TIPPER_RECORD_SEED, tipper.key().as_ref(), recipient_profile.key().as_ref()],
+        bump,
+    )]
+    pub tipper_record: Account<'info, TipperRecord>,
+
+    #[account(
+        init_if_needed,
+        payer  = tipper,
+        space  = RateLimit::LEN,
+        seeds  = [RATE_LIMIT_SEED, tipper.key().as_ref(), recipient_profile.key().as_ref()],
+        bump,
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    /// CHECK: blocklist PDA for this tipper; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), tipper.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
+    /// Global platform config – checked for pause state.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub token_program:  Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Send an SPL tip distributed across recipients.
+/// Recipient token accounts must be passed as remaining_accounts in order matching split config.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SendTipSplitSpl<'info>>,
+    amount: u64,
+    message: Option<String>,
+    media: Option<MediaAttachment>,
+    encrypted_message: Option<EncryptedMessage>,
+) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_TIP_SPLITS), ErrorCode::TipSplitsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_MULTI_TOKEN), ErrorCode::MultiTokenDisabled);
+
+    let clock = Clock::get()?;
+    let ts    = clock.unix_timestamp;
+
+    // Platform pause check
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    // Self-tip prevention
+    require!(
+        ctx.accounts.tipper.key() != ctx.accounts.profile_owner.key(),
+        ErrorCode::CannotTipSelf
+    );
+
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
+    // Rate limit
+    let is_new_rl = ctx.accounts.rate_limit.last_tip_at == 0;
+    if is_new_rl {
+        ctx.accounts.rate_limit.initialize(
+            ctx.accounts.tipper.key(),
+            ctx.accounts.recipient_profile.key(),
+            ts,
+            ctx.bumps.rate_limit,
+        );
+    } else if ctx.accounts.platform_config.is_enabled(FEATURE_RATE_LIMITING) {
+        let limits = ctx.accounts.recipient_profile.rate_limit_settings;
+        ctx.accounts.rate_limit.check_and_record(ts, limits.cooldown(), limits.daily_cap())?;
+    }
+
+    // Anonymous tip check
+    if !ctx.accounts.recipient_profile.accept_anonymous
+        || !ctx.accounts.platform_config.is_enabled(FEATURE_ANONYMOUS_TIPS)
+    {
+        require!(message.is_some() || encrypted_message.is_some(), ErrorCode::AnonymousTipsDisabled);
+    }
+
+    // Reentrancy guard
+    ctx.accounts.recipient_profile.acquire_guard()?;
+
+    // Validate amount, message and media
+    ctx.accounts.recipient_profile.validate_tip_amount(amount)?;
+    ctx.accounts.recipient_profile.validate_media(None, media.as_ref())?;
+    ctx.accounts.recipient_profile.validate_encrypted_message(encrypted_message.as_ref(), message.is_some())?;
+    if let Some(ref m) = message {
+        require!(m.len() <= MAX_MESSAGE_LENGTH, ErrorCode::MessageTooLong);
+        require!(validate_text_content(m), ErrorCode::UnsafeTextContent);
+    }
+    require!(
+        ctx.accounts.tipper_token_account.amount >= amount,
+        ErrorCode::InsufficientTokenBalance
+    );
+
+    // Claimable balances are kept in lamports only
+    require!(!ctx.accounts.tip_split.accrue, ErrorCode::SplitAccrualSolOnly);
+
+    // Calculate per-recipient shares
+    let shares = ctx.accounts.tip_split.calculate_shares(amount)?;
+    require!(
+        ctx.remaining_accounts.len() == shares.len(),
+        ErrorCode::SplitRecipientMismatch
+    );
+
+    // Each destination must be the configured wallet's account for this mint
+    let mint = ctx.accounts.token_mint.key();
+    for (i, (wallet, _share)) in shares.iter().enumerate() {
+        let destination = Account::<TokenAccount>::try_from(&ctx.remaining_accounts[i])?;
+        require!(destination.owner == *wallet, ErrorCode::SplitRecipientMismatch);
+        require!(destination.mint == mint, ErrorCode::TokenMintMismatch);
+    }
+
+    // SPL transfer: tipper → each recipient token account
+    for (i, (_wallet, share)) in shares.iter().enumerate() {
+        if *share > 0 {
+            let cpi = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SplTransfer {
+                    from:      ctx.accounts.tipper_token_account.to_account_info(),
+                    to:        ctx.remaining_accounts[i].clone(),
+                    authority: ctx.accounts.tipper.to_account_info(),
+                },
+            );
+            token::transfer(cpi, *share)?;
+        }
+    }
+
+    // TipperRecord – init or update (same pattern as send_tip_spl)
+    let is_new_tipper = ctx.accounts.tipper_record.tip_count == 0;
+    if is_new_tipper {
+        ctx.accounts.tipper_record.initialize(
+            ctx.accounts.tipper.key(),
+            ctx.accounts.recipient_profile.key(),
+            amount,
+            ts,
+            ctx.bumps.tipper_record,
+        )?;
+    } else {
+        ctx.accounts.tipper_record.record_tip(amount, ts)?;
+    }
+
+    // Update SPL stats on profile
+    ctx.accounts.recipient_profile.record_spl_tip(amount)?;
+
+    let num_recipients = shares.len();
+    emit!(SplTipSplitSentEvent {
+        tipper:     ctx.accounts.tipper.key(),
+        profile:    ctx.accounts.recipient_profile.key(),
+        token_mint: mint,
+        amount,
+        shares:     shares.into_iter().map(|(wallet, amount)| SplitShare { wallet, amount }).collect(),
+        media,
+        encrypted_message,
+        timestamp:  ts,
+    });
+
+    msg!("Split SPL tip {} tokens (mint: {}) across {} recipients", amount, mint, num_recipients);
+    if let Some(ref m) = message { msg!("Message: {}", m); }
+
+    // Release reentrancy guard
+    ctx.accounts.recipient_profile.release_guard();
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::TipProfile;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[event]
+pub struct EncryptionKeyUpdatedEvent {
+    pub profile:           Pubkey,
+    pub encryption_pubkey: [u8; 32],
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct SetEncryptionKey<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+}
+
+/// Publish (or rotate) the X25519 key tippers seal private messages to.
+/// An all-zero key withdraws it and stops new sealed messages; messages
+/// already sent stay readable with the old secret key.
+pub fn handler(ctx: Context<SetEncryptionKey>, encryption_pubkey: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let tip_profile = &mut ctx.accounts.tip_profile;
+    tip_profile.encryption_pubkey = encryption_pubkey;
+    tip_profile.updated_at        = clock.unix_timestamp;
+
+    emit!(EncryptionKeyUpdatedEvent {
+        profile:   tip_profile.key(),
+        encryption_pubkey,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Encryption key {} for: {}", if tip_profile.has_encryption_key() { "set" } else { "cleared" }, tip_profile.username);
+    Ok(())
+}
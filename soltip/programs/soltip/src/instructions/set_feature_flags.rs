@@ -0,0 +1,53 @@
+// ==========================================================
+// set_feature_flags – admin runtime feature toggles
+//
+// Enables or disables FEATURE_* bits in PlatformConfig so a
+// feature can be switched off during an incident without a
+// program upgrade. Entry points check the flags; cancel, close,
+// refund and withdrawal paths stay open so funds can always leave.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::instructions::initialize_platform::PlatformConfig;
+
+#[event]
+pub struct FeatureFlagsUpdatedEvent {
+    pub authority:         Pubkey,
+    pub features:          u32,
+    pub enabled:           bool,
+    pub disabled_features: u32,
+    pub timestamp:         i64,
+}
+
+#[derive(Accounts)]
+pub struct SetFeatureFlags<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [PLATFORM_CONFIG_SEED],
+        bump    = platform_config.bump,
+        has_one = authority @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+pub fn handler(ctx: Context<SetFeatureFlags>, features: u32, enabled: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let cfg   = &mut ctx.accounts.platform_config;
+
+    cfg.set_features(features, enabled, clock.unix_timestamp)?;
+
+    emit!(FeatureFlagsUpdatedEvent {
+        authority:         cfg.authority,
+        features,
+        enabled,
+        disabled_features: cfg.disabled_features,
+        timestamp:         clock.unix_timestamp,
+    });
+
+    msg!("Features {:#x} enabled: {} | disabled mask: {:#x}", features, enabled, cfg.disabled_features);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, BlockedTipper};
+use crate::instructions::block_tipper::TipperBlockedEvent;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UnblockTipper<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds = [BLOCKED_TIPPER_SEED, tip_profile.key().as_ref(), blocked_tipper.wallet.as_ref()],
+        bump  = blocked_tipper.bump,
+        close = owner,
+    )]
+    pub blocked_tipper: Account<'info, BlockedTipper>,
+}
+
+/// Lift a block. The entry is closed and its rent returned to the owner.
+pub fn handler(ctx: Context<UnblockTipper>) -> Result<()> {
+    let wallet = ctx.accounts.blocked_tipper.wallet;
+
+    emit!(TipperBlockedEvent {
+        profile:   ctx.accounts.tip_profile.key(),
+        wallet,
+        blocked:   false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Unblocked {} on @{}", wallet, ctx.accounts.tip_profile.username);
+    Ok(())
+}
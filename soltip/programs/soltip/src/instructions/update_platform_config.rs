@@ -0,0 +1,56 @@
+// ==========================================================
+// update_platform_config – admin update of fee and treasury
+//
+// Every fee-charging instruction reads platform_fee_bps and
+// treasury from PlatformConfig, so promotions and treasury
+// rotation take effect without a program upgrade. Treasury
+// withdrawals drain whichever treasury is configured, so sweep
+// the old one before rotating away from it.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::instructions::initialize_platform::PlatformConfig;
+
+#[event]
+pub struct PlatformConfigUpdatedEvent {
+    pub authority:        Pubkey,
+    pub treasury:         Pubkey,
+    pub platform_fee_bps: u16,
+    pub timestamp:        i64,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds   = [PLATFORM_CONFIG_SEED],
+        bump    = platform_config.bump,
+        has_one = authority @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePlatformConfig>,
+    platform_fee_bps: Option<u16>,
+    treasury: Option<Pubkey>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let cfg   = &mut ctx.accounts.platform_config;
+
+    cfg.update(platform_fee_bps, treasury, clock.unix_timestamp)?;
+
+    emit!(PlatformConfigUpdatedEvent {
+        authority:        cfg.authority,
+        treasury:         cfg.treasury,
+        platform_fee_bps: cfg.platform_fee_bps,
+        timestamp:        clock.unix_timestamp,
+    });
+
+    msg!("Platform config updated | fee: {} bps | treasury: {}", cfg.platform_fee_bps, cfg.treasury);
+    Ok(())
+}
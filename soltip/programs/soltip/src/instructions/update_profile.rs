@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::TipProfile;
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -17,6 +18,13 @@ pub struct UpdateProfile<'info> {
         has_one = owner @ ErrorCode::NotProfileOwner,
     )]
     pub tip_profile: Account<'info, TipProfile>,
+
+    /// Global platform config – the withdrawal fee may not undercut its fee BPS
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 /// Handler for updating profile settings
@@ -50,6 +58,7 @@ pub fn handler(
         min_tip_amount,
         withdrawal_fee_bps,
         accept_anonymous,
+        ctx.accounts.platform_config.platform_fee_bps,
         clock.unix_timestamp,
     )?;
 
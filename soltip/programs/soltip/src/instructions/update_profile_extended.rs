@@ -1,9 +1,26 @@
 use anchor_lang::prelude::*;
-use crate::state::TipProfile;
+use crate::state::{TipProfile, RateLimitSettings};
 use crate::constants::*;
 use crate::error::ErrorCode;
 
-/// Extended profile update: preset amounts, social links, webhook URL
+/// Extended settings to change; `None` leaves a setting as it is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateProfileExtendedParams {
+    pub preset_amounts: Option<Vec<u64>>,
+    pub social_links: Option<String>,
+    pub webhook_url: Option<String>,
+    pub subscription_grace_seconds: Option<i64>,
+    pub subscription_backcharge: Option<bool>,
+    pub rate_limit_settings: Option<RateLimitSettings>,
+    /// 0 turns media tips off
+    pub min_media_tip_amount: Option<u64>,
+    /// 0 turns refunds off
+    pub refund_window_seconds: Option<i64>,
+}
+
+/// Extended profile update: preset amounts, social links, webhook URL,
+/// subscription late-payment policy, rate limit settings, media tip minimum,
+/// refund window
 #[derive(Accounts)]
 pub struct UpdateProfileExtended<'info> {
     pub owner: Signer<'info>,
@@ -17,12 +34,18 @@ pub struct UpdateProfileExtended<'info> {
     pub tip_profile: Account<'info, TipProfile>,
 }
 
-pub fn handler(
-    ctx: Context<UpdateProfileExtended>,
-    preset_amounts: Option<Vec<u64>>,
-    social_links: Option<String>,
-    webhook_url: Option<String>,
-) -> Result<()> {
+pub fn handler(ctx: Context<UpdateProfileExtended>, params: UpdateProfileExtendedParams) -> Result<()> {
+    let UpdateProfileExtendedParams {
+        preset_amounts,
+        social_links,
+        webhook_url,
+        subscription_grace_seconds,
+        subscription_backcharge,
+        rate_limit_settings,
+        min_media_tip_amount,
+        refund_window_seconds,
+    } = params;
+
     // Validate text content on all user-provided strings
     if let Some(ref v) = social_links {
         require!(validate_text_content(v), ErrorCode::UnsafeTextContent);
@@ -43,6 +66,16 @@ pub fn handler(
     if let Some(url) = webhook_url {
         tip_profile.set_webhook_url(url)?;
     }
+    tip_profile.set_subscription_policy(subscription_grace_seconds, subscription_backcharge)?;
+    if let Some(settings) = rate_limit_settings {
+        tip_profile.set_rate_limit_settings(settings)?;
+    }
+    if let Some(amount) = min_media_tip_amount {
+        tip_profile.set_min_media_tip_amount(amount)?;
+    }
+    if let Some(seconds) = refund_window_seconds {
+        tip_profile.set_refund_window(seconds)?;
+    }
 
     tip_profile.updated_at = clock.unix_timestamp;
 
@@ -0,0 +1,57 @@
+// ==========================================================
+// update_split – change the recipients or mode of a tip split
+//
+// Splits created before accrual mode were sized for 5 fixed
+// recipients; the realloc grows them to the current layout
+// (the owner covers the extra rent) before the update.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, TipSplit, SplitRecipient};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateSplit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds   = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump    = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds   = [TIP_SPLIT_SEED, tip_profile.key().as_ref()],
+        bump    = tip_split.bump,
+        realloc = TipSplit::LEN,
+        realloc::payer = owner,
+        realloc::zero  = false,
+    )]
+    pub tip_split: Account<'info, TipSplit>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateSplit>,
+    recipients: Vec<SplitRecipient>,
+    accrue: bool,
+) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_TIP_SPLITS), ErrorCode::TipSplitsDisabled);
+
+    ctx.accounts.tip_split.update(recipients, accrue)?;
+    msg!("Tip split updated for profile: {}", ctx.accounts.tip_profile.username);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{TipProfile, SubscriptionTier};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateSubscriptionTier<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [TIP_PROFILE_SEED, owner.key().as_ref()],
+        bump = tip_profile.bump,
+        has_one = owner @ ErrorCode::NotProfileOwner,
+    )]
+    pub tip_profile: Account<'info, TipProfile>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_TIER_SEED, tip_profile.key().as_ref(), subscription_tier.tier_id.to_le_bytes().as_ref()],
+        bump = subscription_tier.bump,
+        constraint = subscription_tier.profile == tip_profile.key() @ ErrorCode::NotProfileOwner,
+    )]
+    pub subscription_tier: Account<'info, SubscriptionTier>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// Price and interval changes apply to new members; existing
+/// subscriptions keep the terms they joined at.
+pub fn handler(
+    ctx: Context<UpdateSubscriptionTier>,
+    name: Option<String>,
+    price: Option<u64>,
+    interval_seconds: Option<i64>,
+    max_members: Option<u32>,
+    benefits_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_SUBSCRIPTIONS), ErrorCode::SubscriptionsDisabled);
+
+    let tier = &mut ctx.accounts.subscription_tier;
+    require!(tier.is_active, ErrorCode::SubscriptionTierNotActive);
+
+    let clock = Clock::get()?;
+    tier.update(name, price, interval_seconds, max_members, benefits_hash, clock.unix_timestamp)?;
+
+    msg!("Subscription tier updated: {} | price: {} every {}s", tier.name, tier.price, tier.interval_seconds);
+    Ok(())
+}
@@ -1,5 +1,15 @@
+// ==========================================================
+// verify_content_access – gated perk check
+//
+// Access is granted if the viewer has tipped at least the gate's
+// required amount, or holds a subscription to the creator that is
+// in good standing (active or still inside its grace period).
+// Lapsed and cancelled subscriptions do not unlock perks.
+// ==========================================================
+
 use anchor_lang::prelude::*;
-use crate::state::{TipProfile, TipperRecord, ContentGate};
+use crate::state::{TipProfile, TipperRecord, ContentGate, Subscription};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -10,6 +20,8 @@ pub struct ContentAccessEvent {
     pub gate:         Pubkey,
     pub gate_id:      u64,
     pub total_tipped: u64,
+    /// Access was granted through a subscription in good standing
+    pub via_subscription: bool,
     pub timestamp:    i64,
 }
 
@@ -26,11 +38,19 @@ pub struct VerifyContentAccess<'info> {
     /// CHECK: validated by PDA derivation
     pub profile_owner: UncheckedAccount<'info>,
 
+    /// Viewer's tip history with the creator (omit if they never tipped)
     #[account(
         seeds = [TIPPER_RECORD_SEED, viewer.key().as_ref(), recipient_profile.key().as_ref()],
         bump = tipper_record.bump,
     )]
-    pub tipper_record: Account<'info, TipperRecord>,
+    pub tipper_record: Option<Account<'info, TipperRecord>>,
+
+    /// Viewer's subscription to the creator (omit if none)
+    #[account(
+        seeds = [SUBSCRIPTION_SEED, viewer.key().as_ref(), recipient_profile.key().as_ref()],
+        bump = subscription.bump,
+    )]
+    pub subscription: Option<Account<'info, Subscription>>,
 
     #[account(
         mut,
@@ -39,12 +59,21 @@ pub struct VerifyContentAccess<'info> {
         constraint = content_gate.profile == recipient_profile.key() @ ErrorCode::NotGateOwner,
     )]
     pub content_gate: Account<'info, ContentGate>,
+
+    /// Global platform config – checked for feature flags.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn handler(ctx: Context<VerifyContentAccess>) -> Result<()> {
-    require!(ENABLE_CONTENT_GATES, ErrorCode::ContentGatesDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_CONTENT_GATES), ErrorCode::ContentGatesDisabled);
 
-    let tipper_total = ctx.accounts.tipper_record.total_amount;
+    let tipper_total = ctx.accounts.tipper_record
+        .as_ref()
+        .map_or(0, |r| r.total_amount);
     let clock = Clock::get()?;
 
     // Capture values before mutable borrow
@@ -52,8 +81,11 @@ pub fn handler(ctx: Context<VerifyContentAccess>) -> Result<()> {
     let gate_id = ctx.accounts.content_gate.gate_id;
     let required = ctx.accounts.content_gate.required_amount;
 
-    let has_access = ctx.accounts.content_gate.check_access(tipper_total)?;
-    require!(has_access, ErrorCode::InsufficientTipsForAccess);
+    let via_tips = ctx.accounts.content_gate.check_access(tipper_total)?;
+    let via_subscription = !via_tips && ctx.accounts.subscription
+        .as_ref()
+        .is_some_and(|s| s.is_in_good_standing(clock.unix_timestamp));
+    require!(via_tips || via_subscription, ErrorCode::InsufficientTipsForAccess);
 
     ctx.accounts.content_gate.record_access()?;
 
@@ -63,6 +95,7 @@ pub fn handler(ctx: Context<VerifyContentAccess>) -> Result<()> {
         gate:         gate_key,
         gate_id,
         total_tipped: tipper_total,
+        via_subscription,
         timestamp:    clock.unix_timestamp,
     });
 
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::{TipProfile, TipPoll, Vault};
+use crate::state::{TipProfile, TipPoll, Vault, RateLimit, BlockedTipper};
 use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
@@ -48,6 +48,24 @@ pub struct VotePoll<'info> {
     )]
     pub tip_poll: Account<'info, TipPoll>,
 
+    /// Rate-limit account shared with tips: init on the voter's first interaction
+    #[account(
+        init_if_needed,
+        payer  = voter,
+        space  = RateLimit::LEN,
+        seeds  = [RATE_LIMIT_SEED, voter.key().as_ref(), recipient_profile.key().as_ref()],
+        bump,
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    /// CHECK: blocklist PDA for this voter; must not exist
+    /// (see BlockedTipper::ensure_not_blocked)
+    #[account(
+        seeds = [BLOCKED_TIPPER_SEED, recipient_profile.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub blocked_tipper: UncheckedAccount<'info>,
+
     #[account(
         seeds = [PLATFORM_CONFIG_SEED],
         bump = platform_config.bump,
@@ -63,7 +81,7 @@ pub fn handler(
     amount: u64,
     message: Option<String>,
 ) -> Result<()> {
-    require!(ENABLE_POLLS, ErrorCode::PollsDisabled);
+    require!(ctx.accounts.platform_config.is_enabled(FEATURE_POLLS), ErrorCode::PollsDisabled);
     require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
 
     let clock = Clock::get()?;
@@ -75,8 +93,26 @@ pub fn handler(
         ErrorCode::CannotTipSelf
     );
 
+    // Creator blocklist
+    BlockedTipper::ensure_not_blocked(&ctx.accounts.blocked_tipper)?;
+
+    // Rate limit
+    let is_new_rl = ctx.accounts.rate_limit.last_tip_at == 0;
+    if is_new_rl {
+        ctx.accounts.rate_limit.initialize(
+            ctx.accounts.voter.key(),
+            ctx.accounts.recipient_profile.key(),
+            ts,
+            ctx.bumps.rate_limit,
+        );
+    } else if ctx.accounts.platform_config.is_enabled(FEATURE_RATE_LIMITING) {
+        let limits = ctx.accounts.recipient_profile.rate_limit_settings;
+        ctx.accounts.rate_limit.check_and_record(ts, limits.cooldown(), limits.daily_cap())?;
+    }
+
     // Validate amount
     ctx.accounts.recipient_profile.validate_tip_amount(amount)?;
+    ctx.accounts.recipient_profile.rate_limit_settings.check_amount(amount, is_new_rl)?;
 
     // Validate message
     if let Some(ref m) = message {
@@ -1,6 +1,9 @@
 // withdraw – vault-based withdrawal with fee split  (v2)
+// If the creator was referred, the referrer's share of the platform
+// fee accrues on the Referral PDA (see claim_referral_earnings).
 use anchor_lang::prelude::*;
-use crate::state::{TipProfile, Vault};
+use crate::state::{TipProfile, Vault, Referral, check_referral, referral_share};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -34,14 +37,24 @@ pub struct Withdraw<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
-    /// CHECK: PDA verified by seeds
+    /// Global platform config – source of the fee BPS and treasury.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury
     #[account(
         mut,
-        seeds = [PLATFORM_TREASURY_SEED],
-        bump,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
     )]
     pub platform_treasury: UncheckedAccount<'info>,
 
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -56,9 +69,13 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
 
     let fee_bps       = ctx.accounts.tip_profile.withdrawal_fee_bps;
     let total_fee     = calculate_fee(amount, fee_bps)?;
-    let platform_fee  = calculate_fee(total_fee, PLATFORM_FEE_BPS)?;
+    let platform_fee  = calculate_fee(total_fee, ctx.accounts.platform_config.platform_fee_bps)?;
     let creator_share = amount.checked_sub(total_fee).ok_or(ErrorCode::MathUnderflow)?;
 
+    check_referral(ctx.accounts.tip_profile.referral, ctx.accounts.referral.as_ref())?;
+    let referrer_cut  = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+    let treasury_fee  = platform_fee.checked_sub(referrer_cut).ok_or(ErrorCode::MathUnderflow)?;
+
     // Only deduct the amounts actually transferred out (creator_share + platform_fee)
     // The remainder of total_fee (total_fee - platform_fee) stays in the vault
     let actual_out = creator_share
@@ -71,9 +88,15 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= creator_share;
     **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += creator_share;
 
-    if platform_fee > 0 {
-        **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= platform_fee;
-        **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
+    if treasury_fee > 0 {
+        **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= treasury_fee;
+        **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += treasury_fee;
+    }
+
+    if let (true, Some(referral)) = (referrer_cut > 0, ctx.accounts.referral.as_mut()) {
+        **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= referrer_cut;
+        **referral.to_account_info().try_borrow_mut_lamports()? += referrer_cut;
+        referral.accrue(referrer_cut)?;
     }
 
     emit!(WithdrawalEvent { owner: ctx.accounts.owner.key(), amount, fee: total_fee, creator_share, timestamp: ts });
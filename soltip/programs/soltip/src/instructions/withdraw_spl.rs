@@ -1,19 +1,25 @@
 // ==========================================================
-// withdraw_spl – SPL token withdrawal for creator earnings
+// withdraw_spl – SPL token withdrawal from the creator's vault
 //
-// Transfers SPL tokens (USDC, USDT, etc.) from the creator's
-// token account to their wallet. A platform fee is deducted
-// in tokens and sent to the platform fee token account.
+// Transfers SPL tokens (USDC, USDT, etc.) out of the creator's
+// per-mint SplVault token account via a PDA-signed CPI. The
+// creator share goes to the creator's token account and the
+// platform cut to a token account owned by the treasury PDA,
+// which withdraw_treasury_spl pays out of.
 //
-// Fee model: same withdrawal_fee_bps as SOL withdrawal.
+// Fee model: same as SOL withdraw.
 //   total_fee     = amount × withdrawal_fee_bps / 10_000
-//   platform_fee  = total_fee × PLATFORM_FEE_BPS / 10_000
+//   platform_fee  = total_fee × platform_fee_bps / 10_000
 //   creator_share = amount − total_fee
+// The remainder of total_fee (total_fee − platform_fee) stays
+// in the vault. If the creator was referred, the referrer's share
+// of platform_fee is transferred to the referrer's token account.
 // ==========================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer as SplTransfer, TokenAccount};
-use crate::state::TipProfile;
+use anchor_spl::token::{self, Mint, Token, Transfer as SplTransfer, TokenAccount};
+use crate::state::{TipProfile, SplVault, Referral, check_referral, referral_share, referrer_token_account};
+use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
 
@@ -49,69 +55,149 @@ pub struct WithdrawSpl<'info> {
     )]
     pub tip_profile: Account<'info, TipProfile>,
 
-    /// Creator's SPL token account (source of withdrawal).
-    /// Must be owned by `owner` and hold the correct mint.
+    pub token_mint: Account<'info, Mint>,
+
+    /// Creator's per-mint SPL vault (signs the outgoing transfers).
     #[account(
         mut,
-        constraint = creator_token_account.owner == owner.key()             @ ErrorCode::TokenAccountOwnerMismatch,
-        constraint = creator_token_account.mint  == platform_fee_token_account.mint @ ErrorCode::TokenMintMismatch,
+        seeds      = [SPL_VAULT_SEED, tip_profile.key().as_ref(), token_mint.key().as_ref()],
+        bump       = spl_vault.bump,
+        constraint = spl_vault.owner == owner.key() @ ErrorCode::NotProfileOwner,
+    )]
+    pub spl_vault: Account<'info, SplVault>,
+
+    /// Vault token account (source of withdrawal).
+    #[account(
+        mut,
+        address = spl_vault.token_account @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Creator's SPL token account (receives creator share).
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == owner.key()      @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = creator_token_account.mint  == token_mint.key() @ ErrorCode::TokenMintMismatch,
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
 
+    /// Global platform config – source of the fee BPS and treasury.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump  = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury; owner of the platform fee token account
+    #[account(
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
     /// Platform fee SPL token account (receives platform cut).
-    /// Must share the same mint as the creator token account.
+    /// Must be owned by the treasury PDA and hold the vault mint.
     #[account(
         mut,
-        constraint = platform_fee_token_account.mint == creator_token_account.mint @ ErrorCode::TokenMintMismatch,
+        constraint = platform_fee_token_account.owner == platform_treasury.key() @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = platform_fee_token_account.mint  == token_mint.key()        @ ErrorCode::TokenMintMismatch,
     )]
     pub platform_fee_token_account: Account<'info, TokenAccount>,
 
+    /// Referral PDA of the creator's referrer; required when the profile has one
+    #[account(mut)]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// Referrer's token account (receives the referral share); required when one is due
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
 }
 
-/// Handler: withdraw SPL tokens with fee deduction.
+/// Handler: withdraw SPL tokens from the vault with fee deduction.
 pub fn handler(ctx: Context<WithdrawSpl>, amount: u64) -> Result<()> {
-    require!(ENABLE_MULTI_TOKEN, ErrorCode::MultiTokenDisabled);
     require!(amount >= MIN_WITHDRAWAL_AMOUNT, ErrorCode::WithdrawalTooSmall);
 
     let clock = Clock::get()?;
     let ts    = clock.unix_timestamp;
 
-    // Validate sufficient balance
+    // Validate sufficient balance (tracked and actual)
+    require!(ctx.accounts.spl_vault.balance >= amount, ErrorCode::InsufficientTokenBalance);
     require!(
-        ctx.accounts.creator_token_account.amount >= amount,
+        ctx.accounts.vault_token_account.amount >= amount,
         ErrorCode::InsufficientTokenBalance
     );
 
     let fee_bps       = ctx.accounts.tip_profile.withdrawal_fee_bps;
     let total_fee     = calculate_fee(amount, fee_bps)?;
-    let platform_fee  = calculate_fee(total_fee, PLATFORM_FEE_BPS)?;
+    let platform_fee  = calculate_fee(total_fee, ctx.accounts.platform_config.platform_fee_bps)?;
     let creator_share = amount.checked_sub(total_fee).ok_or(ErrorCode::MathUnderflow)?;
 
-    let mint = ctx.accounts.creator_token_account.mint;
-
-    // Transfer creator share: creator_token_account → owner (same account, no-op if same address)
-    // In practice the creator may hold tokens in an ATA; we send creator_share to that same account
-    // and send platform_fee to the platform fee account.
+    check_referral(ctx.accounts.tip_profile.referral, ctx.accounts.referral.as_ref())?;
+    let referrer_cut  = referral_share(ctx.accounts.referral.as_ref(), platform_fee)?;
+    let treasury_fee  = platform_fee.checked_sub(referrer_cut).ok_or(ErrorCode::MathUnderflow)?;
+    let referrer_ta   = referrer_token_account(
+        ctx.accounts.referral.as_ref(),
+        ctx.accounts.referrer_token_account.as_ref(),
+        ctx.accounts.token_mint.key(),
+        referrer_cut,
+    )?;
+
+    // Only deduct the amounts actually transferred out (creator_share + platform_fee)
+    // The remainder of total_fee (total_fee - platform_fee) stays in the vault
+    let actual_out = creator_share
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    ctx.accounts.spl_vault.withdraw(actual_out)?;
+
+    let mint        = ctx.accounts.token_mint.key();
+    let profile_key = ctx.accounts.tip_profile.key();
+    let bump        = ctx.accounts.spl_vault.bump;
+    let seeds: &[&[u8]] = &[SPL_VAULT_SEED, profile_key.as_ref(), mint.as_ref(), &[bump]];
+    let signer = &[seeds];
+
+    // Creator share: vault token account → creator token account
+    let cpi_creator = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        SplTransfer {
+            from:      ctx.accounts.vault_token_account.to_account_info(),
+            to:        ctx.accounts.creator_token_account.to_account_info(),
+            authority: ctx.accounts.spl_vault.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(cpi_creator, creator_share)?;
 
-    // Send platform fee first (if any)
-    if platform_fee > 0 {
-        let cpi_fee = CpiContext::new(
+    // Platform fee: vault token account → platform fee token account
+    if treasury_fee > 0 {
+        let cpi_fee = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             SplTransfer {
-                from:      ctx.accounts.creator_token_account.to_account_info(),
+                from:      ctx.accounts.vault_token_account.to_account_info(),
                 to:        ctx.accounts.platform_fee_token_account.to_account_info(),
-                authority: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.spl_vault.to_account_info(),
             },
+            signer,
         );
-        token::transfer(cpi_fee, platform_fee)?;
+        token::transfer(cpi_fee, treasury_fee)?;
     }
 
-    // The remaining creator_share stays in the creator's token account (no transfer needed).
-    // We only moved platform_fee out. The net effect for the creator is:
-    //   creator keeps:  amount − platform_fee  (= creator_share + (total_fee − platform_fee))
-    // If the creator also wants to move tokens to a different wallet they call a separate transfer.
-    // This instruction's primary purpose is to deduct the platform fee from earned SPL tips.
+    // Referral share: vault token account → referrer token account
+    if let Some(referrer_ta) = referrer_ta {
+        let cpi_ref = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SplTransfer {
+                from:      ctx.accounts.vault_token_account.to_account_info(),
+                to:        referrer_ta.to_account_info(),
+                authority: ctx.accounts.spl_vault.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(cpi_ref, referrer_cut)?;
+        if let Some(referral) = ctx.accounts.referral.as_mut() {
+            referral.record_spl_earning(referrer_cut)?;
+        }
+    }
 
     emit!(SplWithdrawalEvent {
         owner:         ctx.accounts.owner.key(),
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use crate::instructions::initialize_platform::PlatformConfig;
 use crate::constants::*;
 use crate::error::ErrorCode;
@@ -15,11 +16,11 @@ pub struct WithdrawTreasury<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    /// CHECK: PDA verified by seeds – platform treasury holding SOL
+    /// CHECK: must match platform_config.treasury – the treasury PDA, or a
+    /// rotated-in wallet that signs the transaction itself
     #[account(
         mut,
-        seeds = [PLATFORM_TREASURY_SEED],
-        bump,
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
     )]
     pub platform_treasury: UncheckedAccount<'info>,
 
@@ -31,7 +32,34 @@ pub struct WithdrawTreasury<'info> {
 }
 
 pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
-    let treasury = &ctx.accounts.platform_treasury;
+    pay_from_treasury(
+        &ctx.accounts.system_program,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.destination.to_account_info(),
+        amount,
+    )
+}
+
+/// Bump to sign for `treasury` when it is the program's treasury PDA.
+/// Any other (rotated-in) treasury must be a signer itself.
+pub(crate) fn treasury_signer_bump(treasury: &AccountInfo) -> Result<Option<u8>> {
+    let (pda, bump) = Pubkey::find_program_address(&[PLATFORM_TREASURY_SEED], &crate::ID);
+    if treasury.key() == pda {
+        return Ok(Some(bump));
+    }
+    require!(treasury.is_signer, ErrorCode::TreasuryNotSigner);
+    Ok(None)
+}
+
+/// Move lamports out of the configured treasury, keeping it rent-exempt.
+/// Shared with governance-executed treasury withdrawals.
+pub(crate) fn pay_from_treasury<'info>(
+    system_program: &Program<'info, System>,
+    treasury: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let bump = treasury_signer_bump(treasury)?;
     let treasury_lamports = treasury.lamports();
 
     // Ensure we leave enough for rent exemption
@@ -42,11 +70,16 @@ pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
     require!(amount <= available, ErrorCode::InsufficientBalance);
     require!(amount > 0, ErrorCode::WithdrawalTooSmall);
 
-    // Transfer lamports from treasury PDA to destination via direct lamport manipulation
-    // (treasury is a PDA with no data, so we use direct manipulation)
-    **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.destination.to_account_info().try_borrow_mut_lamports()? += amount;
+    // The treasury is system-owned, so lamports move via a system transfer
+    let cpi = CpiContext::new(
+        system_program.to_account_info(),
+        Transfer { from: treasury.clone(), to: destination.clone() },
+    );
+    match bump {
+        Some(bump) => transfer(cpi.with_signer(&[&[PLATFORM_TREASURY_SEED, &[bump]]]), amount)?,
+        None => transfer(cpi, amount)?,
+    }
 
-    msg!("Treasury withdrawal: {} lamports to {}", amount, ctx.accounts.destination.key());
+    msg!("Treasury withdrawal: {} lamports to {}", amount, destination.key());
     Ok(())
 }
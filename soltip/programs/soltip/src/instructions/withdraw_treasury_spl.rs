@@ -0,0 +1,94 @@
+// ==========================================================
+// withdraw_treasury_spl – move SPL platform fees out of the treasury
+//
+// SPL withdrawals and goal releases pay the platform cut into
+// token accounts owned by platform_config.treasury. When that is
+// the treasury PDA only the program can sign for those accounts,
+// so this instruction (and the matching governance action) is the
+// way fees leave them.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer as SplTransfer};
+use crate::instructions::initialize_platform::PlatformConfig;
+use crate::instructions::withdraw_treasury::treasury_signer_bump;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasurySpl<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds   = [PLATFORM_CONFIG_SEED],
+        bump    = platform_config.bump,
+        has_one = authority @ ErrorCode::NotAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: must match platform_config.treasury – owner of the fee token accounts
+    #[account(
+        address = platform_config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Treasury fee token account (source)
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == platform_treasury.key() @ ErrorCode::TokenAccountOwnerMismatch,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    /// Destination token account for the same mint
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == treasury_token_account.mint @ ErrorCode::TokenMintMismatch,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasurySpl>, amount: u64) -> Result<()> {
+    pay_spl_from_treasury(
+        &ctx.accounts.token_program,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.destination_token_account,
+        amount,
+    )
+}
+
+/// Transfer tokens out of a treasury-owned token account, signed by
+/// the treasury PDA when it is the configured treasury. Shared with
+/// governance-executed withdrawals.
+pub(crate) fn pay_spl_from_treasury<'info>(
+    token_program: &Program<'info, Token>,
+    treasury: &AccountInfo<'info>,
+    source: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    let bump = treasury_signer_bump(treasury)?;
+    require!(amount > 0, ErrorCode::WithdrawalTooSmall);
+    require!(source.amount >= amount, ErrorCode::InsufficientTokenBalance);
+
+    let cpi = CpiContext::new(
+        token_program.to_account_info(),
+        SplTransfer {
+            from:      source.to_account_info(),
+            to:        destination.to_account_info(),
+            authority: treasury.clone(),
+        },
+    );
+    match bump {
+        Some(bump) => token::transfer(cpi.with_signer(&[&[PLATFORM_TREASURY_SEED, &[bump]]]), amount)?,
+        None => token::transfer(cpi, amount)?,
+    }
+
+    msg!(
+        "Treasury SPL withdrawal: {} tokens (mint: {}) to {}",
+        amount, source.mint, destination.key()
+    );
+    Ok(())
+}
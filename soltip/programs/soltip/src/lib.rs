@@ -8,8 +8,14 @@
 //   - TipperRecord PDAs power the on-chain leaderboard without linear scans.
 //   - TipGoal PDAs track fundraising campaigns with optional deadlines.
 //   - Subscription PDAs manage recurring payments (SOL or SPL).
-//   - TipSplit PDAs distribute a single tip across multiple wallets atomically.
-//   - PlatformConfig PDA stores admin settings (fee BPS, pause state, treasury).
+//   - SubscriptionTier PDAs define creator-priced membership levels.
+//   - TipSplit PDAs distribute a single tip across multiple wallets atomically,
+//     or accrue shares in the vault for recipients to claim.
+//   - PlatformConfig PDA stores admin settings (fee BPS, pause state, treasury);
+//     fee and treasury are read on-chain by every fee-charging instruction,
+//     and its disabled_features bitmask gates features at runtime.
+//   - Governance PDA (optional) holds the platform authority as an M-of-N
+//     council; admin actions run through timelocked AdminProposal PDAs.
 //   - Reentrancy guard on TipProfile prevents cross-instruction reentrancy.
 //
 // v3 additions:
@@ -35,52 +41,133 @@ pub use constants::*;
 
 // Import state types explicitly to avoid collisions
 pub use state::{
-    TipProfile, TipGoal, Subscription, Vault,
-    TipperRecord, LeaderboardEntry, TipSplit as TipSplitAccount, SplitRecipient, RateLimit,
-    TipPoll, PollOption, Referral, ContentGate,
+    TipProfile, TipGoal, Subscription, Vault, SplVault,
+    TipperRecord, LeaderboardEntry, TipSplit as TipSplitAccount, SplitRecipient, SplitShare, RateLimit,
+    RateLimitSettings, MediaAttachment, MediaKind, EncryptedMessage,
+    TipPoll, PollOption, Referral, ContentGate, GoalContribution,
+    SubscriptionTier, SubscriptionTierTerms, SubscriptionStatus,
+    Governance, AdminProposal, AdminAction, ProposalStatus,
+    UsernameRegistry, MatchingPool, MatchRecord, Bounty, BountyStatus, PinnedSlot,
 };
 
 // Import instruction contexts
 pub use instructions::{
-    CreateProfile, UpdateProfile, SendTip, SendTipSpl,
-    Withdraw, WithdrawSpl, CreateGoal, ContributeGoal, CloseGoal,
-    CreateSubscription, CancelSubscription, ProcessSubscription,
-    InitializeVault, ConfigureSplit, SendTipSplit,
+    CreateProfile, UpdateProfile, CloseProfile, ChangeUsername, BlockTipper, UnblockTipper,
+    SetEncryptionKey,
+    SendTip, SendTipSpl, SendTipBatch, BatchTip, RefundTip,
+    Withdraw, WithdrawSpl, CreateGoal, CreateGoalParams, ContributeGoal, ContributeGoalSpl, CloseGoal,
+    RefundGoalContribution, ReleaseGoalEscrow,
+    CreateMatchingPool, CloseMatchingPool, ReclaimGoalMatch,
+    CreateBounty, CreateBountySpl, AcceptBounty, FulfillBounty, DisputeBounty,
+    ReleaseBounty, RefundBounty, ResolveBountyDispute,
+    ConfigurePinnedSlot,
+    CreateSubscription, CancelSubscription, CloseLapsedSubscription, ProcessSubscription,
+    FundSubscription, CrankSubscription,
+    CreateSubscriptionTier, UpdateSubscriptionTier, RetireSubscriptionTier,
+    InitializeVault, InitializeSplVault, ConfigureSplit, UpdateSplit, SendTipSplit, SendTipSplitSpl,
+    ClaimSplitShare,
     InitializePlatform, VerifyCreator, PausePlatform, PlatformConfig,
     CreatePoll, VotePoll, ClosePoll,
     CreateContentGate, VerifyContentAccess, CloseContentGate,
-    RegisterReferral, UpdateProfileExtended,
-    WithdrawTreasury, ResetReentrancyGuard,
+    RegisterReferral, ClaimReferralEarnings, UpdateProfileExtended, UpdateProfileExtendedParams,
+    WithdrawTreasury, WithdrawTreasurySpl, ResetReentrancyGuard,
+    UpdatePlatformConfig, ProposeAuthority, AcceptAuthority, SetFeatureFlags,
+    InitializeGovernance, CreateAdminProposal, ApproveAdminProposal,
+    ExecuteAdminProposal, CancelAdminProposal,
+    MigrateAccount,
 };
 
 // Import events
-pub use instructions::send_tip::TipSentEvent;
+pub use instructions::close_profile::ProfileClosedEvent;
+pub use instructions::change_username::UsernameChangedEvent;
+pub use instructions::block_tipper::TipperBlockedEvent;
+pub use instructions::set_encryption_key::EncryptionKeyUpdatedEvent;
+pub use instructions::send_tip::{TipSentEvent, TipPinnedEvent};
 pub use instructions::send_tip_spl::SplTipSentEvent;
+pub use instructions::refund_tip::TipRefundedEvent;
 pub use instructions::withdraw::WithdrawalEvent;
 pub use instructions::withdraw_spl::SplWithdrawalEvent;
 pub use instructions::send_tip_split::TipSplitSentEvent;
+pub use instructions::send_tip_split_spl::SplTipSplitSentEvent;
+pub use instructions::claim_split_share::SplitShareClaimedEvent;
 pub use instructions::contribute_goal::GoalContributionEvent;
+pub use instructions::refund_goal_contribution::GoalRefundEvent;
+pub use instructions::release_goal_escrow::GoalEscrowReleasedEvent;
+pub use instructions::create_matching_pool::{MatchingPoolCreatedEvent, MatchedEvent};
+pub use instructions::close_matching_pool::MatchingPoolClosedEvent;
+pub use instructions::reclaim_goal_match::GoalMatchReclaimedEvent;
+pub use instructions::create_bounty::BountyCreatedEvent;
+pub use instructions::accept_bounty::BountyAcceptedEvent;
+pub use instructions::fulfill_bounty::BountyFulfilledEvent;
+pub use instructions::dispute_bounty::BountyDisputedEvent;
+pub use instructions::release_bounty::BountyReleasedEvent;
+pub use instructions::refund_bounty::BountyRefundedEvent;
+pub use instructions::resolve_bounty_dispute::BountyDisputeResolvedEvent;
+pub use instructions::configure_pinned_slot::PinnedSlotConfiguredEvent;
 pub use instructions::process_subscription::SubscriptionProcessedEvent;
+pub use instructions::crank_subscription::{SubscriptionCrankedEvent, SubscriptionLapsedEvent};
+pub use instructions::close_lapsed_subscription::LapsedSubscriptionClosedEvent;
 pub use instructions::vote_poll::PollVoteEvent;
 pub use instructions::verify_content_access::ContentAccessEvent;
 pub use instructions::register_referral::ReferralCreatedEvent;
+pub use instructions::claim_referral_earnings::ReferralEarningsClaimedEvent;
+pub use instructions::update_platform_config::PlatformConfigUpdatedEvent;
+pub use instructions::accept_authority::AuthorityTransferredEvent;
+pub use instructions::set_feature_flags::FeatureFlagsUpdatedEvent;
+pub use instructions::initialize_governance::GovernanceInitializedEvent;
+pub use instructions::create_admin_proposal::AdminProposalCreatedEvent;
+pub use instructions::approve_admin_proposal::AdminProposalApprovedEvent;
+pub use instructions::execute_admin_proposal::AdminProposalExecutedEvent;
+pub use instructions::migrate_account::AccountMigratedEvent;
 
 // Re-export __client_accounts_* modules to crate root (required by #[program] macro)
 pub(crate) use instructions::create_profile::__client_accounts_create_profile;
 pub(crate) use instructions::update_profile::__client_accounts_update_profile;
+pub(crate) use instructions::close_profile::__client_accounts_close_profile;
+pub(crate) use instructions::change_username::__client_accounts_change_username;
+pub(crate) use instructions::block_tipper::__client_accounts_block_tipper;
+pub(crate) use instructions::unblock_tipper::__client_accounts_unblock_tipper;
+pub(crate) use instructions::set_encryption_key::__client_accounts_set_encryption_key;
 pub(crate) use instructions::send_tip::__client_accounts_send_tip;
 pub(crate) use instructions::send_tip_spl::__client_accounts_send_tip_spl;
+pub(crate) use instructions::send_tip_batch::__client_accounts_send_tip_batch;
+pub(crate) use instructions::refund_tip::__client_accounts_refund_tip;
 pub(crate) use instructions::withdraw::__client_accounts_withdraw;
 pub(crate) use instructions::withdraw_spl::__client_accounts_withdraw_spl;
 pub(crate) use instructions::create_goal::__client_accounts_create_goal;
 pub(crate) use instructions::contribute_goal::__client_accounts_contribute_goal;
+pub(crate) use instructions::contribute_goal_spl::__client_accounts_contribute_goal_spl;
 pub(crate) use instructions::close_goal::__client_accounts_close_goal;
+pub(crate) use instructions::refund_goal_contribution::__client_accounts_refund_goal_contribution;
+pub(crate) use instructions::release_goal_escrow::__client_accounts_release_goal_escrow;
+pub(crate) use instructions::create_matching_pool::__client_accounts_create_matching_pool;
+pub(crate) use instructions::close_matching_pool::__client_accounts_close_matching_pool;
+pub(crate) use instructions::reclaim_goal_match::__client_accounts_reclaim_goal_match;
+pub(crate) use instructions::create_bounty::__client_accounts_create_bounty;
+pub(crate) use instructions::create_bounty_spl::__client_accounts_create_bounty_spl;
+pub(crate) use instructions::accept_bounty::__client_accounts_accept_bounty;
+pub(crate) use instructions::fulfill_bounty::__client_accounts_fulfill_bounty;
+pub(crate) use instructions::dispute_bounty::__client_accounts_dispute_bounty;
+pub(crate) use instructions::release_bounty::__client_accounts_release_bounty;
+pub(crate) use instructions::refund_bounty::__client_accounts_refund_bounty;
+pub(crate) use instructions::resolve_bounty_dispute::__client_accounts_resolve_bounty_dispute;
+pub(crate) use instructions::configure_pinned_slot::__client_accounts_configure_pinned_slot;
 pub(crate) use instructions::create_subscription::__client_accounts_create_subscription;
 pub(crate) use instructions::cancel_subscription::__client_accounts_cancel_subscription;
+pub(crate) use instructions::close_lapsed_subscription::__client_accounts_close_lapsed_subscription;
 pub(crate) use instructions::process_subscription::__client_accounts_process_subscription;
+pub(crate) use instructions::fund_subscription::__client_accounts_fund_subscription;
+pub(crate) use instructions::crank_subscription::__client_accounts_crank_subscription;
+pub(crate) use instructions::create_subscription_tier::__client_accounts_create_subscription_tier;
+pub(crate) use instructions::update_subscription_tier::__client_accounts_update_subscription_tier;
+pub(crate) use instructions::retire_subscription_tier::__client_accounts_retire_subscription_tier;
 pub(crate) use instructions::initialize_vault::__client_accounts_initialize_vault;
+pub(crate) use instructions::initialize_spl_vault::__client_accounts_initialize_spl_vault;
 pub(crate) use instructions::configure_split::__client_accounts_configure_split;
+pub(crate) use instructions::update_split::__client_accounts_update_split;
 pub(crate) use instructions::send_tip_split::__client_accounts_send_tip_split;
+pub(crate) use instructions::send_tip_split_spl::__client_accounts_send_tip_split_spl;
+pub(crate) use instructions::claim_split_share::__client_accounts_claim_split_share;
 pub(crate) use instructions::initialize_platform::__client_accounts_initialize_platform;
 pub(crate) use instructions::verify_creator::__client_accounts_verify_creator;
 pub(crate) use instructions::pause_platform::__client_accounts_pause_platform;
@@ -91,9 +178,21 @@ pub(crate) use instructions::create_content_gate::__client_accounts_create_conte
 pub(crate) use instructions::verify_content_access::__client_accounts_verify_content_access;
 pub(crate) use instructions::close_content_gate::__client_accounts_close_content_gate;
 pub(crate) use instructions::register_referral::__client_accounts_register_referral;
+pub(crate) use instructions::claim_referral_earnings::__client_accounts_claim_referral_earnings;
 pub(crate) use instructions::update_profile_extended::__client_accounts_update_profile_extended;
 pub(crate) use instructions::withdraw_treasury::__client_accounts_withdraw_treasury;
+pub(crate) use instructions::withdraw_treasury_spl::__client_accounts_withdraw_treasury_spl;
 pub(crate) use instructions::reset_reentrancy_guard::__client_accounts_reset_reentrancy_guard;
+pub(crate) use instructions::update_platform_config::__client_accounts_update_platform_config;
+pub(crate) use instructions::propose_authority::__client_accounts_propose_authority;
+pub(crate) use instructions::accept_authority::__client_accounts_accept_authority;
+pub(crate) use instructions::set_feature_flags::__client_accounts_set_feature_flags;
+pub(crate) use instructions::initialize_governance::__client_accounts_initialize_governance;
+pub(crate) use instructions::create_admin_proposal::__client_accounts_create_admin_proposal;
+pub(crate) use instructions::approve_admin_proposal::__client_accounts_approve_admin_proposal;
+pub(crate) use instructions::execute_admin_proposal::__client_accounts_execute_admin_proposal;
+pub(crate) use instructions::cancel_admin_proposal::__client_accounts_cancel_admin_proposal;
+pub(crate) use instructions::migrate_account::__client_accounts_migrate_account;
 
 declare_id!("BhynwWdN5g5S5FfCEgDovajaYQDq925S2Xs8vXas58uo");
 
@@ -128,14 +227,45 @@ pub mod soltip {
         )
     }
 
-    /// Update extended profile settings: preset amounts, social links, webhook URL
+    /// Update extended profile settings: preset amounts, social links, webhook URL,
+    /// subscription grace period and missed-interval back-charge policy, and
+    /// creator rate limits (cooldown, daily cap, per-tip max, first-tip minimum),
+    /// the minimum tip that may carry media (0 = media tips off), and the
+    /// refund window (0 = refunds off)
     pub fn update_profile_extended(
         ctx: Context<UpdateProfileExtended>,
-        preset_amounts: Option<Vec<u64>>,
-        social_links: Option<String>,
-        webhook_url: Option<String>,
+        params: UpdateProfileExtendedParams,
     ) -> Result<()> {
-        instructions::update_profile_extended::handler(ctx, preset_amounts, social_links, webhook_url)
+        instructions::update_profile_extended::handler(ctx, params)
+    }
+
+    /// Move the profile to a new username, releasing the old handle.
+    /// Legacy profiles pass their current username to register it.
+    pub fn change_username(ctx: Context<ChangeUsername>, new_username: String) -> Result<()> {
+        instructions::change_username::handler(ctx, new_username)
+    }
+
+    /// Block a wallet from tipping, voting, contributing or subscribing.
+    pub fn block_tipper(ctx: Context<BlockTipper>, wallet: Pubkey) -> Result<()> {
+        instructions::block_tipper::handler(ctx, wallet)
+    }
+
+    /// Remove a wallet from the blocklist and reclaim the entry's rent.
+    pub fn unblock_tipper(ctx: Context<UnblockTipper>) -> Result<()> {
+        instructions::unblock_tipper::handler(ctx)
+    }
+
+    /// Publish the X25519 key tippers seal private messages to (zeros to withdraw).
+    pub fn set_encryption_key(ctx: Context<SetEncryptionKey>, encryption_pubkey: [u8; 32]) -> Result<()> {
+        instructions::set_encryption_key::handler(ctx, encryption_pubkey)
+    }
+
+    /// Close the profile once no goals, polls, gates, subscribers or accepted
+    /// bounties remain. Drains the vault through the withdraw fee path, closes
+    /// the vault, tip split, pinned slot and any subscription tiers passed in
+    /// remaining_accounts, and returns all rent to the owner.
+    pub fn close_profile<'info>(ctx: Context<'_, '_, 'info, 'info, CloseProfile<'info>>) -> Result<()> {
+        instructions::close_profile::handler(ctx)
     }
 
     // ---- Vault Management ------------------------------------------
@@ -144,31 +274,114 @@ pub mod soltip {
         instructions::initialize_vault::handler(ctx)
     }
 
+    /// Create the per-mint SPL escrow vault that send_tip_spl deposits into.
+    pub fn initialize_spl_vault(ctx: Context<InitializeSplVault>) -> Result<()> {
+        instructions::initialize_spl_vault::handler(ctx)
+    }
+
     // ---- Tipping ---------------------------------------------------
 
-    pub fn send_tip(ctx: Context<SendTip>, amount: u64, message: Option<String>) -> Result<()> {
-        instructions::send_tip::handler(ctx, amount, message)
+    /// Tip SOL into the creator's vault, optionally attaching media
+    /// (gated by the creator's min_media_tip_amount). A message sealed to
+    /// the creator's encryption key may replace the plaintext message.
+    /// Passing a matching pool adds the sponsor's match to the vault;
+    /// passing the pinned slot bids this tip for the pinned message.
+    pub fn send_tip(
+        ctx: Context<SendTip>,
+        amount: u64,
+        message: Option<String>,
+        media: Option<MediaAttachment>,
+        encrypted_message: Option<EncryptedMessage>,
+    ) -> Result<()> {
+        instructions::send_tip::handler(ctx, amount, message, media, encrypted_message)
     }
 
-    pub fn send_tip_spl(ctx: Context<SendTipSpl>, amount: u64, message: Option<String>) -> Result<()> {
-        instructions::send_tip_spl::handler(ctx, amount, message)
+    pub fn send_tip_spl(
+        ctx: Context<SendTipSpl>,
+        amount: u64,
+        message: Option<String>,
+        media: Option<MediaAttachment>,
+        encrypted_message: Option<EncryptedMessage>,
+    ) -> Result<()> {
+        instructions::send_tip_spl::handler(ctx, amount, message, media, encrypted_message)
+    }
+
+    /// Tip up to 5 creators in one transaction. Each entry is checked and
+    /// recorded like send_tip against its account group in remaining_accounts;
+    /// one failing entry fails the batch.
+    pub fn send_tip_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendTipBatch<'info>>,
+        tips: Vec<BatchTip>,
+    ) -> Result<()> {
+        instructions::send_tip_batch::handler(ctx, tips)
+    }
+
+    /// Open or retune the profile's pinned-message auction: pin length,
+    /// opening bid and the raise (bps) needed to outbid a live pin.
+    pub fn configure_pinned_slot(
+        ctx: Context<ConfigurePinnedSlot>,
+        duration_seconds: i64,
+        min_bid: u64,
+        min_increment_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_pinned_slot::handler(ctx, duration_seconds, min_bid, min_increment_bps)
     }
 
     // ---- Multi-Recipient Splits ------------------------------------
 
+    /// Create the split. With `accrue`, split tips land in the vault and
+    /// recipients claim their shares (up to 25 recipients).
     pub fn configure_split(
         ctx: Context<ConfigureSplit>,
         recipients: Vec<SplitRecipient>,
+        accrue: bool,
+    ) -> Result<()> {
+        instructions::configure_split::handler(ctx, recipients, accrue)
+    }
+
+    /// Replace the split recipients or switch accrual mode. Grows splits
+    /// created under the old 5-recipient layout.
+    pub fn update_split(
+        ctx: Context<UpdateSplit>,
+        recipients: Vec<SplitRecipient>,
+        accrue: bool,
     ) -> Result<()> {
-        instructions::configure_split::handler(ctx, recipients)
+        instructions::update_split::handler(ctx, recipients, accrue)
     }
 
     pub fn send_tip_split<'info>(
         ctx: Context<'_, '_, 'info, 'info, SendTipSplit<'info>>,
         amount: u64,
         message: Option<String>,
+        media: Option<MediaAttachment>,
+        encrypted_message: Option<EncryptedMessage>,
+    ) -> Result<()> {
+        instructions::send_tip_split::handler(ctx, amount, message, media, encrypted_message)
+    }
+
+    /// SPL counterpart of send_tip_split: one token transfer per recipient.
+    /// Recipient token accounts go in remaining_accounts, in split order.
+    pub fn send_tip_split_spl<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendTipSplitSpl<'info>>,
+        amount: u64,
+        message: Option<String>,
+        media: Option<MediaAttachment>,
+        encrypted_message: Option<EncryptedMessage>,
     ) -> Result<()> {
-        instructions::send_tip_split::handler(ctx, amount, message)
+        instructions::send_tip_split_spl::handler(ctx, amount, message, media, encrypted_message)
+    }
+
+    /// Pay the caller their accrued share of an accrual-mode split.
+    pub fn claim_split_share(ctx: Context<ClaimSplitShare>) -> Result<()> {
+        instructions::claim_split_share::handler(ctx)
+    }
+
+    // ---- Refunds ---------------------------------------------------
+
+    /// Return SOL from the vault to a tipper whose last tip is inside the
+    /// creator's refund window, reversing it in stats and the leaderboard.
+    pub fn refund_tip(ctx: Context<RefundTip>, amount: u64) -> Result<()> {
+        instructions::refund_tip::handler(ctx, amount)
     }
 
     // ---- Withdrawal ------------------------------------------------
@@ -186,43 +399,174 @@ pub mod soltip {
     pub fn create_goal(
         ctx: Context<CreateGoal>,
         goal_id: u64,
-        title: String,
-        description: String,
-        target_amount: u64,
-        token_mint: Pubkey,
-        deadline: Option<i64>,
+        params: CreateGoalParams,
     ) -> Result<()> {
-        instructions::create_goal::handler(ctx, goal_id, title, description, target_amount, token_mint, deadline)
+        instructions::create_goal::handler(ctx, goal_id, params)
     }
 
     pub fn contribute_goal(ctx: Context<ContributeGoal>, amount: u64, message: Option<String>) -> Result<()> {
         instructions::contribute_goal::handler(ctx, amount, message)
     }
 
+    /// Contribute SPL tokens to a goal created with a matching token_mint.
+    pub fn contribute_goal_spl(ctx: Context<ContributeGoalSpl>, amount: u64, message: Option<String>) -> Result<()> {
+        instructions::contribute_goal_spl::handler(ctx, amount, message)
+    }
+
     pub fn close_goal(ctx: Context<CloseGoal>) -> Result<()> {
         instructions::close_goal::handler(ctx)
     }
 
+    /// Pay out a completed escrowed goal: creator share to the vault, fee to treasury.
+    pub fn release_goal_escrow(ctx: Context<ReleaseGoalEscrow>) -> Result<()> {
+        instructions::release_goal_escrow::handler(ctx)
+    }
+
+    /// Reclaim an escrowed contribution after the goal's deadline passed unmet.
+    pub fn refund_goal_contribution(ctx: Context<RefundGoalContribution>) -> Result<()> {
+        instructions::refund_goal_contribution::handler(ctx)
+    }
+
+    // ---- Sponsor Matching ------------------------------------------
+
+    /// Escrow `total_cap` lamports to match tips to a profile (or, with a
+    /// goal, contributions to that goal) at `match_ratio_bps` until expiry.
+    pub fn create_matching_pool(
+        ctx: Context<CreateMatchingPool>,
+        pool_id: u64,
+        match_ratio_bps: u16,
+        per_tipper_cap: u64,
+        total_cap: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_matching_pool::handler(ctx, pool_id, match_ratio_bps, per_tipper_cap, total_cap, expires_at)
+    }
+
+    /// Return an expired pool's unmatched funds and rent to the sponsor.
+    pub fn close_matching_pool(ctx: Context<CloseMatchingPool>) -> Result<()> {
+        instructions::close_matching_pool::handler(ctx)
+    }
+
+    /// Return a failed escrowed goal's match to the sponsor's pool.
+    pub fn reclaim_goal_match(ctx: Context<ReclaimGoalMatch>) -> Result<()> {
+        instructions::reclaim_goal_match::handler(ctx)
+    }
+
+    // ---- Bounties --------------------------------------------------
+
+    /// Escrow `amount` lamports against a request to a creator, refundable
+    /// if not fulfilled by `expires_at`.
+    pub fn create_bounty(
+        ctx: Context<CreateBounty>,
+        bounty_id: u64,
+        amount: u64,
+        description: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_bounty::handler(ctx, bounty_id, amount, description, expires_at)
+    }
+
+    /// SPL-token variant of create_bounty; the creator needs an SplVault for the mint.
+    pub fn create_bounty_spl(
+        ctx: Context<CreateBountySpl>,
+        bounty_id: u64,
+        amount: u64,
+        description: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_bounty_spl::handler(ctx, bounty_id, amount, description, expires_at)
+    }
+
+    /// Creator accepts an open bounty.
+    pub fn accept_bounty(ctx: Context<AcceptBounty>) -> Result<()> {
+        instructions::accept_bounty::handler(ctx)
+    }
+
+    /// Creator marks an accepted bounty fulfilled, opening the review window.
+    pub fn fulfill_bounty(ctx: Context<FulfillBounty>) -> Result<()> {
+        instructions::fulfill_bounty::handler(ctx)
+    }
+
+    /// Requester disputes a fulfilled bounty within the review window.
+    pub fn dispute_bounty(ctx: Context<DisputeBounty>) -> Result<()> {
+        instructions::dispute_bounty::handler(ctx)
+    }
+
+    /// Pay a fulfilled bounty to the creator's vault: on the requester's
+    /// approval, or by anyone after the review window.
+    pub fn release_bounty(ctx: Context<ReleaseBounty>) -> Result<()> {
+        instructions::release_bounty::handler(ctx)
+    }
+
+    /// Return a cancelled or expired bounty to the requester.
+    pub fn refund_bounty(ctx: Context<RefundBounty>) -> Result<()> {
+        instructions::refund_bounty::handler(ctx)
+    }
+
+    /// Platform authority settles a disputed bounty either way. Under a
+    /// council, use an AdminAction::ResolveBountyDispute proposal.
+    pub fn resolve_bounty_dispute(ctx: Context<ResolveBountyDispute>, release: bool) -> Result<()> {
+        instructions::resolve_bounty_dispute::handler(ctx, release)
+    }
+
     // ---- Subscriptions ---------------------------------------------
 
-    pub fn create_subscription(
-        ctx: Context<CreateSubscription>,
-        amount_per_interval: u64,
-        interval_seconds: i64,
-        is_spl: bool,
-        token_mint: Pubkey,
+    /// Create a creator-priced subscription tier (owner only)
+    pub fn create_subscription_tier(
+        ctx: Context<CreateSubscriptionTier>,
+        tier_id: u64,
+        terms: SubscriptionTierTerms,
+    ) -> Result<()> {
+        instructions::create_subscription_tier::handler(ctx, tier_id, terms)
+    }
+
+    /// Update a tier's terms; existing members keep the price they joined at
+    pub fn update_subscription_tier(
+        ctx: Context<UpdateSubscriptionTier>,
+        name: Option<String>,
+        price: Option<u64>,
+        interval_seconds: Option<i64>,
+        max_members: Option<u32>,
+        benefits_hash: Option<[u8; 32]>,
     ) -> Result<()> {
-        instructions::create_subscription::handler(ctx, amount_per_interval, interval_seconds, is_spl, token_mint)
+        instructions::update_subscription_tier::handler(ctx, name, price, interval_seconds, max_members, benefits_hash)
+    }
+
+    /// Retire a tier: no new members, existing subscriptions continue
+    pub fn retire_subscription_tier(ctx: Context<RetireSubscriptionTier>) -> Result<()> {
+        instructions::retire_subscription_tier::handler(ctx)
+    }
+
+    /// Subscribe to a creator's tier; price, interval and mint come from the tier
+    pub fn create_subscription(ctx: Context<CreateSubscription>) -> Result<()> {
+        instructions::create_subscription::handler(ctx)
     }
 
     pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
         instructions::cancel_subscription::handler(ctx)
     }
 
+    /// Creator closes a lapsed or ended subscription, returning its
+    /// escrow and rent to the subscriber. An SPL delegate approval stays
+    /// in place; only the subscriber can revoke it.
+    pub fn close_lapsed_subscription(ctx: Context<CloseLapsedSubscription>) -> Result<()> {
+        instructions::close_lapsed_subscription::handler(ctx)
+    }
+
     pub fn process_subscription(ctx: Context<ProcessSubscription>) -> Result<()> {
         instructions::process_subscription::handler(ctx)
     }
 
+    /// Prefund a SOL subscription so keepers can crank due payments.
+    pub fn fund_subscription(ctx: Context<FundSubscription>, amount: u64) -> Result<()> {
+        instructions::fund_subscription::handler(ctx, amount)
+    }
+
+    /// Permissionless: charge a due subscription from its escrow (SOL) or delegate (SPL).
+    pub fn crank_subscription(ctx: Context<CrankSubscription>) -> Result<()> {
+        instructions::crank_subscription::handler(ctx)
+    }
+
     // ---- Platform Admin --------------------------------------------
 
     pub fn initialize_platform(ctx: Context<InitializePlatform>) -> Result<()> {
@@ -303,6 +647,11 @@ pub mod soltip {
         instructions::register_referral::handler(ctx, fee_share_bps)
     }
 
+    /// Referrer claims SOL referral earnings accrued on the Referral PDA.
+    pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+        instructions::claim_referral_earnings::handler(ctx)
+    }
+
     // ---- Admin: Treasury Withdrawal ────────────────────────────────
 
     /// Withdraw accumulated SOL from the platform treasury PDA.
@@ -314,6 +663,15 @@ pub mod soltip {
         instructions::withdraw_treasury::handler(ctx, amount)
     }
 
+    /// Withdraw SPL platform fees from a treasury-owned token account.
+    /// Only the platform authority can call this.
+    pub fn withdraw_treasury_spl(
+        ctx: Context<WithdrawTreasurySpl>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury_spl::handler(ctx, amount)
+    }
+
     // ---- Admin: Reentrancy Guard Reset ─────────────────────────────
 
     /// Reset a stuck reentrancy guard on a tip profile.
@@ -323,4 +681,93 @@ pub mod soltip {
     ) -> Result<()> {
         instructions::reset_reentrancy_guard::handler(ctx)
     }
+
+    // ---- Admin: Platform Config ────────────────────────────────────
+
+    /// Update the platform fee (bps, bounded) and/or treasury address.
+    /// Only the platform authority can call this.
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        platform_fee_bps: Option<u16>,
+        treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_platform_config::handler(ctx, platform_fee_bps, treasury)
+    }
+
+    /// Nominate a new platform authority (step one of two).
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a pending platform authority nomination (step two of two).
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>,
+    ) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Enable or disable the FEATURE_* bits in `features` at runtime.
+    /// Only the platform authority can call this.
+    pub fn set_feature_flags(
+        ctx: Context<SetFeatureFlags>,
+        features: u32,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_feature_flags::handler(ctx, features, enabled)
+    }
+
+    // ---- Admin: Council Governance ─────────────────────────────────
+
+    /// Create the admin council and make it the platform authority.
+    /// Afterwards single-key admin instructions are unusable; admin
+    /// actions go through proposals.
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        instructions::initialize_governance::handler(ctx, approvers, threshold, timelock_seconds)
+    }
+
+    /// Propose an admin action. Council members only; counts as the
+    /// proposer's approval.
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>,
+        action: AdminAction,
+    ) -> Result<()> {
+        instructions::create_admin_proposal::handler(ctx, action)
+    }
+
+    /// Approve a pending admin proposal. Council members only.
+    pub fn approve_admin_proposal(
+        ctx: Context<ApproveAdminProposal>,
+    ) -> Result<()> {
+        instructions::approve_admin_proposal::handler(ctx)
+    }
+
+    /// Execute an approved proposal once its timelock (if any) has passed.
+    pub fn execute_admin_proposal(
+        ctx: Context<ExecuteAdminProposal>,
+    ) -> Result<()> {
+        instructions::execute_admin_proposal::handler(ctx)
+    }
+
+    /// Cancel an unexecuted proposal. Council members only.
+    pub fn cancel_admin_proposal(
+        ctx: Context<CancelAdminProposal>,
+    ) -> Result<()> {
+        instructions::cancel_admin_proposal::handler(ctx)
+    }
+
+    // ---- Maintenance ───────────────────────────────────────────────
+
+    /// Realloc and upgrade a TipProfile, TipperRecord, TipGoal or
+    /// Subscription created under an older layout. Payer covers extra rent.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler(ctx)
+    }
 }
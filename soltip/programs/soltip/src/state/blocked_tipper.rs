@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// A wallet a creator has blocked. One PDA per (profile, wallet) pair,
+/// so the blocklist has no size limit and a lookup is a single address
+/// derivation.
+///
+/// Tips, poll votes, goal contributions and new subscriptions take this
+/// PDA as a required account and fail with `TipperBlocked` if it exists.
+///
+/// **PDA seeds:** `[b"blocked_tipper", profile, wallet]`
+#[account]
+pub struct BlockedTipper {
+    /// TipProfile PDA that owns the block
+    pub profile: Pubkey,
+    /// Blocked wallet
+    pub wallet: Pubkey,
+    /// Unix timestamp the block was created
+    pub blocked_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl BlockedTipper {
+    pub const LEN: usize = BLOCKED_TIPPER_SIZE;
+
+    pub fn initialize(&mut self, profile: Pubkey, wallet: Pubkey, timestamp: i64, bump: u8) {
+        self.profile    = profile;
+        self.wallet     = wallet;
+        self.blocked_at = timestamp;
+        self.bump       = bump;
+    }
+
+    /// Fail if the blocklist PDA at `info` exists. The caller derives the
+    /// address from seeds, so an uninitialized account means "not blocked".
+    pub fn ensure_not_blocked(info: &AccountInfo) -> Result<()> {
+        require!(
+            info.owner != &crate::ID || info.data_is_empty(),
+            ErrorCode::TipperBlocked
+        );
+        Ok(())
+    }
+}
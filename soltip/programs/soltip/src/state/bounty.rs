@@ -0,0 +1,259 @@
+// ==========================================================
+// Bounty – viewer-escrowed request a creator fulfills for pay
+//
+// A viewer ("requester") escrows SOL in the Bounty PDA, or SPL
+// tokens in the PDA's associated token account, against a
+// description and an expiry. Lifecycle:
+//   Open      – creator may accept; requester may cancel
+//   Accepted  – creator may mark fulfilled before expiry;
+//               refundable once expired
+//   Fulfilled – requester approves (or disputes) within the
+//               review window; after it anyone may release
+//   Disputed  – only the platform authority can settle it
+// Release pays the creator's Vault (or SplVault); refund
+// returns the escrow to the requester. Both close the bounty.
+// From acceptance until then the bounty counts toward the
+// profile's active_bounties, so the vault cannot be closed
+// under it.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer as SplTransfer};
+use crate::state::{Vault, SplVault};
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum BountyStatus {
+    #[default]
+    Open,
+    Accepted,
+    Fulfilled,
+    Disputed,
+}
+
+#[account]
+pub struct Bounty {
+    /// Viewer who escrowed the funds and receives any refund
+    pub requester: Pubkey,
+    /// TipProfile PDA of the creator asked
+    pub profile: Pubkey,
+    /// Creator wallet (profile owner)
+    pub creator: Pubkey,
+    /// Requester-chosen id (used in PDA derivation)
+    pub bounty_id: u64,
+    /// system_program::ID for SOL, else the SPL mint
+    pub token_mint: Pubkey,
+    /// Escrow token account (ATA of this PDA); default for SOL
+    pub escrow_token_account: Pubkey,
+    /// Escrowed amount (lamports or token base units)
+    pub amount: u64,
+    pub description: String,
+    pub status: BountyStatus,
+    /// Must be fulfilled before this timestamp
+    pub expires_at: i64,
+    pub accepted_at: i64,
+    pub fulfilled_at: i64,
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Bounty {
+    pub const LEN: usize = BOUNTY_SIZE;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        requester: Pubkey,
+        profile: Pubkey,
+        creator: Pubkey,
+        bounty_id: u64,
+        token_mint: Pubkey,
+        escrow_token_account: Pubkey,
+        amount: u64,
+        description: String,
+        expires_at: i64,
+        timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(amount >= MIN_TIP_AMOUNT, ErrorCode::InvalidBountyAmount);
+        require!(
+            !description.is_empty() && description.len() <= MAX_BOUNTY_DESCRIPTION_LENGTH,
+            ErrorCode::InvalidBountyDescription
+        );
+        require!(validate_text_content(&description), ErrorCode::UnsafeTextContent);
+        require!(expires_at > timestamp, ErrorCode::InvalidBountyExpiry);
+        require!(expires_at - timestamp <= MAX_BOUNTY_DURATION, ErrorCode::InvalidBountyExpiry);
+
+        self.requester            = requester;
+        self.profile              = profile;
+        self.creator              = creator;
+        self.bounty_id            = bounty_id;
+        self.token_mint           = token_mint;
+        self.escrow_token_account = escrow_token_account;
+        self.amount               = amount;
+        self.description          = description;
+        self.status               = BountyStatus::Open;
+        self.expires_at           = expires_at;
+        self.accepted_at          = 0;
+        self.fulfilled_at         = 0;
+        self.created_at           = timestamp;
+        self.bump                 = bump;
+        Ok(())
+    }
+
+    pub fn is_sol(&self) -> bool {
+        self.token_mint == anchor_lang::system_program::ID
+    }
+
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp >= self.expires_at
+    }
+
+    /// End of the requester's approve-or-dispute window
+    pub fn review_ends_at(&self) -> i64 {
+        self.fulfilled_at.saturating_add(BOUNTY_REVIEW_WINDOW_SECONDS)
+    }
+
+    pub fn accept(&mut self, timestamp: i64) -> Result<()> {
+        require!(self.status == BountyStatus::Open, ErrorCode::BountyNotOpen);
+        require!(!self.is_expired(timestamp), ErrorCode::BountyExpired);
+        self.status      = BountyStatus::Accepted;
+        self.accepted_at = timestamp;
+        Ok(())
+    }
+
+    pub fn fulfill(&mut self, timestamp: i64) -> Result<()> {
+        require!(self.status == BountyStatus::Accepted, ErrorCode::BountyNotAccepted);
+        require!(!self.is_expired(timestamp), ErrorCode::BountyExpired);
+        self.status       = BountyStatus::Fulfilled;
+        self.fulfilled_at = timestamp;
+        Ok(())
+    }
+
+    pub fn dispute(&mut self, timestamp: i64) -> Result<()> {
+        require!(self.status == BountyStatus::Fulfilled, ErrorCode::BountyNotFulfilled);
+        require!(timestamp < self.review_ends_at(), ErrorCode::BountyReviewWindowClosed);
+        self.status = BountyStatus::Disputed;
+        Ok(())
+    }
+
+    /// The requester may release a fulfilled bounty at once; anyone else
+    /// once the review window has passed without a dispute.
+    pub fn ensure_releasable(&self, caller: Pubkey, timestamp: i64) -> Result<()> {
+        require!(self.status == BountyStatus::Fulfilled, ErrorCode::BountyNotFulfilled);
+        require!(
+            caller == self.requester || timestamp >= self.review_ends_at(),
+            ErrorCode::BountyReviewWindowOpen
+        );
+        Ok(())
+    }
+
+    /// The requester may cancel an unaccepted bounty at any time; anyone may
+    /// refund one that expired unaccepted or unfulfilled.
+    pub fn ensure_refundable(&self, caller: Pubkey, timestamp: i64) -> Result<()> {
+        let refundable = match self.status {
+            BountyStatus::Open     => caller == self.requester || self.is_expired(timestamp),
+            BountyStatus::Accepted => self.is_expired(timestamp),
+            BountyStatus::Fulfilled | BountyStatus::Disputed => false,
+        };
+        require!(refundable, ErrorCode::BountyNotRefundable);
+        Ok(())
+    }
+}
+
+/// Pay the escrow to the creator: lamports into the Vault, tokens into
+/// the SplVault's token account. The caller closes the bounty.
+pub fn pay_bounty_to_creator<'info>(
+    bounty: &Account<'info, Bounty>,
+    vault: Option<&mut Account<'info, Vault>>,
+    spl_vault: Option<&mut Account<'info, SplVault>>,
+    vault_token_account: Option<&Account<'info, TokenAccount>>,
+    escrow_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    if bounty.is_sol() {
+        let vault = vault.ok_or(ErrorCode::BountyAccountsMismatch)?;
+        // Direct lamport manipulation: the bounty PDA is program-owned and holds data
+        **bounty.to_account_info().try_borrow_mut_lamports()? -= bounty.amount;
+        **vault.to_account_info().try_borrow_mut_lamports()? += bounty.amount;
+        return vault.deposit(bounty.amount);
+    }
+
+    let spl_vault = spl_vault.ok_or(ErrorCode::BountyAccountsMismatch)?;
+    let to        = vault_token_account.ok_or(ErrorCode::BountyAccountsMismatch)?;
+    require_keys_eq!(to.key(), spl_vault.token_account, ErrorCode::InvalidTokenAccount);
+    let moved = drain_escrow(bounty, escrow_token_account, to, token_program, rent_to)?;
+    spl_vault.deposit(moved)
+}
+
+/// Return SPL escrow to the requester's token account. SOL escrow needs
+/// nothing here: closing the bounty returns it with the rent.
+pub fn refund_bounty_escrow<'info>(
+    bounty: &Account<'info, Bounty>,
+    requester_token_account: Option<&Account<'info, TokenAccount>>,
+    escrow_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    if bounty.is_sol() {
+        return Ok(());
+    }
+
+    let to = requester_token_account.ok_or(ErrorCode::BountyAccountsMismatch)?;
+    require_keys_eq!(to.owner, bounty.requester, ErrorCode::TokenAccountOwnerMismatch);
+    drain_escrow(bounty, escrow_token_account, to, token_program, rent_to)?;
+    Ok(())
+}
+
+/// Move everything in the escrow account to `to` and close it; returns
+/// the tokens moved (the escrow can only have grown since creation)
+fn drain_escrow<'info>(
+    bounty: &Account<'info, Bounty>,
+    escrow_token_account: Option<&Account<'info, TokenAccount>>,
+    to: &Account<'info, TokenAccount>,
+    token_program: Option<&Program<'info, Token>>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<u64> {
+    let escrow        = escrow_token_account.ok_or(ErrorCode::BountyAccountsMismatch)?;
+    let token_program = token_program.ok_or(ErrorCode::BountyAccountsMismatch)?;
+    require_keys_eq!(escrow.key(), bounty.escrow_token_account, ErrorCode::InvalidTokenAccount);
+    require_keys_eq!(to.mint, bounty.token_mint, ErrorCode::TokenMintMismatch);
+
+    let amount    = escrow.amount;
+    let id_bytes  = bounty.bounty_id.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        BOUNTY_SEED,
+        bounty.profile.as_ref(),
+        bounty.requester.as_ref(),
+        id_bytes.as_ref(),
+        &[bounty.bump],
+    ];
+    let signer = &[seeds];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SplTransfer {
+                from:      escrow.to_account_info(),
+                to:        to.to_account_info(),
+                authority: bounty.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account:     escrow.to_account_info(),
+            destination: rent_to.clone(),
+            authority:   bounty.to_account_info(),
+        },
+        signer,
+    ))?;
+    Ok(amount)
+}
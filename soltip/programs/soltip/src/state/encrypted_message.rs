@@ -0,0 +1,31 @@
+// ==========================================================
+// EncryptedMessage – tip message sealed to the creator
+// NaCl box (X25519 + XSalsa20-Poly1305): the tipper encrypts to
+// TipProfile.encryption_pubkey with a fresh ephemeral key pair.
+// The program only checks sizes; it cannot read the message.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct EncryptedMessage {
+    /// Tipper's ephemeral X25519 public key
+    pub ephemeral_pubkey: [u8; 32],
+    /// Box nonce
+    pub nonce: [u8; 24],
+    /// Ciphertext including the 16-byte tag
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedMessage {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.ciphertext.len() > ENCRYPTION_TAG_LENGTH
+                && self.ciphertext.len() <= MAX_ENCRYPTED_MESSAGE_LENGTH,
+            ErrorCode::InvalidEncryptedMessage
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// ==========================================================
+// GoalContribution – tracks a (goal, contributor) relationship
+//
+// Created on a contributor's first contribution to a goal.
+// Gives exact per-contributor totals for escrow refunds and
+// lets TipGoal count unique contributors without a scan.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[account]
+pub struct GoalContribution {
+    /// The contributor wallet
+    pub contributor: Pubkey,
+    /// The TipGoal PDA contributed to
+    pub tip_goal: Pubkey,
+    /// Cumulative amount contributed (lamports or token base units)
+    pub amount: u64,
+    /// Number of individual contributions made
+    pub contribution_count: u32,
+    /// Timestamp of first contribution
+    pub first_contributed_at: i64,
+    /// Timestamp of most recent contribution
+    pub last_contributed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GoalContribution {
+    pub const LEN: usize = GOAL_CONTRIBUTION_SIZE;
+
+    pub fn initialize(
+        &mut self,
+        contributor: Pubkey,
+        tip_goal: Pubkey,
+        first_amount: u64,
+        timestamp: i64,
+        bump: u8,
+    ) {
+        self.contributor          = contributor;
+        self.tip_goal             = tip_goal;
+        self.amount               = first_amount;
+        self.contribution_count   = 1;
+        self.first_contributed_at = timestamp;
+        self.last_contributed_at  = timestamp;
+        self.bump                 = bump;
+    }
+
+    pub fn record_contribution(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        self.amount = self.amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.contribution_count = self.contribution_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.last_contributed_at = timestamp;
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// Admin council that owns `PlatformConfig.authority`.
+///
+/// Once `initialize_governance` runs, the platform authority is this PDA,
+/// so single-key admin instructions can no longer be signed. Admin
+/// actions instead go through `AdminProposal`s that need `threshold`
+/// approvals from `approvers`; sensitive actions additionally wait
+/// `timelock_seconds` after reaching the threshold.
+///
+/// **PDA seeds:** `[b"governance"]`
+#[account]
+pub struct Governance {
+    /// Council members allowed to propose and approve
+    pub approvers: Vec<Pubkey>,
+    /// Approvals required to execute a proposal (M of N)
+    pub threshold: u8,
+    /// Delay between reaching the threshold and executing a timelocked action
+    pub timelock_seconds: i64,
+    /// Number of proposals created; next proposal ID
+    pub proposal_count: u64,
+    /// Bumped whenever the council changes; older proposals become stale
+    pub council_nonce: u32,
+    /// Unix timestamp of creation
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const LEN: usize = GOVERNANCE_SIZE;
+
+    /// Replace the council. Invalidates approvals on open proposals.
+    pub fn set_council(&mut self, approvers: Vec<Pubkey>, threshold: u8, timelock_seconds: i64) -> Result<()> {
+        require!(
+            !approvers.is_empty() && approvers.len() <= MAX_COUNCIL_SIZE,
+            ErrorCode::InvalidCouncil
+        );
+        for (i, a) in approvers.iter().enumerate() {
+            require!(*a != Pubkey::default(), ErrorCode::InvalidCouncil);
+            require!(!approvers[..i].contains(a), ErrorCode::InvalidCouncil);
+        }
+        require!(
+            threshold >= 1 && threshold as usize <= approvers.len(),
+            ErrorCode::InvalidThreshold
+        );
+        require!(
+            (0..=MAX_GOVERNANCE_TIMELOCK_SECONDS).contains(&timelock_seconds),
+            ErrorCode::InvalidTimelock
+        );
+        self.approvers        = approvers;
+        self.threshold        = threshold;
+        self.timelock_seconds = timelock_seconds;
+        self.council_nonce    = self.council_nonce.wrapping_add(1);
+        Ok(())
+    }
+
+    /// Position of `key` in the council, or NotCouncilMember.
+    pub fn approver_index(&self, key: &Pubkey) -> Result<usize> {
+        self.approvers
+            .iter()
+            .position(|a| a == key)
+            .ok_or_else(|| error!(ErrorCode::NotCouncilMember))
+    }
+
+    /// Allocate the next proposal ID.
+    pub fn next_proposal_id(&mut self) -> Result<u64> {
+        let id = self.proposal_count;
+        self.proposal_count = self.proposal_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(id)
+    }
+}
+
+/// Admin action carried by a proposal. Mirrors the single-key admin
+/// instructions plus council management.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    /// pause_platform. Pausing skips the timelock; unpausing does not.
+    Pause { paused: bool },
+    /// verify_creator
+    VerifyCreator { profile: Pubkey, verified: bool },
+    /// withdraw_treasury (timelocked)
+    WithdrawTreasury { amount: u64, destination: Pubkey },
+    /// reset_reentrancy_guard
+    ResetReentrancyGuard { profile: Pubkey },
+    /// update_platform_config (timelocked)
+    UpdatePlatformConfig { platform_fee_bps: Option<u16>, treasury: Option<Pubkey> },
+    /// set_feature_flags
+    SetFeatureFlags { features: u32, enabled: bool },
+    /// propose_authority; hands the platform back to a single key (timelocked)
+    TransferAuthority { new_authority: Pubkey },
+    /// Replace the council (timelocked)
+    UpdateCouncil { approvers: Vec<Pubkey>, threshold: u8, timelock_seconds: i64 },
+    /// withdraw_treasury_spl; `destination` is the receiving token account (timelocked)
+    WithdrawTreasurySpl { amount: u64, destination: Pubkey },
+    /// resolve_bounty_dispute; pays the creator or refunds the requester
+    ResolveBountyDispute { bounty: Pubkey, award_to_creator: bool },
+}
+
+impl AdminAction {
+    /// Whether the action waits out the governance timelock after approval.
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            AdminAction::Pause { paused: false }
+                | AdminAction::WithdrawTreasury { .. }
+                | AdminAction::WithdrawTreasurySpl { .. }
+                | AdminAction::UpdatePlatformConfig { .. }
+                | AdminAction::TransferAuthority { .. }
+                | AdminAction::UpdateCouncil { .. }
+        )
+    }
+}
+
+/// Lifecycle of an admin proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ProposalStatus {
+    #[default]
+    Pending,
+    Approved,
+    Executed,
+    Cancelled,
+}
+
+/// A proposed admin action awaiting council approval.
+///
+/// **PDA seeds:** `[b"admin_proposal", proposal_id.to_le_bytes()]`
+#[account]
+pub struct AdminProposal {
+    /// Sequential proposal ID
+    pub proposal_id: u64,
+    /// Council member who created the proposal
+    pub proposer: Pubkey,
+    /// The action to execute
+    pub action: AdminAction,
+    /// Bitmask of approving council indexes
+    pub approvals: u16,
+    /// Number of approvals collected
+    pub approval_count: u8,
+    /// Governance council_nonce at creation
+    pub council_nonce: u32,
+    pub status: ProposalStatus,
+    /// Unix timestamp of creation
+    pub created_at: i64,
+    /// Unix timestamp the threshold was reached (0 = not yet)
+    pub approved_at: i64,
+    /// Earliest execution time (set once approved)
+    pub executable_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const LEN: usize = ADMIN_PROPOSAL_SIZE;
+
+    pub fn initialize(
+        &mut self,
+        proposal_id: u64,
+        proposer: Pubkey,
+        action: AdminAction,
+        council_nonce: u32,
+        timestamp: i64,
+        bump: u8,
+    ) {
+        self.proposal_id    = proposal_id;
+        self.proposer       = proposer;
+        self.action         = action;
+        self.approvals      = 0;
+        self.approval_count = 0;
+        self.council_nonce  = council_nonce;
+        self.status         = ProposalStatus::Pending;
+        self.created_at     = timestamp;
+        self.approved_at    = 0;
+        self.executable_at  = 0;
+        self.bump           = bump;
+    }
+
+    /// Record an approval from the council member at `index`. Once the
+    /// threshold is reached the proposal is Approved and its execution
+    /// time is fixed.
+    pub fn approve(&mut self, governance: &Governance, index: usize, timestamp: i64) -> Result<()> {
+        require!(self.council_nonce == governance.council_nonce, ErrorCode::ProposalStale);
+        require!(self.status == ProposalStatus::Pending, ErrorCode::ProposalNotPending);
+        let bit = 1u16 << index;
+        require!(self.approvals & bit == 0, ErrorCode::AlreadyApproved);
+
+        self.approvals |= bit;
+        self.approval_count = self.approval_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        if self.approval_count >= governance.threshold {
+            self.status      = ProposalStatus::Approved;
+            self.approved_at = timestamp;
+            let delay = if self.action.is_timelocked() { governance.timelock_seconds } else { 0 };
+            self.executable_at = timestamp
+                .checked_add(delay)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Check the proposal can run now and mark it executed.
+    pub fn mark_executed(&mut self, governance: &Governance, timestamp: i64) -> Result<()> {
+        require!(self.council_nonce == governance.council_nonce, ErrorCode::ProposalStale);
+        require!(self.status == ProposalStatus::Approved, ErrorCode::ProposalNotApproved);
+        require!(timestamp >= self.executable_at, ErrorCode::TimelockNotElapsed);
+        self.status = ProposalStatus::Executed;
+        Ok(())
+    }
+
+    pub fn cancel(&mut self) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Pending || self.status == ProposalStatus::Approved,
+            ErrorCode::ProposalNotPending
+        );
+        self.status = ProposalStatus::Cancelled;
+        Ok(())
+    }
+}
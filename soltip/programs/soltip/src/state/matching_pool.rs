@@ -0,0 +1,185 @@
+// ==========================================================
+// MatchingPool – sponsor-funded matching for tips or a goal
+//
+// A sponsor escrows `total_cap` lamports in the pool PDA and
+// matches each tip (or contribution to one goal) at
+// `match_ratio_bps`, up to `per_tipper_cap` per wallet, until
+// the pool runs dry or expires. Whatever is left goes back to
+// the sponsor when the pool is closed after expiry.
+//
+// MatchRecord tracks how much one wallet has had matched.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[account]
+pub struct MatchingPool {
+    /// Wallet that funded the pool and receives the remainder
+    pub sponsor: Pubkey,
+    /// TipProfile PDA whose tips are matched
+    pub profile: Pubkey,
+    /// TipGoal PDA whose contributions are matched (default = tips)
+    pub goal: Pubkey,
+    /// Sponsor-chosen id (used in PDA derivation)
+    pub pool_id: u64,
+    /// Match per tipped lamport in basis points (10_000 = 1:1)
+    pub match_ratio_bps: u16,
+    /// Most any one wallet can have matched
+    pub per_tipper_cap: u64,
+    /// Lamports escrowed at creation
+    pub total_cap: u64,
+    /// Lamports paid out as matches so far
+    pub matched_amount: u64,
+    /// No matches from this timestamp; closable by the sponsor
+    pub expires_at: i64,
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MatchingPool {
+    pub const LEN: usize = MATCHING_POOL_SIZE;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        sponsor: Pubkey,
+        profile: Pubkey,
+        goal: Pubkey,
+        pool_id: u64,
+        match_ratio_bps: u16,
+        per_tipper_cap: u64,
+        total_cap: u64,
+        expires_at: i64,
+        timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            match_ratio_bps > 0 && match_ratio_bps <= MAX_MATCH_RATIO_BPS,
+            ErrorCode::InvalidMatchRatio
+        );
+        require!(total_cap >= MIN_TIP_AMOUNT, ErrorCode::InvalidMatchCap);
+        require!(per_tipper_cap > 0 && per_tipper_cap <= total_cap, ErrorCode::InvalidMatchCap);
+        require!(expires_at > timestamp, ErrorCode::InvalidMatchExpiry);
+        require!(
+            expires_at - timestamp <= MAX_MATCHING_POOL_DURATION,
+            ErrorCode::InvalidMatchExpiry
+        );
+
+        self.sponsor         = sponsor;
+        self.profile         = profile;
+        self.goal            = goal;
+        self.pool_id         = pool_id;
+        self.match_ratio_bps = match_ratio_bps;
+        self.per_tipper_cap  = per_tipper_cap;
+        self.total_cap       = total_cap;
+        self.matched_amount  = 0;
+        self.expires_at      = expires_at;
+        self.created_at      = timestamp;
+        self.bump            = bump;
+        Ok(())
+    }
+
+    /// Whether the pool matches tips rather than a goal
+    pub fn is_for_tips(&self) -> bool {
+        self.goal == Pubkey::default()
+    }
+
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp >= self.expires_at
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.total_cap.saturating_sub(self.matched_amount)
+    }
+
+    /// Match owed on `amount`, limited by the wallet's remaining cap and
+    /// what is left in the pool. Zero once the pool has expired.
+    pub fn match_for(&self, amount: u64, already_matched: u64, timestamp: i64) -> Result<u64> {
+        if self.is_expired(timestamp) {
+            return Ok(0);
+        }
+        let by_ratio = calculate_fee(amount, self.match_ratio_bps)?;
+        let tipper_left = self.per_tipper_cap.saturating_sub(already_matched);
+        Ok(by_ratio.min(tipper_left).min(self.remaining()))
+    }
+
+    pub fn record_match(&mut self, amount: u64) -> Result<()> {
+        self.matched_amount = self.matched_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Undo matches that came back to the pool from a failed goal
+    pub fn release_match(&mut self, amount: u64) -> Result<()> {
+        self.matched_amount = self.matched_amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct MatchRecord {
+    /// MatchingPool PDA
+    pub pool: Pubkey,
+    /// Matched wallet
+    pub tipper: Pubkey,
+    /// Lamports matched for this wallet so far
+    pub matched_amount: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MatchRecord {
+    pub const LEN: usize = MATCH_RECORD_SIZE;
+
+    pub fn record(&mut self, pool: Pubkey, tipper: Pubkey, amount: u64, bump: u8) -> Result<()> {
+        self.pool   = pool;
+        self.tipper = tipper;
+        self.bump   = bump;
+        self.matched_amount = self.matched_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Key a MatchRecord is derived from; default when no pool is passed
+pub fn matching_pool_key(pool: &Option<Account<MatchingPool>>) -> Pubkey {
+    pool.as_ref().map(|p| p.key()).unwrap_or_default()
+}
+
+/// Pay the match on `amount` from the pool into `to` and record it against
+/// the tipper. Returns the lamports matched: 0 without a pool, or once the
+/// pool has expired or run dry.
+pub fn apply_match<'info>(
+    pool: Option<&mut Account<'info, MatchingPool>>,
+    record: Option<&mut Account<'info, MatchRecord>>,
+    record_bump: Option<u8>,
+    tipper: Pubkey,
+    to: &AccountInfo<'info>,
+    amount: u64,
+    timestamp: i64,
+) -> Result<u64> {
+    let Some(pool) = pool else {
+        return Ok(0);
+    };
+    let record = record.ok_or(ErrorCode::MatchRecordRequired)?;
+    let bump   = record_bump.ok_or(ErrorCode::MatchRecordRequired)?;
+
+    let matched = pool.match_for(amount, record.matched_amount, timestamp)?;
+    record.record(pool.key(), tipper, matched, bump)?;
+    if matched == 0 {
+        return Ok(0);
+    }
+    pool.record_match(matched)?;
+
+    // Direct lamport manipulation: the pool PDA is program-owned and holds data
+    **pool.to_account_info().try_borrow_mut_lamports()? -= matched;
+    **to.try_borrow_mut_lamports()? += matched;
+    Ok(matched)
+}
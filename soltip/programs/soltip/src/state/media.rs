@@ -0,0 +1,42 @@
+// ==========================================================
+// MediaAttachment – optional media shown with a tip
+// Carried in the tip instruction and emitted in the tip event;
+// nothing is stored on-chain. The overlay renders it.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MediaKind {
+    Image,
+    Gif,
+    /// YouTube clip, played from `start_seconds`
+    YoutubeClip,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MediaAttachment {
+    pub kind: MediaKind,
+    /// https URL of the media (max 200 chars)
+    pub url: String,
+    /// Clip start offset; must be 0 for images and GIFs
+    pub start_seconds: u32,
+}
+
+impl MediaAttachment {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.url.len() <= MAX_MEDIA_URL_LENGTH, ErrorCode::MediaUrlTooLong);
+        require!(
+            self.url.len() > MEDIA_URL_SCHEME.len() && self.url.starts_with(MEDIA_URL_SCHEME),
+            ErrorCode::InvalidMediaUrl
+        );
+        require!(validate_text_content(&self.url), ErrorCode::UnsafeTextContent);
+        require!(
+            self.start_seconds == 0 || self.kind == MediaKind::YoutubeClip,
+            ErrorCode::InvalidMediaStartTime
+        );
+        Ok(())
+    }
+}
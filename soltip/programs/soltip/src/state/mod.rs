@@ -2,20 +2,44 @@ pub mod tip_profile;
 pub mod tip_goal;
 pub mod subscription;
 pub mod vault;
+pub mod spl_vault;
 pub mod tipper_record;
 pub mod tip_split;
 pub mod rate_limit;
 pub mod tip_poll;
 pub mod referral;
 pub mod content_gate;
+pub mod goal_contribution;
+pub mod subscription_tier;
+pub mod governance;
+pub mod username_registry;
+pub mod blocked_tipper;
+pub mod media;
+pub mod encrypted_message;
+pub mod matching_pool;
+pub mod bounty;
+pub mod pinned_slot;
+pub mod versioned;
 
 pub use tip_profile::*;
 pub use tip_goal::*;
 pub use subscription::*;
 pub use vault::*;
+pub use spl_vault::*;
 pub use tipper_record::*;
 pub use tip_split::*;
 pub use rate_limit::*;
 pub use tip_poll::*;
 pub use referral::*;
 pub use content_gate::*;
+pub use goal_contribution::*;
+pub use subscription_tier::*;
+pub use governance::*;
+pub use username_registry::*;
+pub use blocked_tipper::*;
+pub use media::*;
+pub use encrypted_message::*;
+pub use matching_pool::*;
+pub use bounty::*;
+pub use pinned_slot::*;
+pub use versioned::*;
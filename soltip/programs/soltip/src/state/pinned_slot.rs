@@ -0,0 +1,102 @@
+// ==========================================================
+// PinnedSlot – per-profile "super chat" pin auction
+//
+// The creator sets how long a pin lasts, the opening bid and
+// the minimum raise. A send_tip that passes the slot bids its
+// amount: while a pin is live the tip must beat the current
+// bid by `min_increment_bps`, once it has lapsed any tip of at
+// least `min_bid` takes it. Every bid is an ordinary tip to the
+// vault; an outbid tipper is not refunded.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[account]
+pub struct PinnedSlot {
+    /// TipProfile PDA this slot belongs to
+    pub profile: Pubkey,
+    /// How long a winning bid stays pinned
+    pub duration_seconds: i64,
+    /// Smallest tip that takes a free slot
+    pub min_bid: u64,
+    /// Raise over a live pin's bid, in basis points
+    pub min_increment_bps: u16,
+    /// Current (or last) pin; default when never pinned
+    pub tipper: Pubkey,
+    pub bid: u64,
+    pub message: String,
+    pub pinned_at: i64,
+    /// Pin is live until this timestamp
+    pub expires_at: i64,
+    /// Lifetime pins taken
+    pub total_pins: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PinnedSlot {
+    pub const LEN: usize = PINNED_SLOT_SIZE;
+
+    /// Set the auction rules; a live pin keeps its bid and expiry
+    pub fn configure(
+        &mut self,
+        profile: Pubkey,
+        duration_seconds: i64,
+        min_bid: u64,
+        min_increment_bps: u16,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            (MIN_PIN_DURATION..=MAX_PIN_DURATION).contains(&duration_seconds),
+            ErrorCode::InvalidPinDuration
+        );
+        require!(min_bid >= MIN_TIP_AMOUNT, ErrorCode::InvalidPinBid);
+        require!(min_increment_bps <= MAX_PIN_INCREMENT_BPS, ErrorCode::InvalidPinIncrement);
+
+        self.profile           = profile;
+        self.duration_seconds  = duration_seconds;
+        self.min_bid           = min_bid;
+        self.min_increment_bps = min_increment_bps;
+        self.bump              = bump;
+        Ok(())
+    }
+
+    pub fn is_live(&self, timestamp: i64) -> bool {
+        timestamp < self.expires_at
+    }
+
+    /// Smallest tip that takes the slot now
+    pub fn min_next_bid(&self, timestamp: i64) -> Result<u64> {
+        if !self.is_live(timestamp) {
+            return Ok(self.min_bid);
+        }
+        let raise = calculate_fee(self.bid, self.min_increment_bps)?.max(1);
+        let next  = self.bid.checked_add(raise).ok_or(ErrorCode::MathOverflow)?;
+        Ok(next)
+    }
+
+    /// Take the slot with a tip of `amount`. Returns the live pin that was
+    /// outbid, if any, as (tipper, bid).
+    pub fn pin(
+        &mut self,
+        tipper: Pubkey,
+        amount: u64,
+        message: String,
+        timestamp: i64,
+    ) -> Result<Option<(Pubkey, u64)>> {
+        require!(amount >= self.min_next_bid(timestamp)?, ErrorCode::PinBidTooLow);
+
+        let outbid = self.is_live(timestamp).then_some((self.tipper, self.bid));
+        self.tipper     = tipper;
+        self.bid        = amount;
+        self.message    = message;
+        self.pinned_at  = timestamp;
+        self.expires_at = timestamp
+            .checked_add(self.duration_seconds)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_pins = self.total_pins.saturating_add(1);
+        Ok(outbid)
+    }
+}
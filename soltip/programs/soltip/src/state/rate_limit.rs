@@ -4,6 +4,8 @@
 // One PDA per (tipper, recipient) pair.
 // Enforces minimum interval between tips and a daily cap.
 // Automatically resets the daily window every 24 hours.
+// The cooldown and cap come from the recipient profile's
+// RateLimitSettings, falling back to the platform defaults.
 // ==========================================================
 
 use anchor_lang::prelude::*;
@@ -14,7 +16,9 @@ use crate::error::ErrorCode;
 ///
 /// Created on demand (`init_if_needed`) on the first tip from a given tipper
 /// to a given profile. Enforces both a per-tip cooldown (default 3 s) and a
-/// rolling 24-hour daily cap (default 100 tips/day).
+/// rolling 24-hour daily cap (default 100 tips/day); creators can tighten
+/// both through `RateLimitSettings`. Shared by tips, split tips and poll
+/// votes, so a missing PDA means a wallet's first interaction.
 ///
 /// **PDA seeds:** `[b"rate_limit", tipper.key(), recipient_profile.key()]`
 #[account]
@@ -52,12 +56,9 @@ impl RateLimit {
     }
 
     /// Check and record a new tip attempt.
-    /// Returns Err if cooldown or daily limit is active.
-    pub fn check_and_record(&mut self, timestamp: i64, cooldown: i64) -> Result<()> {
-        if !ENABLE_RATE_LIMITING {
-            return Ok(());
-        }
-
+    /// Returns Err if cooldown or daily limit is active. Callers skip
+    /// this when FEATURE_RATE_LIMITING is disabled in PlatformConfig.
+    pub fn check_and_record(&mut self, timestamp: i64, cooldown: i64, daily_cap: u32) -> Result<()> {
         // Reset daily window if 24 h have passed
         if timestamp.saturating_sub(self.window_start) >= SECONDS_PER_DAY {
             self.tip_count_today = 0;
@@ -70,7 +71,7 @@ impl RateLimit {
 
         // Enforce daily cap
         require!(
-            self.tip_count_today < MAX_TIPS_PER_DAY,
+            self.tip_count_today < daily_cap,
             ErrorCode::DailyLimitExceeded
         );
 
@@ -81,3 +82,70 @@ impl RateLimit {
         Ok(())
     }
 }
+
+/// Creator-configured spam controls, stored on `TipProfile`.
+///
+/// Zero means "not set": the cooldown and daily cap fall back to the
+/// platform defaults and the amount limits are off. Settings can only
+/// tighten the platform defaults. Amounts are lamports, so SPL tips
+/// honour the cooldown and daily cap only.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct RateLimitSettings {
+    /// Minimum seconds between tips from one wallet
+    pub tip_cooldown_seconds: u32,
+    /// Maximum tips per wallet per 24 h window
+    pub max_tips_per_day: u32,
+    /// Largest single tip accepted (lamports)
+    pub max_tip_amount: u64,
+    /// Minimum for the first tip from a wallet that has never tipped this profile
+    pub min_first_tip_amount: u64,
+}
+
+impl RateLimitSettings {
+    pub fn validate(&self, min_tip_amount: u64) -> Result<()> {
+        let cooldown = self.tip_cooldown_seconds as i64;
+        require!(
+            cooldown == 0 || (DEFAULT_TIP_COOLDOWN_SECONDS..=MAX_TIP_COOLDOWN_SECONDS).contains(&cooldown),
+            ErrorCode::InvalidRateLimitSettings
+        );
+        require!(self.max_tips_per_day <= MAX_TIPS_PER_DAY, ErrorCode::InvalidRateLimitSettings);
+        if self.max_tip_amount > 0 {
+            require!(
+                self.max_tip_amount >= min_tip_amount && self.max_tip_amount <= MAX_TIP_AMOUNT,
+                ErrorCode::InvalidRateLimitSettings
+            );
+            require!(self.min_first_tip_amount <= self.max_tip_amount, ErrorCode::InvalidRateLimitSettings);
+        }
+        Ok(())
+    }
+
+    /// Effective cooldown between tips
+    pub fn cooldown(&self) -> i64 {
+        if self.tip_cooldown_seconds == 0 {
+            DEFAULT_TIP_COOLDOWN_SECONDS
+        } else {
+            self.tip_cooldown_seconds as i64
+        }
+    }
+
+    /// Effective daily cap
+    pub fn daily_cap(&self) -> u32 {
+        if self.max_tips_per_day == 0 {
+            MAX_TIPS_PER_DAY
+        } else {
+            self.max_tips_per_day
+        }
+    }
+
+    /// Check a SOL amount against the per-tip maximum and, for a wallet's
+    /// first tip, the first-tip minimum.
+    pub fn check_amount(&self, amount: u64, is_first_tip: bool) -> Result<()> {
+        if self.max_tip_amount > 0 {
+            require!(amount <= self.max_tip_amount, ErrorCode::TipAboveCreatorMaximum);
+        }
+        if is_first_tip {
+            require!(amount >= self.min_first_tip_amount, ErrorCode::FirstTipTooSmall);
+        }
+        Ok(())
+    }
+}
@@ -5,6 +5,10 @@ use crate::error::ErrorCode;
 /// Tracks a referral relationship: Creator A referred Creator B.
 /// A earns a percentage of B's platform fees for the lifetime of the referral.
 ///
+/// SOL earnings accrue as lamports held by this PDA (`pending_lamports`) and
+/// are paid out with `claim_referral_earnings`. SPL earnings are routed
+/// straight to the referrer's token account when the fee is charged.
+///
 /// **PDA seeds:** `[b"referral", referrer.key(), referee_profile.key()]`
 #[account]
 pub struct Referral {
@@ -16,7 +20,7 @@ pub struct Referral {
     pub fee_share_bps: u16,
     /// Total lamports earned from this referral
     pub total_earned: u64,
-    /// Number of fee payments received
+    /// Number of registrations recorded for this referral
     pub referral_count: u32,
     /// Unix timestamp when referral was created
     pub created_at: i64,
@@ -24,6 +28,10 @@ pub struct Referral {
     pub is_active: bool,
     /// PDA bump
     pub bump: u8,
+    /// Lamports earned but not yet claimed (held by this PDA)
+    pub pending_lamports: u64,
+    /// Total SPL token base units earned (all mints)
+    pub total_earned_spl: u64,
 }
 
 impl Referral {
@@ -43,27 +51,94 @@ impl Referral {
         self.referee_profile = referee_profile;
         self.fee_share_bps = fee_share_bps;
         self.total_earned = 0;
-        self.referral_count = 0;
+        self.referral_count = 1;
         self.created_at = timestamp;
         self.is_active = true;
         self.bump = bump;
+        self.pending_lamports = 0;
+        self.total_earned_spl = 0;
         Ok(())
     }
 
+    /// Referrer's cut of a platform fee (0 once deactivated)
+    pub fn share_of(&self, platform_fee: u64) -> Result<u64> {
+        if !self.is_active {
+            return Ok(0);
+        }
+        calculate_fee(platform_fee, self.fee_share_bps)
+    }
+
     pub fn record_earning(&mut self, amount: u64) -> Result<()> {
         require!(self.is_active, ErrorCode::ReferralNotActive);
         self.total_earned = self.total_earned
             .checked_add(amount)
             .ok_or(ErrorCode::MathOverflow)?;
-        self.referral_count = self.referral_count
-            .checked_add(1)
+        Ok(())
+    }
+
+    /// Record SOL earnings moved into this PDA (claimable)
+    pub fn accrue(&mut self, amount: u64) -> Result<()> {
+        self.record_earning(amount)?;
+        self.pending_lamports = self.pending_lamports
+            .checked_add(amount)
             .ok_or(ErrorCode::MathOverflow)?;
         Ok(())
     }
 
+    /// Record SPL earnings paid directly to the referrer
+    pub fn record_spl_earning(&mut self, amount: u64) -> Result<()> {
+        require!(self.is_active, ErrorCode::ReferralNotActive);
+        self.total_earned_spl = self.total_earned_spl
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Zero out pending earnings and return the amount to pay out
+    pub fn claim(&mut self) -> Result<u64> {
+        let amount = self.pending_lamports;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        self.pending_lamports = 0;
+        Ok(amount)
+    }
+
     pub fn deactivate(&mut self) -> Result<()> {
         require!(self.is_active, ErrorCode::ReferralNotActive);
         self.is_active = false;
         Ok(())
     }
 }
+
+/// Check the optional referral account passed to a fee-charging instruction
+/// against the profile's registered referral (`Pubkey::default()` = none).
+/// A referred profile must always supply its referral so the referrer is paid.
+pub fn check_referral(expected: Pubkey, referral: Option<&Account<Referral>>) -> Result<()> {
+    match referral {
+        Some(r) => require_keys_eq!(r.key(), expected, ErrorCode::ReferralMismatch),
+        None => require!(expected == Pubkey::default(), ErrorCode::ReferralAccountMissing),
+    }
+    Ok(())
+}
+
+/// Referrer's share of `platform_fee` (0 when the profile has no referral)
+pub fn referral_share(referral: Option<&Account<Referral>>, platform_fee: u64) -> Result<u64> {
+    referral.map_or(Ok(0), |r| r.share_of(platform_fee))
+}
+
+/// Validate the referrer's token account for an SPL referral payout of `share`.
+/// Returns the account to pay, or `None` when there is nothing to pay.
+pub fn referrer_token_account<'a, 'info>(
+    referral: Option<&Account<'info, Referral>>,
+    token_account: Option<&'a Account<'info, anchor_spl::token::TokenAccount>>,
+    mint: Pubkey,
+    share: u64,
+) -> Result<Option<&'a Account<'info, anchor_spl::token::TokenAccount>>> {
+    let Some(referral) = referral else { return Ok(None) };
+    if share == 0 {
+        return Ok(None);
+    }
+    let ta = token_account.ok_or(ErrorCode::MissingTokenAccounts)?;
+    require_keys_eq!(ta.owner, referral.referrer, ErrorCode::TokenAccountOwnerMismatch);
+    require_keys_eq!(ta.mint, mint, ErrorCode::TokenMintMismatch);
+    Ok(Some(ta))
+}
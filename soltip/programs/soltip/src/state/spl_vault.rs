@@ -0,0 +1,80 @@
+// ==========================================================
+// SplVault – per-(creator, mint) SPL token escrow account
+// SPL tips flow into the vault's associated token account;
+// the creator withdraws via a PDA-signed transfer.
+// Mirrors the SOL Vault so token tips get the same escrow,
+// balance tracking and fee split.
+// ==========================================================
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+#[account]
+pub struct SplVault {
+    /// The creator who owns this vault
+    pub owner: Pubkey,
+    /// The creator's TipProfile PDA
+    pub profile: Pubkey,
+    /// SPL token mint held by this vault
+    pub token_mint: Pubkey,
+    /// Vault token account (ATA of this PDA for `token_mint`)
+    pub token_account: Pubkey,
+    /// Current token balance held in escrow (base units)
+    pub balance: u64,
+    /// Lifetime total deposited (base units)
+    pub total_deposited: u64,
+    /// Lifetime total withdrawn (base units)
+    pub total_withdrawn: u64,
+    /// Unix timestamp of vault creation
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SplVault {
+    pub const LEN: usize = SPL_VAULT_SIZE;
+
+    pub fn initialize(
+        &mut self,
+        owner: Pubkey,
+        profile: Pubkey,
+        token_mint: Pubkey,
+        token_account: Pubkey,
+        timestamp: i64,
+        bump: u8,
+    ) {
+        self.owner           = owner;
+        self.profile         = profile;
+        self.token_mint      = token_mint;
+        self.token_account   = token_account;
+        self.balance         = 0;
+        self.total_deposited = 0;
+        self.total_withdrawn = 0;
+        self.created_at      = timestamp;
+        self.bump            = bump;
+    }
+
+    /// Credit tokens into the vault (called after a CPI transfer into the vault token account)
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_deposited = self.total_deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Debit tokens from vault tracking (actual transfer done via PDA-signed CPI).
+    /// Token accounts carry their own rent, so no buffer is kept back.
+    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientTokenBalance)?;
+        self.total_withdrawn = self.total_withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
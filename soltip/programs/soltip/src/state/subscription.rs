@@ -3,18 +3,46 @@
 // Additions:
 //  • is_spl + token_mint fields for SPL-token subscriptions
 //  • grace_period_seconds for late-payment tolerance
+//  • escrow_balance: prefunded SOL held by the PDA so keepers
+//    can crank due payments without the subscriber signing
+//  • status / missed_intervals / backcharge_missed: lapse
+//    tracking and the creator's policy for missed intervals
+//  • tier: the creator-defined SubscriptionTier joined
 // ==========================================================
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::ErrorCode;
+use crate::state::Versioned;
+
+/// Membership state of a subscription at a point in time.
+///
+/// * `Active`    – paid up; next payment not yet due.
+/// * `PastDue`   – payment due but still inside the grace period; perks kept.
+/// * `Lapsed`    – grace period elapsed without payment; perks withheld.
+/// * `Cancelled` – subscription ended (cancelled or one-shot completed).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum SubscriptionStatus {
+    #[default]
+    Active,
+    PastDue,
+    Lapsed,
+    Cancelled,
+}
 
 /// Recurring tip subscription from a subscriber to a creator.
 ///
 /// Supports both SOL and SPL-token payment modes. The subscription stores
-/// a `next_payment_due` timestamp; anyone can trigger `process_subscription`
-/// once that timestamp is reached. Auto-renewing subscriptions reschedule
-/// after each successful payment; one-shot subscriptions deactivate.
+/// a `next_payment_due` timestamp; the subscriber can pay with
+/// `process_subscription`, or anyone can trigger `crank_subscription` once
+/// that timestamp is reached (funded from `escrow_balance` for SOL or the
+/// token delegate approval for SPL).
+///
+/// Missed intervals: a payment made within `grace_period_seconds` of the due
+/// date keeps the original schedule. Once the grace period has elapsed the
+/// subscription is lapsed, and the next payment either back-charges every
+/// missed interval (`backcharge_missed`) or charges one interval and restarts
+/// the schedule from the payment time, skipping the missed ones.
 ///
 /// **PDA seeds:** `[b"subscription", subscriber.key(), recipient_profile.key()]`
 #[account]
@@ -33,7 +61,7 @@ pub struct Subscription {
     pub auto_renew: bool,
     /// Cumulative amount paid to date.
     pub total_paid: u64,
-    /// Number of payments successfully processed.
+    /// Number of intervals successfully paid.
     pub payment_count: u32,
     /// Unix timestamp of subscription creation.
     pub created_at: i64,
@@ -47,6 +75,20 @@ pub struct Subscription {
     pub token_mint: Pubkey,
     /// PDA bump seed.
     pub bump: u8,
+    /// Prefunded SOL held by this PDA for keeper-cranked payments (lamports).
+    pub escrow_balance: u64,
+    /// Seconds after `next_payment_due` before the subscription lapses.
+    pub grace_period_seconds: i64,
+    /// If true, missed intervals are charged when payment resumes; otherwise skipped.
+    pub backcharge_missed: bool,
+    /// Last recorded status (see `current_status` for the live value).
+    pub status: SubscriptionStatus,
+    /// Cumulative count of intervals that went unpaid past their due date.
+    pub missed_intervals: u32,
+    /// The SubscriptionTier this subscription was created from.
+    pub tier: Pubkey,
+    /// Layout version (0 = created before versioning).
+    pub version: u8,
 }
 
 impl Subscription {
@@ -56,6 +98,7 @@ impl Subscription {
         &mut self,
         subscriber: Pubkey,
         recipient_profile: Pubkey,
+        tier: Pubkey,
         amount_per_interval: u64,
         interval_seconds: i64,
         is_spl: bool,
@@ -84,37 +127,143 @@ impl Subscription {
         self.is_spl              = is_spl;
         self.token_mint          = token_mint;
         self.bump                = bump;
+        self.escrow_balance      = 0;
+        self.grace_period_seconds = 0;
+        self.backcharge_missed   = false;
+        self.status              = SubscriptionStatus::Active;
+        self.missed_intervals    = 0;
+        self.tier                = tier;
+        self.version             = SUBSCRIPTION_VERSION;
         Ok(())
     }
 
-    pub fn process_payment(&mut self, timestamp: i64) -> Result<()> {
+    /// Fix the missed-payment policy for this subscription (copied from the
+    /// creator's profile at creation). The grace period is clamped below one
+    /// interval so at most one interval can be due while still in grace.
+    pub fn set_payment_policy(&mut self, grace_period_seconds: i64, backcharge_missed: bool) {
+        self.grace_period_seconds = grace_period_seconds
+            .clamp(0, self.interval_seconds.saturating_sub(1));
+        self.backcharge_missed = backcharge_missed;
+    }
+
+    /// Number of whole intervals due at `ts` (0 if not yet due).
+    pub fn due_intervals(&self, ts: i64) -> u32 {
+        if ts < self.next_payment_due || self.interval_seconds <= 0 {
+            return 0;
+        }
+        let overdue = (ts - self.next_payment_due) / self.interval_seconds;
+        u32::try_from(overdue.saturating_add(1)).unwrap_or(u32::MAX)
+    }
+
+    /// Live membership status at `ts`.
+    pub fn current_status(&self, ts: i64) -> SubscriptionStatus {
+        if !self.is_active {
+            SubscriptionStatus::Cancelled
+        } else if ts < self.next_payment_due {
+            SubscriptionStatus::Active
+        } else if ts <= self.next_payment_due.saturating_add(self.grace_period_seconds) {
+            SubscriptionStatus::PastDue
+        } else {
+            SubscriptionStatus::Lapsed
+        }
+    }
+
+    /// Paying member right now (active or inside the grace period).
+    pub fn is_in_good_standing(&self, ts: i64) -> bool {
+        matches!(
+            self.current_status(ts),
+            SubscriptionStatus::Active | SubscriptionStatus::PastDue
+        )
+    }
+
+    /// Intervals the next payment at `ts` will charge, per the missed-interval policy.
+    pub fn intervals_to_charge(&self, ts: i64) -> u32 {
+        let due = self.due_intervals(ts);
+        if due > 1 && self.backcharge_missed { due } else { due.min(1) }
+    }
+
+    /// Amount the next payment at `ts` will charge.
+    pub fn amount_due(&self, ts: i64) -> Result<u64> {
+        self.amount_per_interval
+            .checked_mul(self.intervals_to_charge(ts) as u64)
+            .ok_or(error!(ErrorCode::MathOverflow))
+    }
+
+    /// Apply a payment at `ts`. Returns the number of intervals charged.
+    pub fn process_payment(&mut self, timestamp: i64) -> Result<u32> {
         require!(self.is_active, ErrorCode::SubscriptionNotActive);
         require!(timestamp >= self.next_payment_due, ErrorCode::SubscriptionNotDue);
 
+        let due     = self.due_intervals(timestamp);
+        let charged = self.intervals_to_charge(timestamp);
+        let lapsed  = self.current_status(timestamp) == SubscriptionStatus::Lapsed;
+        let amount  = self.amount_due(timestamp)?;
+
+        self.missed_intervals = self.missed_intervals
+            .checked_add(due - 1)
+            .ok_or(ErrorCode::MathOverflow)?;
         self.total_paid = self.total_paid
-            .checked_add(self.amount_per_interval)
+            .checked_add(amount)
             .ok_or(ErrorCode::MathOverflow)?;
         self.payment_count = self.payment_count
-            .checked_add(1)
+            .checked_add(charged)
             .ok_or(ErrorCode::MathOverflow)?;
         self.last_payment_at = timestamp;
 
         if self.auto_renew {
-            self.next_payment_due = timestamp
-                .checked_add(self.interval_seconds)
-                .ok_or(ErrorCode::MathOverflow)?;
+            self.next_payment_due = if lapsed && !self.backcharge_missed {
+                // Skip missed intervals: restart the schedule from now
+                timestamp.checked_add(self.interval_seconds)
+            } else {
+                // Keep the original schedule anchor
+                (charged as i64)
+                    .checked_mul(self.interval_seconds)
+                    .and_then(|d| self.next_payment_due.checked_add(d))
+            }
+            .ok_or(ErrorCode::MathOverflow)?;
+            self.status = SubscriptionStatus::Active;
         } else {
             self.is_active = false;
+            self.status    = SubscriptionStatus::Cancelled;
         }
-        Ok(())
+        Ok(charged)
     }
 
-    pub fn cancel(&mut self) -> Result<()> {
+    /// Record a payment that could not be collected at `ts`.
+    /// Returns the status now stored on the account.
+    pub fn record_failed_payment(&mut self, ts: i64) -> Result<SubscriptionStatus> {
         require!(self.is_active, ErrorCode::SubscriptionNotActive);
-        self.is_active = false;
+        require!(ts >= self.next_payment_due, ErrorCode::SubscriptionNotDue);
+        self.status = self.current_status(ts);
+        Ok(self.status)
+    }
+
+    /// Credit prefunded SOL (called after a transfer into the subscription PDA)
+    pub fn deposit_escrow(&mut self, amount: u64) -> Result<()> {
+        self.escrow_balance = self.escrow_balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
         Ok(())
     }
 
+    /// Debit prefunded SOL (actual lamport move done by the caller)
+    pub fn withdraw_escrow(&mut self, amount: u64) -> Result<()> {
+        self.escrow_balance = self.escrow_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientSubscriptionEscrow)?;
+        Ok(())
+    }
+
+    /// End the subscription. Returns whether it was still active, i.e.
+    /// still holding its tier slot; a one-shot subscription gave its slot
+    /// up when its final payment ended it.
+    pub fn cancel(&mut self) -> bool {
+        let was_active = self.is_active;
+        self.is_active = false;
+        self.status    = SubscriptionStatus::Cancelled;
+        was_active
+    }
+
     pub fn reactivate(&mut self, timestamp: i64) -> Result<()> {
         require!(!self.is_active, ErrorCode::AccountAlreadyInitialized);
         self.next_payment_due = timestamp
@@ -122,6 +271,7 @@ impl Subscription {
             .ok_or(ErrorCode::MathOverflow)?;
         self.is_active  = true;
         self.auto_renew = true;
+        self.status     = SubscriptionStatus::Active;
         Ok(())
     }
 
@@ -135,3 +285,22 @@ impl Subscription {
         if remaining <= 0 { 0 } else { remaining / SECONDS_PER_DAY }
     }
 }
+
+impl Versioned for Subscription {
+    const VERSION: u8 = SUBSCRIPTION_VERSION;
+    const SPACE: usize = SUBSCRIPTION_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) -> Result<()> {
+        // v0 -> v1: subscriptions from before lapse tracking read status
+        // Active; an inactive one was cancelled.
+        if self.version == 0 && !self.is_active {
+            self.status = SubscriptionStatus::Cancelled;
+        }
+        self.version = SUBSCRIPTION_VERSION;
+        Ok(())
+    }
+}
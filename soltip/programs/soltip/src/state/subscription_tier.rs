@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// Terms a creator sets when opening a tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SubscriptionTierTerms {
+    pub name: String,
+    /// Price per interval (lamports or token base units)
+    pub price: u64,
+    pub interval_seconds: i64,
+    /// Payment mint; `system_program::ID` for SOL
+    pub token_mint: Pubkey,
+    /// Member cap (0 = unlimited)
+    pub max_members: u32,
+    pub benefits_hash: [u8; 32],
+}
+
+/// Creator-defined subscription tier (Patreon-style membership level).
+///
+/// Price, interval and payment mint are set by the creator; subscribers
+/// join a tier through `create_subscription`, which copies the tier's
+/// current price and interval onto the Subscription. Later price changes
+/// apply to new members only. Perks are described off-chain; only the
+/// sha256 of the benefits document is stored.
+///
+/// A retired tier accepts no new members; existing subscriptions keep
+/// running until cancelled.
+///
+/// **PDA seeds:** `[b"subscription_tier", profile.key(), tier_id.to_le_bytes()]`
+#[account]
+pub struct SubscriptionTier {
+    /// The TipProfile PDA that owns this tier
+    pub profile: Pubkey,
+    /// Unique tier ID (chosen by the creator, per profile)
+    pub tier_id: u64,
+    /// Display name
+    pub name: String,
+    /// Price per interval (lamports or token base units)
+    pub price: u64,
+    /// Billing interval in seconds. Minimum: `SECONDS_PER_DAY`
+    pub interval_seconds: i64,
+    /// Payment mint; `system_program::ID` for SOL
+    pub token_mint: Pubkey,
+    /// Member cap (0 = unlimited)
+    pub max_members: u32,
+    /// Current number of open subscriptions on this tier
+    pub member_count: u32,
+    /// SHA-256 hash of the benefits description (served off-chain)
+    pub benefits_hash: [u8; 32],
+    /// Whether the tier accepts new members
+    pub is_active: bool,
+    /// Unix timestamp of creation
+    pub created_at: i64,
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SubscriptionTier {
+    pub const LEN: usize = SUBSCRIPTION_TIER_SIZE;
+
+    pub fn initialize(
+        &mut self,
+        profile: Pubkey,
+        tier_id: u64,
+        terms: SubscriptionTierTerms,
+        timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        self.profile       = profile;
+        self.tier_id       = tier_id;
+        self.member_count  = 0;
+        self.token_mint    = terms.token_mint;
+        self.is_active     = true;
+        self.created_at    = timestamp;
+        self.bump          = bump;
+        self.update(
+            Some(terms.name),
+            Some(terms.price),
+            Some(terms.interval_seconds),
+            Some(terms.max_members),
+            Some(terms.benefits_hash),
+            timestamp,
+        )
+    }
+
+    /// Update tier terms. Existing subscriptions keep the price and interval they joined at.
+    pub fn update(
+        &mut self,
+        name: Option<String>,
+        price: Option<u64>,
+        interval_seconds: Option<i64>,
+        max_members: Option<u32>,
+        benefits_hash: Option<[u8; 32]>,
+        timestamp: i64,
+    ) -> Result<()> {
+        if let Some(name) = name {
+            require!(!name.is_empty() && name.len() <= MAX_TIER_NAME_LENGTH, ErrorCode::TierNameTooLong);
+            require!(validate_text_content(&name), ErrorCode::UnsafeTextContent);
+            self.name = name;
+        }
+        if let Some(price) = price {
+            require!(price > 0, ErrorCode::InvalidSubscriptionAmount);
+            self.price = price;
+        }
+        if let Some(interval) = interval_seconds {
+            require!(interval >= SECONDS_PER_DAY, ErrorCode::InvalidSubscriptionInterval);
+            self.interval_seconds = interval;
+        }
+        if let Some(max) = max_members {
+            self.max_members = max;
+        }
+        if let Some(hash) = benefits_hash {
+            self.benefits_hash = hash;
+        }
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    pub fn is_sol(&self) -> bool {
+        self.token_mint == anchor_lang::system_program::ID
+    }
+
+    /// Admit a new member
+    pub fn add_member(&mut self) -> Result<()> {
+        require!(self.is_active, ErrorCode::SubscriptionTierNotActive);
+        require!(
+            self.max_members == 0 || self.member_count < self.max_members,
+            ErrorCode::SubscriptionTierFull
+        );
+        self.member_count = self.member_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_member(&mut self) -> Result<()> {
+        self.member_count = self.member_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        Ok(())
+    }
+
+    pub fn retire(&mut self, timestamp: i64) -> Result<()> {
+        require!(self.is_active, ErrorCode::SubscriptionTierNotActive);
+        self.is_active  = false;
+        self.updated_at = timestamp;
+        Ok(())
+    }
+}
+
+/// Give up the tier slot held by a subscription that has just ended.
+/// Subscriptions opened before tiers existed have no tier and hold no slot.
+pub fn release_tier_slot(tier_key: Pubkey, tier: Option<&mut Account<SubscriptionTier>>) -> Result<()> {
+    if tier_key == Pubkey::default() {
+        return Ok(());
+    }
+    tier.ok_or(ErrorCode::SubscriptionTierMismatch)?.remove_member()
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::ErrorCode;
+use crate::state::Versioned;
 
 /// Represents a fundraising goal for a creator
 /// Goals can be for specific purposes and have optional deadlines
@@ -44,6 +45,20 @@ pub struct TipGoal {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// All-or-nothing mode: contributions are held in this PDA until the
+    /// goal completes, and are refundable if the deadline passes unmet
+    pub escrowed: bool,
+
+    /// Lamports currently held in escrow by this PDA (escrowed mode only)
+    pub escrow_balance: u64,
+
+    /// Layout version (0 = created before versioning)
+    pub version: u8,
+
+    /// Sponsor matches held in escrow (part of `escrow_balance`); released
+    /// fee-free on completion, reclaimed by their pools if the goal fails
+    pub match_escrow: u64,
 }
 
 impl TipGoal {
@@ -60,6 +75,7 @@ impl TipGoal {
         target_amount: u64,
         token_mint: Pubkey,
         deadline: Option<i64>,
+        escrowed: bool,
         timestamp: i64,
         bump: u8,
     ) -> Result<()> {
@@ -94,6 +110,12 @@ impl TipGoal {
             );
         }
 
+        // Escrowed goals must be able to expire, otherwise refunds never unlock
+        if escrowed {
+            require!(deadline.is_some(), ErrorCode::EscrowedGoalRequiresDeadline);
+            require!(token_mint == anchor_lang::system_program::ID, ErrorCode::EscrowedGoalRequiresSol);
+        }
+
         self.profile = profile;
         self.goal_id = goal_id;
         self.title = title;
@@ -107,6 +129,10 @@ impl TipGoal {
         self.unique_contributors = 0;
         self.created_at = timestamp;
         self.bump = bump;
+        self.escrowed = escrowed;
+        self.escrow_balance = 0;
+        self.version = TIP_GOAL_VERSION;
+        self.match_escrow = 0;
 
         Ok(())
     }
@@ -180,6 +206,54 @@ impl TipGoal {
         }
     }
 
+    /// Credit escrowed funds (called after a transfer into the goal PDA)
+    pub fn deposit_escrow(&mut self, amount: u64) -> Result<()> {
+        require!(self.escrowed, ErrorCode::GoalNotEscrowed);
+        self.escrow_balance = self.escrow_balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Debit escrowed funds (release to creator or refund to contributor)
+    pub fn withdraw_escrow(&mut self, amount: u64) -> Result<()> {
+        self.escrow_balance = self.escrow_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+        Ok(())
+    }
+
+    /// Credit a sponsor match held in escrow
+    pub fn deposit_match_escrow(&mut self, amount: u64) -> Result<()> {
+        self.deposit_escrow(amount)?;
+        self.match_escrow = self.match_escrow
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Debit a sponsor match (returned to its pool when the goal fails)
+    pub fn withdraw_match_escrow(&mut self, amount: u64) -> Result<()> {
+        self.match_escrow = self.match_escrow
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+        self.withdraw_escrow(amount)
+    }
+
+    /// Validate that escrowed contributions can be refunded:
+    /// the deadline must have passed without the goal completing
+    pub fn validate_can_refund(&self, timestamp: i64) -> Result<()> {
+        require!(self.escrowed, ErrorCode::GoalNotEscrowed);
+        require!(!self.completed, ErrorCode::GoalAlreadyCompleted);
+        require!(self.is_expired(timestamp), ErrorCode::GoalNotExpired);
+        Ok(())
+    }
+
+    /// SOL goals use the System Program id as their token_mint
+    pub fn is_sol(&self) -> bool {
+        self.token_mint == anchor_lang::system_program::ID
+    }
+
     /// Validate that the goal can accept contributions
     pub fn validate_can_contribute(&self, timestamp: i64) -> Result<()> {
         require!(!self.completed, ErrorCode::GoalAlreadyCompleted);
@@ -191,3 +265,20 @@ impl TipGoal {
         Ok(())
     }
 }
+
+impl Versioned for TipGoal {
+    const VERSION: u8 = TIP_GOAL_VERSION;
+    const SPACE: usize = TIP_GOAL_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) -> Result<()> {
+        // v0 -> v1: goals from before escrow read as non-escrowed, which
+        // is how they behaved.
+        // v1 -> v2: zero match_escrow; escrowed goals could not be matched.
+        self.version = TIP_GOAL_VERSION;
+        Ok(())
+    }
+}
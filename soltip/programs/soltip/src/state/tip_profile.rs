@@ -6,12 +6,23 @@
 //  • webhook_url – webhook endpoint for tip notifications
 //  • active_polls_count – track active polls per profile
 //  • active_gates_count – track active content gates per profile
+//  • subscription_grace_seconds / subscription_backcharge –
+//    creator policy for late and missed subscription payments
+//  • rate_limit_settings – creator cooldown, daily cap and tip
+//    amount limits
+//  • min_media_tip_amount – smallest tip that may carry media
+//  • encryption_pubkey – X25519 key tippers seal private messages to
+//  • refund_window_seconds – how long after a tip the creator may
+//    refund it from the vault
+//  • active_bounties – accepted bounties not yet released or
+//    refunded; their escrow pays into the vault
 // ==========================================================
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::ErrorCode;
-use crate::state::tipper_record::{LeaderboardEntry, upsert_leaderboard};
+use crate::state::tipper_record::{LeaderboardEntry, upsert_leaderboard, deduct_leaderboard};
+use crate::state::{RateLimitSettings, MediaAttachment, EncryptedMessage, Versioned};
 
 #[account]
 pub struct TipProfile {
@@ -60,6 +71,37 @@ pub struct TipProfile {
 
     // ---- v3: Active content gates count ----
     pub active_gates_count: u8,
+
+    // ---- Subscription payment policy (copied onto new subscriptions) ----
+    pub subscription_grace_seconds: i64,
+    pub subscription_backcharge: bool,
+
+    // ---- Subscription tiers ----
+    pub active_tiers_count: u8,
+
+    // ---- Referral PDA of the creator who referred this profile (default = none) ----
+    pub referral: Pubkey,
+
+    // ---- Open accounts that must be closed before close_profile ----
+    pub active_subscriptions: u32,
+
+    // ---- Layout version (0 = created before versioning) ----
+    pub version: u8,
+
+    // ---- Creator spam controls (zero = platform defaults) ----
+    pub rate_limit_settings: RateLimitSettings,
+
+    // ---- Smallest SOL tip that may attach media (0 = media tips off) ----
+    pub min_media_tip_amount: u64,
+
+    // ---- X25519 key for sealed tip messages (zeroed = not published) ----
+    pub encryption_pubkey: [u8; 32],
+
+    // ---- Seconds after a tip during which it can be refunded (0 = off) ----
+    pub refund_window_seconds: i64,
+
+    // ---- Accepted, fulfilled or disputed bounties (block close_profile) ----
+    pub active_bounties: u32,
 }
 
 impl TipProfile {
@@ -106,6 +148,17 @@ impl TipProfile {
         self.webhook_url                    = String::new();
         self.active_polls_count             = 0;
         self.active_gates_count             = 0;
+        self.subscription_grace_seconds     = DEFAULT_SUBSCRIPTION_GRACE_SECONDS;
+        self.subscription_backcharge        = false;
+        self.active_tiers_count             = 0;
+        self.referral                       = Pubkey::default();
+        self.active_subscriptions           = 0;
+        self.version                        = TIP_PROFILE_VERSION;
+        self.rate_limit_settings            = RateLimitSettings::default();
+        self.min_media_tip_amount           = 0;
+        self.encryption_pubkey              = [0u8; 32];
+        self.refund_window_seconds          = DEFAULT_REFUND_WINDOW_SECONDS;
+        self.active_bounties                = 0;
         Ok(())
     }
 
@@ -117,6 +170,7 @@ impl TipProfile {
         min_tip_amount: Option<u64>,
         withdrawal_fee_bps: Option<u16>,
         accept_anonymous: Option<bool>,
+        platform_fee_bps: u16,
         timestamp: i64,
     ) -> Result<()> {
         if let Some(v) = display_name {
@@ -136,7 +190,7 @@ impl TipProfile {
             self.min_tip_amount = v;
         }
         if let Some(v) = withdrawal_fee_bps {
-            require!(v >= PLATFORM_FEE_BPS, ErrorCode::InvalidWithdrawalFee);
+            require!(v >= platform_fee_bps, ErrorCode::InvalidWithdrawalFee);
             require!(v <= MAX_WITHDRAWAL_FEE_BPS, ErrorCode::InvalidWithdrawalFee);
             self.withdrawal_fee_bps = v;
         }
@@ -171,6 +225,60 @@ impl TipProfile {
         Ok(())
     }
 
+    pub fn increment_tiers(&mut self) -> Result<()> {
+        require!(self.active_tiers_count < MAX_ACTIVE_TIERS, ErrorCode::MaxActiveTiersReached);
+        self.active_tiers_count = self.active_tiers_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn decrement_tiers(&mut self) -> Result<()> {
+        self.active_tiers_count = self.active_tiers_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        Ok(())
+    }
+
+    /// Update creator spam controls
+    pub fn set_rate_limit_settings(&mut self, settings: RateLimitSettings) -> Result<()> {
+        settings.validate(self.min_tip_amount)?;
+        self.rate_limit_settings = settings;
+        Ok(())
+    }
+
+    /// Set the media tip minimum; 0 turns media tips off
+    pub fn set_min_media_tip_amount(&mut self, amount: u64) -> Result<()> {
+        require!(amount == 0 || amount >= self.min_tip_amount, ErrorCode::InvalidMinTipAmount);
+        require!(amount <= MAX_TIP_AMOUNT, ErrorCode::InvalidMinTipAmount);
+        self.min_media_tip_amount = amount;
+        Ok(())
+    }
+
+    /// Set the refund window; 0 turns refunds off
+    pub fn set_refund_window(&mut self, seconds: i64) -> Result<()> {
+        require!((0..=MAX_REFUND_WINDOW_SECONDS).contains(&seconds), ErrorCode::InvalidRefundWindow);
+        self.refund_window_seconds = seconds;
+        Ok(())
+    }
+
+    /// Whether tippers can send sealed messages
+    pub fn has_encryption_key(&self) -> bool {
+        self.encryption_pubkey != [0u8; 32]
+    }
+
+    /// Update subscription late-payment policy
+    pub fn set_subscription_policy(&mut self, grace_seconds: Option<i64>, backcharge: Option<bool>) -> Result<()> {
+        if let Some(v) = grace_seconds {
+            require!((0..=MAX_SUBSCRIPTION_GRACE_SECONDS).contains(&v), ErrorCode::InvalidGracePeriod);
+            self.subscription_grace_seconds = v;
+        }
+        if let Some(v) = backcharge {
+            self.subscription_backcharge = v;
+        }
+        Ok(())
+    }
+
     /// Record an incoming SOL tip and update leaderboard.
     pub fn record_tip(
         &mut self,
@@ -193,6 +301,58 @@ impl TipProfile {
         Ok(())
     }
 
+    /// Reverse a refunded SOL tip in the stats and leaderboard.
+    /// `tipper_gone` is set when the tipper has no tips left.
+    pub fn record_refund(
+        &mut self,
+        tipper: Pubkey,
+        amount: u64,
+        tipper_gone: bool,
+    ) -> Result<()> {
+        self.total_tips_received = self.total_tips_received.saturating_sub(1);
+        self.total_amount_received_lamports = self.total_amount_received_lamports
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        if tipper_gone {
+            self.total_unique_tippers = self.total_unique_tippers.saturating_sub(1);
+        }
+        deduct_leaderboard(&mut self.top_tippers, tipper, amount);
+        Ok(())
+    }
+
+    /// Reverse a refunded goal contribution, which record_tip counted as
+    /// `contributions` tips without adding a unique tipper.
+    pub fn record_goal_refund(
+        &mut self,
+        contributor: Pubkey,
+        amount: u64,
+        contributions: u32,
+    ) -> Result<()> {
+        self.total_tips_received = self.total_tips_received.saturating_sub(contributions as u64);
+        self.total_amount_received_lamports = self.total_amount_received_lamports
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        deduct_leaderboard(&mut self.top_tippers, contributor, amount);
+        Ok(())
+    }
+
+    /// Count a sponsor match toward SOL received. Not a tip of its own, so
+    /// tip counts and the leaderboard are untouched.
+    pub fn record_matched(&mut self, amount: u64) -> Result<()> {
+        self.total_amount_received_lamports = self.total_amount_received_lamports
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Take a reclaimed sponsor match back out of SOL received
+    pub fn record_match_reclaim(&mut self, amount: u64) -> Result<()> {
+        self.total_amount_received_lamports = self.total_amount_received_lamports
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        Ok(())
+    }
+
     /// Record an incoming SPL tip
     pub fn record_spl_tip(&mut self, amount: u64) -> Result<()> {
         self.total_tips_received = self.total_tips_received
@@ -249,12 +409,72 @@ impl TipProfile {
         Ok(())
     }
 
+    pub fn add_subscriber(&mut self) -> Result<()> {
+        self.active_subscriptions = self.active_subscriptions
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Saturating: subscriptions opened before the counter existed were never counted.
+    pub fn remove_subscriber(&mut self) {
+        self.active_subscriptions = self.active_subscriptions.saturating_sub(1);
+    }
+
+    pub fn add_bounty(&mut self) -> Result<()> {
+        self.active_bounties = self.active_bounties
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Saturating: bounties accepted before the counter existed were never counted.
+    pub fn remove_bounty(&mut self) {
+        self.active_bounties = self.active_bounties.saturating_sub(1);
+    }
+
+    /// A profile can only be closed once nothing else depends on it.
+    pub fn ensure_closable(&self) -> Result<()> {
+        require!(self.active_goals_count == 0, ErrorCode::ProfileHasActiveGoals);
+        require!(self.active_polls_count == 0, ErrorCode::ProfileHasActivePolls);
+        require!(self.active_gates_count == 0, ErrorCode::ProfileHasActiveGates);
+        require!(self.active_subscriptions == 0, ErrorCode::ProfileHasActiveSubscriptions);
+        require!(self.active_bounties == 0, ErrorCode::ProfileHasActiveBounties);
+        require!(!self.reentrancy_guard, ErrorCode::ReentrancyDetected);
+        Ok(())
+    }
+
     pub fn validate_tip_amount(&self, amount: u64) -> Result<()> {
         require!(amount >= self.min_tip_amount, ErrorCode::TipAmountTooSmall);
         require!(amount <= MAX_TIP_AMOUNT, ErrorCode::TipAmountTooLarge);
         Ok(())
     }
 
+    /// Check an optional media attachment against the creator's media
+    /// minimum. The minimum is in lamports, so SPL tips (`lamports` of
+    /// `None`) only need media tips to be switched on.
+    pub fn validate_media(&self, lamports: Option<u64>, media: Option<&MediaAttachment>) -> Result<()> {
+        if let Some(media) = media {
+            require!(self.min_media_tip_amount > 0, ErrorCode::MediaTipsDisabled);
+            if let Some(amount) = lamports {
+                require!(amount >= self.min_media_tip_amount, ErrorCode::TipBelowMediaMinimum);
+            }
+            media.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Check an optional sealed message. It replaces the plaintext message
+    /// and needs a published encryption key.
+    pub fn validate_encrypted_message(&self, encrypted: Option<&EncryptedMessage>, has_plaintext: bool) -> Result<()> {
+        if let Some(encrypted) = encrypted {
+            require!(!has_plaintext, ErrorCode::ConflictingTipMessages);
+            require!(self.has_encryption_key(), ErrorCode::EncryptionKeyNotSet);
+            encrypted.validate()?;
+        }
+        Ok(())
+    }
+
     // ------------------------------------------------------------------
     // Reentrancy guard helpers
     // ------------------------------------------------------------------
@@ -269,3 +489,27 @@ impl TipProfile {
         self.reentrancy_guard = false;
     }
 }
+
+impl Versioned for TipProfile {
+    const VERSION: u8 = TIP_PROFILE_VERSION;
+    const SPACE: usize = TIP_PROFILE_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) -> Result<()> {
+        // v0 -> v1: fields added before versioning default to zero, which is
+        // their "off" value (no tiers, no referral, no grace period).
+        // v1 -> v2: zeroed rate_limit_settings fall back to platform defaults.
+        // v2 -> v3: zero min_media_tip_amount leaves media tips off.
+        // v3 -> v4: zeroed encryption_pubkey means no key published.
+        // v4 -> v5: existing profiles get the default refund window.
+        // v5 -> v6: active_bounties was reserved (zeroed) space.
+        if self.version < 5 {
+            self.refund_window_seconds = DEFAULT_REFUND_WINDOW_SECONDS;
+        }
+        self.version = TIP_PROFILE_VERSION;
+        Ok(())
+    }
+}
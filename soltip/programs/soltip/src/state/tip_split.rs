@@ -4,6 +4,10 @@
 // A creator sets up a split once; every incoming tip is
 // atomically distributed among the recipients in proportion.
 // BPS values must sum to exactly 10 000.
+//
+// In accrual mode the tip lands in the creator's vault instead
+// and each share is added to the recipient's claimable balance,
+// which they pull with claim_split_share.
 // ==========================================================
 
 use anchor_lang::prelude::*;
@@ -19,18 +23,31 @@ pub struct SplitRecipient {
     pub share_bps: u16,
 }
 
+/// One recipient's cut of a split tip, as reported in split events
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SplitShare {
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
 #[account]
 pub struct TipSplit {
     /// The tip profile that owns this split config
     pub profile: Pubkey,
     /// Number of active recipients
     pub num_recipients: u8,
-    /// Recipient entries (max MAX_SPLIT_RECIPIENTS)
+    /// Recipient entries (max MAX_SPLIT_RECIPIENTS, or
+    /// MAX_ACCRUED_SPLIT_RECIPIENTS in accrual mode)
     pub recipients: Vec<SplitRecipient>,
     /// Whether this split is currently active
     pub is_active: bool,
     /// PDA bump
     pub bump: u8,
+    /// Accrual mode: tips land in the creator's vault and each recipient
+    /// claims their share with claim_split_share
+    pub accrue: bool,
+    /// Unclaimed lamports per recipient, parallel to `recipients`
+    pub claimable: Vec<u64>,
 }
 
 impl TipSplit {
@@ -40,41 +57,25 @@ impl TipSplit {
         &mut self,
         profile: Pubkey,
         recipients: Vec<SplitRecipient>,
+        accrue: bool,
         bump: u8,
     ) -> Result<()> {
-        require!(
-            recipients.len() >= 2,
-            ErrorCode::TooManySplitRecipients  // reuse: need at least 2
-        );
-        require!(
-            recipients.len() <= MAX_SPLIT_RECIPIENTS,
-            ErrorCode::TooManySplitRecipients
-        );
-
-        // Validate BPS sum
-        let bps_list: Vec<u16> = recipients.iter().map(|r| r.share_bps).collect();
-        require!(validate_split_bps(&bps_list), ErrorCode::InvalidSplitBps);
-
-        // No duplicate wallets
-        let mut seen = std::collections::BTreeSet::new();
-        for r in &recipients {
-            require!(seen.insert(r.wallet), ErrorCode::DuplicateSplitRecipient);
-        }
-
-        self.profile        = profile;
-        self.num_recipients = recipients.len() as u8;
-        self.recipients     = recipients;
-        self.is_active      = true;
-        self.bump           = bump;
-        Ok(())
+        self.profile   = profile;
+        self.is_active = true;
+        self.bump      = bump;
+        self.update(recipients, accrue)
     }
 
+    /// Replace the recipients and mode. Unclaimed balances follow their
+    /// wallet; a recipient with a balance cannot be dropped.
     pub fn update(
         &mut self,
         recipients: Vec<SplitRecipient>,
+        accrue: bool,
     ) -> Result<()> {
+        let max = if accrue { MAX_ACCRUED_SPLIT_RECIPIENTS } else { MAX_SPLIT_RECIPIENTS };
         require!(
-            recipients.len() >= 2 && recipients.len() <= MAX_SPLIT_RECIPIENTS,
+            recipients.len() >= 2 && recipients.len() <= max,
             ErrorCode::TooManySplitRecipients
         );
         let bps_list: Vec<u16> = recipients.iter().map(|r| r.share_bps).collect();
@@ -85,11 +86,48 @@ impl TipSplit {
             require!(seen.insert(r.wallet), ErrorCode::DuplicateSplitRecipient);
         }
 
+        for (i, owed) in self.claimable.iter().enumerate() {
+            if *owed > 0 {
+                require!(seen.contains(&self.recipients[i].wallet), ErrorCode::SplitSharesUnclaimed);
+            }
+        }
+        let claimable = recipients.iter().map(|r| self.claimable_for(&r.wallet)).collect();
+
         self.num_recipients = recipients.len() as u8;
         self.recipients     = recipients;
+        self.claimable      = claimable;
+        self.accrue         = accrue;
         Ok(())
     }
 
+    pub fn claimable_for(&self, wallet: &Pubkey) -> u64 {
+        self.recipients.iter()
+            .position(|r| r.wallet == *wallet)
+            .and_then(|i| self.claimable.get(i).copied())
+            .unwrap_or(0)
+    }
+
+    /// Credit each recipient's share of `total` to their claimable balance
+    pub fn accrue_shares(&mut self, total: u64) -> Result<Vec<(Pubkey, u64)>> {
+        let shares = self.calculate_shares(total)?;
+        self.claimable.resize(self.recipients.len(), 0);
+        for (owed, (_wallet, share)) in self.claimable.iter_mut().zip(shares.iter()) {
+            *owed = owed.checked_add(*share).ok_or(ErrorCode::MathOverflow)?;
+        }
+        Ok(shares)
+    }
+
+    /// Zero `wallet`'s balance and return what it was owed
+    pub fn claim(&mut self, wallet: &Pubkey) -> Result<u64> {
+        let owed = self.claimable_for(wallet);
+        require!(owed > 0, ErrorCode::NoSplitShareToClaim);
+        let i = self.recipients.iter()
+            .position(|r| r.wallet == *wallet)
+            .ok_or(ErrorCode::SplitRecipientMismatch)?;
+        self.claimable[i] = 0;
+        Ok(owed)
+    }
+
     /// Calculate each recipient's lamport share given a total tip amount
     pub fn calculate_shares(&self, total: u64) -> Result<Vec<(Pubkey, u64)>> {
         let mut shares = Vec::with_capacity(self.recipients.len());
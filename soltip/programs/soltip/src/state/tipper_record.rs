@@ -5,10 +5,16 @@
 //  • weekly_amount / weekly_start – time-window leaderboard (weekly)
 //  • monthly_amount / monthly_start – time-window leaderboard (monthly)
 //  • Badge tier computed from total_amount
+//
+// Layout v2:
+//  • refundable_amount / refundable_since – SOL vault tips a refund
+//    can still return; carved out of the reserved bytes so the
+//    account size (and existing records) stay valid
 // ==========================================================
 
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::state::Versioned;
 
 #[account]
 pub struct TipperRecord {
@@ -36,6 +42,18 @@ pub struct TipperRecord {
     pub monthly_amount: u64,
     /// Start timestamp of current monthly window
     pub monthly_start: i64,
+
+    /// Layout version (0 = created before versioning)
+    pub version: u8,
+
+    // ---- v2: Refunds ----
+    /// SOL tipped into the vault since `refundable_since`; the most a
+    /// refund can return. SPL, split and sponsor-matched tips never
+    /// count here.
+    pub refundable_amount: u64,
+    /// Timestamp of the oldest tip counted in `refundable_amount`
+    /// (u32 to fit the reserved space)
+    pub refundable_since: u32,
 }
 
 impl TipperRecord {
@@ -60,6 +78,9 @@ impl TipperRecord {
         self.weekly_start      = timestamp;
         self.monthly_amount    = first_amount;
         self.monthly_start     = timestamp;
+        self.version           = TIPPER_RECORD_VERSION;
+        self.refundable_amount = 0;
+        self.refundable_since  = 0;
         Ok(())
     }
 
@@ -98,6 +119,60 @@ impl TipperRecord {
         Ok(())
     }
 
+    /// Count a SOL tip into the vault as refundable. Tips pool up while the
+    /// oldest pooled tip is inside the creator's refund window; once it has
+    /// fallen out, the pool starts over from this tip.
+    pub fn record_refundable(&mut self, amount: u64, window: i64, timestamp: i64) -> Result<()> {
+        if timestamp.saturating_sub(self.refundable_since as i64) > window {
+            self.refundable_amount = amount;
+            self.refundable_since  = u32::try_from(timestamp).unwrap_or(u32::MAX);
+        } else {
+            self.refundable_amount = self.refundable_amount
+                .checked_add(amount)
+                .ok_or(anchor_lang::error!(crate::error::ErrorCode::MathOverflow))?;
+        }
+        Ok(())
+    }
+
+    /// Whether `amount` can still be refunded: it must come out of the
+    /// refundable pool, and the oldest tip in the pool must still be inside
+    /// the creator's refund window (0 = refunds off).
+    pub fn ensure_refundable(&self, amount: u64, window: i64, now: i64) -> Result<()> {
+        use crate::error::ErrorCode;
+        require!(amount > 0, ErrorCode::NothingToRefund);
+        require!(amount <= self.refundable_amount, ErrorCode::RefundExceedsTipped);
+        require!(
+            window > 0 && now.saturating_sub(self.refundable_since as i64) <= window,
+            ErrorCode::RefundWindowExpired
+        );
+        Ok(())
+    }
+
+    /// Take a refunded tip back out of the totals. Each refund reverses one
+    /// tip, but the count stays at 1 while any amount is left on record.
+    /// Window amounts saturate because the tip may predate the current window.
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.refundable_amount = self.refundable_amount
+            .checked_sub(amount)
+            .ok_or(anchor_lang::error!(crate::error::ErrorCode::MathUnderflow))?;
+        self.total_amount = self.total_amount
+            .checked_sub(amount)
+            .ok_or(anchor_lang::error!(crate::error::ErrorCode::MathUnderflow))?;
+        self.tip_count = if self.total_amount == 0 {
+            0
+        } else {
+            self.tip_count.saturating_sub(1).max(1)
+        };
+        self.weekly_amount  = self.weekly_amount.saturating_sub(amount);
+        self.monthly_amount = self.monthly_amount.saturating_sub(amount);
+        Ok(())
+    }
+
+    /// True once everything has been refunded; the next tip counts as new
+    pub fn is_fully_refunded(&self) -> bool {
+        self.total_amount == 0
+    }
+
     /// True on the very first tip (tip_count == 1 after initialize)
     pub fn is_new_tipper(&self) -> bool {
         self.tip_count == 1
@@ -109,6 +184,24 @@ impl TipperRecord {
     }
 }
 
+impl Versioned for TipperRecord {
+    const VERSION: u8 = TIPPER_RECORD_VERSION;
+    const SPACE: usize = TIPPER_RECORD_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) -> Result<()> {
+        // v0 -> v1: zeroed window starts make the next tip open fresh
+        // weekly and monthly windows.
+        // v1 -> v2: the refund fields were reserved (zeroed) bytes, so
+        // earlier tips are simply non-refundable.
+        self.version = TIPPER_RECORD_VERSION;
+        Ok(())
+    }
+}
+
 // ==========================================================
 // LeaderboardEntry – stored inline in TipProfile
 // Sorted in descending order by total_amount.
@@ -159,3 +252,18 @@ pub fn upsert_leaderboard(
     // Sort descending
     board.sort_by(|a, b| b.total_amount.cmp(&a.total_amount));
 }
+
+/// Take a refund off a tipper's leaderboard entry, dropping the entry
+/// once nothing is left.
+pub fn deduct_leaderboard(board: &mut Vec<LeaderboardEntry>, tipper: Pubkey, amount: u64) {
+    if let Some(pos) = board.iter().position(|e| e.tipper == tipper) {
+        board[pos].total_amount = board[pos].total_amount.saturating_sub(amount);
+        board[pos].tip_count    = board[pos].tip_count.saturating_sub(1).max(1);
+        if board[pos].total_amount == 0 {
+            board.remove(pos);
+        }
+    }
+
+    // Sort descending
+    board.sort_by_key(|e| std::cmp::Reverse(e.total_amount));
+}
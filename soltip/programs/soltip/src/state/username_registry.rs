@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+
+/// Claim on a username. One PDA per handle makes usernames unique
+/// across all profiles.
+///
+/// The seed is the username itself; `validate_username` only admits
+/// lowercase ASCII, digits and `_`, so every accepted name is already
+/// in its normalized form.
+///
+/// **PDA seeds:** `[b"username", username.as_bytes()]`
+#[account]
+pub struct UsernameRegistry {
+    /// The claimed username
+    pub username: String,
+    /// TipProfile PDA holding the name
+    pub profile: Pubkey,
+    /// Wallet that owns the profile (receives rent on release)
+    pub owner: Pubkey,
+    /// Unix timestamp the name was claimed
+    pub registered_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl UsernameRegistry {
+    pub const LEN: usize = USERNAME_REGISTRY_SIZE;
+
+    /// Claim a freshly created entry. The account is `init_if_needed`, so
+    /// an entry that already names a profile means the handle is taken.
+    pub fn claim(&mut self, username: String, profile: Pubkey, owner: Pubkey, timestamp: i64, bump: u8) -> Result<()> {
+        require!(self.profile == Pubkey::default(), ErrorCode::UsernameAlreadyTaken);
+        self.username      = username;
+        self.profile       = profile;
+        self.owner         = owner;
+        self.registered_at = timestamp;
+        self.bump          = bump;
+        Ok(())
+    }
+
+    /// Release the registry entry at `info` back to `owner`, if it exists
+    /// and belongs to `profile`.
+    ///
+    /// Profiles created before the registry have no entry, and a legacy
+    /// duplicate name may be held by another profile; both are skipped.
+    pub fn release<'info>(
+        info: &AccountInfo<'info>,
+        profile: Pubkey,
+        owner: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(());
+        }
+        let registry = UsernameRegistry::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        if registry.profile != profile {
+            return Ok(());
+        }
+
+        // Same steps as Anchor's `close` constraint
+        let lamports = info.lamports();
+        **owner.try_borrow_mut_lamports()? = owner
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ErrorCode::MathOverflow)?;
+        **info.try_borrow_mut_lamports()? = 0;
+        info.assign(&anchor_lang::system_program::ID);
+        info.realloc(0, false)?;
+        Ok(())
+    }
+}
@@ -23,6 +23,9 @@ pub struct Vault {
     pub created_at: i64,
     /// PDA bump
     pub bump: u8,
+    /// Lamports owed to split recipients in accrual mode; held back from
+    /// withdrawals until claimed
+    pub split_reserved: u64,
 }
 
 impl Vault {
@@ -35,6 +38,7 @@ impl Vault {
         self.total_withdrawn = 0;
         self.created_at    = timestamp;
         self.bump          = bump;
+        self.split_reserved = 0;
     }
 
     /// Credit funds into the vault (called after a CPI transfer into the vault PDA)
@@ -54,7 +58,7 @@ impl Vault {
         let remaining = self.balance
             .checked_sub(amount)
             .ok_or(ErrorCode::InsufficientBalance)?;
-        require!(remaining >= MIN_VAULT_RENT_BUFFER, ErrorCode::VaultBelowRentBuffer);
+        require!(remaining >= self.floor(), ErrorCode::VaultBelowRentBuffer);
         self.balance = remaining;
         self.total_withdrawn = self.total_withdrawn
             .checked_add(amount)
@@ -62,8 +66,50 @@ impl Vault {
         Ok(())
     }
 
-    /// Returns withdrawable amount (balance minus rent buffer)
+    /// Reverse a deposit that is being refunded to the tipper. Subject to
+    /// the same rent buffer as withdrawals.
+    pub fn refund(&mut self, amount: u64) -> Result<()> {
+        let remaining = self.balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+        require!(remaining >= self.floor(), ErrorCode::VaultBelowRentBuffer);
+        self.balance = remaining;
+        self.total_deposited = self.total_deposited
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        Ok(())
+    }
+
+    /// Hold back a split tip's lamports for its recipients (called after
+    /// `deposit`)
+    pub fn reserve_split(&mut self, amount: u64) -> Result<()> {
+        self.split_reserved = self.split_reserved
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Pay out a claimed split share from the reserve
+    pub fn release_split(&mut self, amount: u64) -> Result<()> {
+        self.split_reserved = self.split_reserved
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+        self.balance = self.balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+        self.total_withdrawn = self.total_withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Returns withdrawable amount (balance minus rent buffer and split reserve)
     pub fn withdrawable(&self) -> u64 {
-        self.balance.saturating_sub(MIN_VAULT_RENT_BUFFER)
+        self.balance.saturating_sub(self.floor())
+    }
+
+    /// Balance the creator can never take out
+    fn floor(&self) -> u64 {
+        MIN_VAULT_RENT_BUFFER.saturating_add(self.split_reserved)
     }
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// An account whose layout is tracked by a trailing `version` byte and can
+/// be upgraded in place by `migrate_account`.
+///
+/// New fields are appended to the end of the struct and carved out of the
+/// reserved tail, so an older account reads them as zero once it has been
+/// reallocated to `SPACE`. Accounts created before versioning read `0`.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    /// Layout version written by `initialize`
+    const VERSION: u8;
+    /// Current account size, including the discriminator
+    const SPACE: usize;
+
+    fn version(&self) -> u8;
+
+    /// Upgrade from `self.version()` to `VERSION`. Runs after the realloc,
+    /// so fields added since the stored version already read as zero.
+    fn upgrade(&mut self) -> Result<()>;
+}
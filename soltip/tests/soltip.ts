@@ -3,8 +3,11 @@
  *
  * Coverage (70+ tests):
  *  Profile creation, validation, updates, extended updates
+ *  Username registry: unique handles, change_username
+ *  Account versioning: migrate_account
  *  Vault initialization
  *  SOL tips to vault with rate-limit and on-chain leaderboard
+ *  Batch tips: several creators in one transaction
  *  SPL token tips (USDC mock)
  *  Fundraising goals (create, contribute, auto-complete, close, max-5)
  *  Subscriptions (SOL, cancel, payment rejection)
@@ -13,8 +16,18 @@
  *  Polls: create, vote, close, edge cases
  *  Content Gates: create, verify access, close, edge cases
  *  Referrals: register, edge cases
- *  Tip Splits: configure, send, edge cases
+ *  Tip Splits: configure, send (SOL and SPL), accrue and claim, edge cases
+ *  Governance: M-of-N admin proposals, timelock, emergency pause
+ *  Close profile: dependency checks, vault drain through the fee path
  *  Extended Profile: preset amounts, social links, webhook URL
+ *  Creator rate limits: cooldown, daily cap, per-tip amount limits
+ *  Blocklist: block/unblock wallets, blocked tips rejected
+ *  Media tips: creator media minimum, URL and clip validation
+ *  Sealed messages: creator encryption key, ciphertext size checks
+ *  Tip refunds: refund window, vault and leaderboard reversal
+ *  Matching pools: sponsor-funded tip matches, per-tipper cap
+ *  Pinned slot: tip auction for the pinned overlay message
+ *  Bounties: escrowed requests, accept/fulfill, dispute resolution, refunds
  *  Security: self-tip, below-minimum, max-goals, unauthorized access
  *  Negative edge cases throughout every module
  */
@@ -23,12 +36,14 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { Soltip } from "../target/types/soltip";
 import {
-  Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram,
+  Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction,
 } from "@solana/web3.js";
 import {
   createMint, createAccount, mintTo, TOKEN_PROGRAM_ID, getAccount,
+  ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount,
 } from "@solana/spl-token";
 import { assert, expect } from "chai";
+import { randomBytes } from "crypto";
 
 // ─────────────────────────────────────────────────────────────────
 // Shared provider + program
@@ -45,6 +60,7 @@ const pda = (seeds: Buffer[]) =>
 
 const profilePda   = (o: PublicKey)                 => pda([Buffer.from("tip_profile"),   o.toBuffer()]);
 const vaultPda     = (p: PublicKey)                 => pda([Buffer.from("vault"),          p.toBuffer()]);
+const splVaultPda  = (p: PublicKey, m: PublicKey)   => pda([Buffer.from("spl_vault"),      p.toBuffer(), m.toBuffer()]);
 const trPda        = (t: PublicKey, p: PublicKey)   => pda([Buffer.from("tipper_record"),  t.toBuffer(), p.toBuffer()]);
 const rlPda        = (t: PublicKey, p: PublicKey)   => pda([Buffer.from("rate_limit"),     t.toBuffer(), p.toBuffer()]);
 const goalPda      = (p: PublicKey, id: number)     => pda([Buffer.from("tip_goal"),       p.toBuffer(), new BN(id).toArrayLike(Buffer,"le",8)]);
@@ -55,6 +71,24 @@ const pollPda      = (p: PublicKey, id: number)     => pda([Buffer.from("tip_pol
 const referralPda  = (r: PublicKey, p: PublicKey)   => pda([Buffer.from("referral"),        r.toBuffer(), p.toBuffer()]);
 const gatePda      = (p: PublicKey, id: number)     => pda([Buffer.from("content_gate"),    p.toBuffer(), new BN(id).toArrayLike(Buffer,"le",8)]);
 const splitPda     = (p: PublicKey)                 => pda([Buffer.from("tip_split"),       p.toBuffer()]);
+const contribPda   = (g: PublicKey, c: PublicKey)   => pda([Buffer.from("goal_contribution"), g.toBuffer(), c.toBuffer()]);
+const tierPda      = (p: PublicKey, id: number)     => pda([Buffer.from("subscription_tier"), p.toBuffer(), new BN(id).toArrayLike(Buffer,"le",8)]);
+const governancePda = ()                            => pda([Buffer.from("governance")]);
+const proposalPda  = (id: number)                   => pda([Buffer.from("admin_proposal"), new BN(id).toArrayLike(Buffer,"le",8)]);
+const usernamePda  = (u: string)                    => pda([Buffer.from("username"), Buffer.from(u)]);
+const blockedPda   = (p: PublicKey, w: PublicKey)   => pda([Buffer.from("blocked_tipper"), p.toBuffer(), w.toBuffer()]);
+const matchPoolPda = (p: PublicKey, s: PublicKey, id: number) => pda([Buffer.from("matching_pool"), p.toBuffer(), s.toBuffer(), new BN(id).toArrayLike(Buffer,"le",8)]);
+const matchRecPda  = (pool: PublicKey, t: PublicKey) => pda([Buffer.from("match_record"), pool.toBuffer(), t.toBuffer()]);
+const pinnedPda    = (p: PublicKey)                 => pda([Buffer.from("pinned_slot"), p.toBuffer()]);
+const bountyPda    = (p: PublicKey, r: PublicKey, id: number) => pda([Buffer.from("bounty"), p.toBuffer(), r.toBuffer(), new BN(id).toArrayLike(Buffer,"le",8)]);
+
+// update_profile_extended params with every setting left unchanged
+const extParams = (p: object = {}) => ({
+  presetAmounts: null, socialLinks: null, webhookUrl: null,
+  subscriptionGraceSeconds: null, subscriptionBackcharge: null,
+  rateLimitSettings: null, minMediaTipAmount: null, refundWindowSeconds: null,
+  ...p,
+});
 
 // ─────────────────────────────────────────────────────────────────
 // Airdrop helper
@@ -71,6 +105,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
   let creator: Keypair, tipper1: Keypair, tipper2: Keypair, admin: Keypair;
   let creatorProfile: PublicKey, creatorVault: PublicKey;
   let mint: PublicKey, tipperTA: PublicKey, creatorTA: PublicKey;
+  let creatorSplVault: PublicKey, splVaultTA: PublicKey;
 
   before(async () => {
     creator = Keypair.generate();
@@ -93,6 +128,25 @@ describe("SolTip v2 - Full Platform Tests", () => {
     tipperTA  = await createAccount(provider.connection, tipper1, mint, tipper1.publicKey);
     creatorTA = await createAccount(provider.connection, creator,  mint, creator.publicKey);
     await mintTo(provider.connection, creator, mint, tipperTA, creator, 1_000_000_000);
+
+    creatorSplVault = splVaultPda(creatorProfile, mint);
+    splVaultTA      = getAssociatedTokenAddressSync(mint, creatorSplVault, true);
+  });
+
+  // ── 0. Platform Config (before profile updates and tipping) ──────
+  describe("0. Platform Config (early init)", () => {
+    it("initializes platform config before profile updates and tipping", async () => {
+      await program.methods.initializePlatform()
+        .accounts({
+          authority: admin.publicKey, platformConfig: configPda(),
+          platformTreasury: treasuryPda(), systemProgram: SystemProgram.programId,
+        }).signers([admin]).rpc();
+
+      const cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.authority.toString(), admin.publicKey.toString());
+      assert.equal(cfg.paused, false);
+      console.log("  Platform config initialized (early)");
+    });
   });
 
   // ── 1. Profile Management ─────────────────────────────────────
@@ -103,6 +157,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
         .createProfile("streamer_pro", "Awesome Streamer", "Best stream", "https://img.example.com/a.png")
         .accounts({
           owner: creator.publicKey, tipProfile: creatorProfile,
+          usernameRegistry: usernamePda("streamer_pro"),
           systemProgram: SystemProgram.programId,
         })
         .signers([creator]).rpc();
@@ -121,7 +176,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
       try {
         await program.methods
           .createProfile("BADUSER", "n", "n", "")
-          .accounts({ owner: bad.publicKey, tipProfile: profilePda(bad.publicKey), systemProgram: SystemProgram.programId })
+          .accounts({ owner: bad.publicKey, tipProfile: profilePda(bad.publicKey), usernameRegistry: usernamePda("BADUSER"), systemProgram: SystemProgram.programId })
           .signers([bad]).rpc();
         assert.fail("Should reject uppercase");
       } catch (e) {
@@ -135,7 +190,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
       try {
         await program.methods
           .createProfile("", "n", "n", "")
-          .accounts({ owner: bad.publicKey, tipProfile: profilePda(bad.publicKey), systemProgram: SystemProgram.programId })
+          .accounts({ owner: bad.publicKey, tipProfile: profilePda(bad.publicKey), usernameRegistry: usernamePda(""), systemProgram: SystemProgram.programId })
           .signers([bad]).rpc();
         assert.fail("Should reject empty");
       } catch (e) {
@@ -143,10 +198,72 @@ describe("SolTip v2 - Full Platform Tests", () => {
       }
     });
 
+    it("rejects a username already held by another profile", async () => {
+      const squatter = Keypair.generate();
+      await airdrop(squatter.publicKey);
+      try {
+        await program.methods
+          .createProfile("streamer_pro", "Fake", "n", "")
+          .accounts({
+            owner: squatter.publicKey, tipProfile: profilePda(squatter.publicKey),
+            usernameRegistry: usernamePda("streamer_pro"), systemProgram: SystemProgram.programId,
+          })
+          .signers([squatter]).rpc();
+        assert.fail("Should reject duplicate username");
+      } catch (e) {
+        expect(e.toString()).to.include("UsernameAlreadyTaken");
+      }
+      const reg = await program.account.usernameRegistry.fetch(usernamePda("streamer_pro"));
+      assert.equal(reg.profile.toString(), creatorProfile.toString());
+    });
+
+    it("changes username and releases the old handle", async () => {
+      const changeAccounts = (from: string, to: string) => ({
+        owner: creator.publicKey, tipProfile: creatorProfile,
+        oldUsernameRegistry: usernamePda(from), newUsernameRegistry: usernamePda(to),
+        systemProgram: SystemProgram.programId,
+      });
+
+      await program.methods.changeUsername("streamer_new")
+        .accounts(changeAccounts("streamer_pro", "streamer_new"))
+        .signers([creator]).rpc();
+      assert.equal((await program.account.tipProfile.fetch(creatorProfile)).username, "streamer_new");
+      assert.isNull(await provider.connection.getAccountInfo(usernamePda("streamer_pro")), "Old handle released");
+
+      try {
+        await program.methods.changeUsername("streamer_new")
+          .accounts(changeAccounts("streamer_new", "streamer_new"))
+          .signers([creator]).rpc();
+        assert.fail("Should reject unchanged username");
+      } catch (e) {
+        expect(e.toString()).to.include("UsernameUnchanged");
+      }
+
+      // Switch back; later suites look the creator up as streamer_pro
+      await program.methods.changeUsername("streamer_pro")
+        .accounts(changeAccounts("streamer_new", "streamer_pro"))
+        .signers([creator]).rpc();
+      assert.equal((await program.account.tipProfile.fetch(creatorProfile)).username, "streamer_pro");
+    });
+
+    it("new profiles start at the current layout version", async () => {
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(p.version, 5);
+
+      try {
+        await program.methods.migrateAccount()
+          .accounts({ payer: creator.publicKey, account: creatorProfile, systemProgram: SystemProgram.programId })
+          .signers([creator]).rpc();
+        assert.fail("Current accounts have nothing to migrate");
+      } catch (e) {
+        expect(e.toString()).to.include("AccountAlreadyMigrated");
+      }
+    });
+
     it("updates profile fields", async () => {
       await program.methods
         .updateProfile("Updated Name", "New bio", null, null, null, false)
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -158,7 +275,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
       try {
         await program.methods
           .updateProfile("Hacked", null, null, null, null, null)
-          .accounts({ owner: tipper1.publicKey, tipProfile: creatorProfile })
+          .accounts({ owner: tipper1.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
           .signers([tipper1]).rpc();
         assert.fail("Should reject unauthorized");
       } catch (e) {
@@ -187,22 +304,6 @@ describe("SolTip v2 - Full Platform Tests", () => {
     });
   });
 
-  // ── 2b. Platform Config (must be before tipping) ────────────────
-  describe("2b. Platform Config (early init)", () => {
-    it("initializes platform config before tipping", async () => {
-      await program.methods.initializePlatform()
-        .accounts({
-          authority: admin.publicKey, platformConfig: configPda(),
-          platformTreasury: treasuryPda(), systemProgram: SystemProgram.programId,
-        }).signers([admin]).rpc();
-
-      const cfg = await program.account.platformConfig.fetch(configPda());
-      assert.equal(cfg.authority.toString(), admin.publicKey.toString());
-      assert.equal(cfg.paused, false);
-      console.log("  Platform config initialized (early)");
-    });
-  });
-
   // ── 3. SOL Tipping ────────────────────────────────────────────
 
   describe("3. SOL Tipping", () => {
@@ -210,14 +311,16 @@ describe("SolTip v2 - Full Platform Tests", () => {
       const amount = 0.5 * LAMPORTS_PER_SOL;
       const vBefore = (await program.account.vault.fetch(creatorVault)).balance.toNumber();
 
-      await program.methods.sendTip(new BN(amount), "Great stream!")
+      await program.methods.sendTip(new BN(amount), "Great stream!", null, null)
         .accounts({
           tipper: tipper1.publicKey, recipientProfile: creatorProfile,
           recipientOwner: creator.publicKey, vault: creatorVault,
           tipperRecord: trPda(tipper1.publicKey, creatorProfile),
           rateLimit: rlPda(tipper1.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
           platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
         }).signers([tipper1]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -232,14 +335,16 @@ describe("SolTip v2 - Full Platform Tests", () => {
 
     it("second tipper ranks #1 in leaderboard (higher amount)", async () => {
       const amount = 1 * LAMPORTS_PER_SOL;
-      await program.methods.sendTip(new BN(amount), "Even better!")
+      await program.methods.sendTip(new BN(amount), "Even better!", null, null)
         .accounts({
           tipper: tipper2.publicKey, recipientProfile: creatorProfile,
           recipientOwner: creator.publicKey, vault: creatorVault,
           tipperRecord: trPda(tipper2.publicKey, creatorProfile),
           rateLimit: rlPda(tipper2.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
           platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
         }).signers([tipper2]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -251,14 +356,16 @@ describe("SolTip v2 - Full Platform Tests", () => {
 
     it("prevents self-tipping", async () => {
       try {
-        await program.methods.sendTip(new BN(LAMPORTS_PER_SOL), "Self tip")
+        await program.methods.sendTip(new BN(LAMPORTS_PER_SOL), "Self tip", null, null)
           .accounts({
             tipper: creator.publicKey, recipientProfile: creatorProfile,
             recipientOwner: creator.publicKey, vault: creatorVault,
             tipperRecord: trPda(creator.publicKey, creatorProfile),
             rateLimit: rlPda(creator.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, creator.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
+            matchingPool: null, matchRecord: null, pinnedSlot: null,
           }).signers([creator]).rpc();
         assert.fail("Should reject self-tip");
       } catch (e) {
@@ -270,20 +377,264 @@ describe("SolTip v2 - Full Platform Tests", () => {
       const t = Keypair.generate();
       await airdrop(t.publicKey);
       try {
-        await program.methods.sendTip(new BN(500), "tiny")
+        await program.methods.sendTip(new BN(500), "tiny", null, null)
           .accounts({
             tipper: t.publicKey, recipientProfile: creatorProfile,
             recipientOwner: creator.publicKey, vault: creatorVault,
             tipperRecord: trPda(t.publicKey, creatorProfile),
             rateLimit: rlPda(t.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, t.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
+            matchingPool: null, matchRecord: null, pinnedSlot: null,
           }).signers([t]).rpc();
         assert.fail("Should reject below-min tip");
       } catch (e) {
         expect(e.toString()).to.include("TipAmountTooSmall");
       }
     });
+
+    it("rejects tips from a blocked wallet until unblocked", async () => {
+      const t = Keypair.generate();
+      await airdrop(t.publicKey);
+      const blocked = blockedPda(creatorProfile, t.publicKey);
+      await program.methods.blockTipper(t.publicKey)
+        .accounts({
+          owner: creator.publicKey, tipProfile: creatorProfile,
+          blockedTipper: blocked, systemProgram: SystemProgram.programId,
+        }).signers([creator]).rpc();
+
+      const entry = await program.account.blockedTipper.fetch(blocked);
+      assert.ok(entry.wallet.equals(t.publicKey));
+
+      const tip = () => program.methods.sendTip(new BN(LAMPORTS_PER_SOL / 10), "let me in", null, null)
+        .accounts({
+          tipper: t.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(t.publicKey, creatorProfile),
+          rateLimit: rlPda(t.publicKey, creatorProfile),
+          blockedTipper: blocked,
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
+        }).signers([t]).rpc();
+
+      try {
+        await tip();
+        assert.fail("Should reject tip from a blocked wallet");
+      } catch (e) {
+        expect(e.toString()).to.include("TipperBlocked");
+      }
+
+      await program.methods.unblockTipper()
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, blockedTipper: blocked })
+        .signers([creator]).rpc();
+      assert.isNull(await provider.connection.getAccountInfo(blocked));
+      await tip();
+    });
+
+    it("rejects blocking the creator's own wallet", async () => {
+      try {
+        await program.methods.blockTipper(creator.publicKey)
+          .accounts({
+            owner: creator.publicKey, tipProfile: creatorProfile,
+            blockedTipper: blockedPda(creatorProfile, creator.publicKey),
+            systemProgram: SystemProgram.programId,
+          }).signers([creator]).rpc();
+        assert.fail("Should reject self-block");
+      } catch (e) {
+        expect(e.toString()).to.include("CannotBlockSelf");
+      }
+    });
+
+    it("matches a tip from a sponsor pool up to the per-tipper cap", async () => {
+      const sponsor = Keypair.generate();
+      const fan = Keypair.generate();
+      await airdrop(sponsor.publicKey, 5);
+      await airdrop(fan.publicKey, 2);
+      const pool = matchPoolPda(creatorProfile, sponsor.publicKey, 1);
+      const now = Math.floor(Date.now() / 1000);
+      const createPool = (ratioBps: number) => program.methods
+        .createMatchingPool(new BN(1), ratioBps, new BN(0.1 * LAMPORTS_PER_SOL), new BN(LAMPORTS_PER_SOL), new BN(now + 3600))
+        .accounts({
+          sponsor: sponsor.publicKey, tipProfile: creatorProfile, tipGoal: null,
+          matchingPool: pool, platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+        }).signers([sponsor]).rpc();
+
+      try {
+        await createPool(0);
+        assert.fail("Zero match ratio");
+      } catch (e: any) {
+        assert.include(e.toString(), "InvalidMatchRatio");
+      }
+      await createPool(10_000);
+
+      const amount = 0.2 * LAMPORTS_PER_SOL;
+      const vBefore = (await program.account.vault.fetch(creatorVault)).balance.toNumber();
+      await program.methods.sendTip(new BN(amount), "matched!", null, null)
+        .accounts({
+          tipper: fan.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(fan.publicKey, creatorProfile),
+          rateLimit: rlPda(fan.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, fan.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: pool, matchRecord: matchRecPda(pool, fan.publicKey), pinnedSlot: null,
+        }).signers([fan]).rpc();
+
+      // 1:1 would be 0.2 SOL; the per-tipper cap holds it to 0.1 SOL
+      const matched = 0.1 * LAMPORTS_PER_SOL;
+      const v = await program.account.vault.fetch(creatorVault);
+      const mp = await program.account.matchingPool.fetch(pool);
+      const rec = await program.account.matchRecord.fetch(matchRecPda(pool, fan.publicKey));
+      assert.equal(v.balance.toNumber() - vBefore, amount + matched);
+      assert.equal(mp.matchedAmount.toNumber(), matched);
+      assert.equal(rec.matchedAmount.toNumber(), matched);
+
+      const tr = await program.account.tipperRecord.fetch(trPda(fan.publicKey, creatorProfile));
+      assert.equal(tr.refundableAmount.toNumber(), 0, "Matched tips are not refundable");
+      try {
+        await program.methods.refundTip(new BN(amount))
+          .accounts({
+            owner: creator.publicKey, tipProfile: creatorProfile, vault: creatorVault,
+            tipperRecord: trPda(fan.publicKey, creatorProfile), tipper: fan.publicKey,
+          }).signers([creator]).rpc();
+        assert.fail("A matched tip cannot be refunded");
+      } catch (e: any) {
+        assert.include(e.toString(), "RefundExceedsTipped");
+      }
+
+      try {
+        await program.methods.closeMatchingPool()
+          .accounts({ sponsor: sponsor.publicKey, matchingPool: pool })
+          .signers([sponsor]).rpc();
+        assert.fail("Pool is still running");
+      } catch (e: any) {
+        assert.include(e.toString(), "MatchingPoolNotExpired");
+      }
+    });
+
+    it("auctions the pinned slot: outbids need the minimum raise", async () => {
+      const slot = pinnedPda(creatorProfile);
+      const [fanA, fanB] = [Keypair.generate(), Keypair.generate()];
+      await airdrop(fanA.publicKey, 2);
+      await airdrop(fanB.publicKey, 2);
+
+      // 60s pins, 0.05 SOL opening bid, +10% to outbid
+      await program.methods.configurePinnedSlot(new BN(60), new BN(0.05 * LAMPORTS_PER_SOL), 1_000)
+        .accounts({
+          owner: creator.publicKey, tipProfile: creatorProfile,
+          pinnedSlot: slot, systemProgram: SystemProgram.programId,
+        }).signers([creator]).rpc();
+
+      const bid = (fan: Keypair, amount: number, message: string | null) => program.methods
+        .sendTip(new BN(amount), message, null, null)
+        .accounts({
+          tipper: fan.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(fan.publicKey, creatorProfile),
+          rateLimit: rlPda(fan.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, fan.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: slot,
+        }).signers([fan]).rpc();
+
+      const vBefore = (await program.account.vault.fetch(creatorVault)).balance.toNumber();
+      await bid(fanA, 0.1 * LAMPORTS_PER_SOL, "first pin");
+      let s = await program.account.pinnedSlot.fetch(slot);
+      assert.equal(s.tipper.toString(), fanA.publicKey.toString());
+      assert.equal(s.message, "first pin");
+
+      try {
+        await bid(fanB, 0.105 * LAMPORTS_PER_SOL, "not enough");
+        assert.fail("Needs a 10% raise");
+      } catch (e: any) {
+        assert.include(e.toString(), "PinBidTooLow");
+      }
+      try {
+        await bid(fanB, 0.2 * LAMPORTS_PER_SOL, null);
+        assert.fail("Pins need a message");
+      } catch (e: any) {
+        assert.include(e.toString(), "PinRequiresMessage");
+      }
+
+      await bid(fanB, 0.11 * LAMPORTS_PER_SOL, "outbid you");
+      s = await program.account.pinnedSlot.fetch(slot);
+      assert.equal(s.tipper.toString(), fanB.publicKey.toString());
+      assert.equal(s.bid.toNumber(), 0.11 * LAMPORTS_PER_SOL);
+      assert.equal(s.totalPins.toNumber(), 2);
+
+      // Both bids are ordinary tips: nothing is refunded to fanA
+      const v = await program.account.vault.fetch(creatorVault);
+      assert.equal(v.balance.toNumber() - vBefore, 0.21 * LAMPORTS_PER_SOL);
+    });
+
+    it("tips two creators in one batch transaction", async () => {
+      const second = Keypair.generate();
+      const fan = Keypair.generate();
+      await airdrop(second.publicKey, 2);
+      await airdrop(fan.publicKey, 2);
+      const secondProfile = profilePda(second.publicKey);
+      const secondVault   = vaultPda(secondProfile);
+      await program.methods.createProfile("batch_creator", "Batch", "", "")
+        .accounts({ owner: second.publicKey, tipProfile: secondProfile, usernameRegistry: usernamePda("batch_creator"), systemProgram: SystemProgram.programId })
+        .signers([second]).rpc();
+      await program.methods.initializeVault()
+        .accounts({ owner: second.publicKey, tipProfile: secondProfile, vault: secondVault, systemProgram: SystemProgram.programId })
+        .signers([second]).rpc();
+
+      const group = (profile: PublicKey, owner: PublicKey, vault: PublicKey) => [
+        { pubkey: profile, isWritable: true, isSigner: false },
+        { pubkey: owner, isWritable: false, isSigner: false },
+        { pubkey: vault, isWritable: true, isSigner: false },
+        { pubkey: trPda(fan.publicKey, profile), isWritable: true, isSigner: false },
+        { pubkey: rlPda(fan.publicKey, profile), isWritable: true, isSigner: false },
+        { pubkey: blockedPda(profile, fan.publicKey), isWritable: false, isSigner: false },
+      ];
+      const batch = (tips: { profile: PublicKey; amount: BN; message: string | null }[], accounts: any[]) =>
+        program.methods.sendTipBatch(tips)
+          .accounts({ tipper: fan.publicKey, platformConfig: configPda(), systemProgram: SystemProgram.programId })
+          .remainingAccounts(accounts)
+          .signers([fan]).rpc();
+
+      // Account groups must line up with the tips
+      try {
+        await batch(
+          [{ profile: creatorProfile, amount: new BN(0.1 * LAMPORTS_PER_SOL), message: "gg" }],
+          group(secondProfile, second.publicKey, secondVault),
+        );
+        assert.fail("Mismatched account group");
+      } catch (e: any) {
+        assert.include(e.toString(), "BatchAccountsMismatch");
+      }
+
+      // Lamports sent to a record PDA ahead of time must not block its creation
+      await provider.sendAndConfirm(new Transaction().add(SystemProgram.transfer({
+        fromPubkey: provider.wallet.publicKey,
+        toPubkey: trPda(fan.publicKey, secondProfile),
+        lamports: 1_000,
+      })));
+
+      const v1Before = (await program.account.vault.fetch(creatorVault)).balance.toNumber();
+      await batch(
+        [
+          { profile: creatorProfile, amount: new BN(0.1 * LAMPORTS_PER_SOL), message: "gg" },
+          { profile: secondProfile, amount: new BN(0.3 * LAMPORTS_PER_SOL), message: "gg wp" },
+        ],
+        [...group(creatorProfile, creator.publicKey, creatorVault), ...group(secondProfile, second.publicKey, secondVault)],
+      );
+
+      const v1 = await program.account.vault.fetch(creatorVault);
+      const p2 = await program.account.tipProfile.fetch(secondProfile);
+      const tr2 = await program.account.tipperRecord.fetch(trPda(fan.publicKey, secondProfile));
+      assert.equal(v1.balance.toNumber() - v1Before, 0.1 * LAMPORTS_PER_SOL);
+      assert.equal(p2.totalTipsReceived.toNumber(), 1);
+      assert.equal(tr2.totalAmount.toNumber(), 0.3 * LAMPORTS_PER_SOL);
+      console.log("  Batch: 0.1 + 0.3 SOL to two creators in one tx");
+    });
   });
 
   // ── 4. SPL Token Tipping ─────────────────────────────────────
@@ -300,17 +651,36 @@ describe("SolTip v2 - Full Platform Tests", () => {
       await mintTo(provider.connection, creator, mint, splTipperTA, creator, 1_000_000_000);
     });
 
-    it("sends USDC tip and updates SPL stats", async () => {
+    it("initializes the per-mint SPL vault", async () => {
+      await program.methods.initializeSplVault()
+        .accounts({
+          owner: creator.publicKey, tipProfile: creatorProfile, tokenMint: mint,
+          splVault: creatorSplVault, vaultTokenAccount: splVaultTA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
+        }).signers([creator]).rpc();
+
+      const v = await program.account.splVault.fetch(creatorSplVault);
+      assert.equal(v.owner.toString(), creator.publicKey.toString());
+      assert.equal(v.tokenMint.toString(), mint.toString());
+      assert.equal(v.tokenAccount.toString(), splVaultTA.toString());
+      assert.equal(v.balance.toNumber(), 0);
+    });
+
+    it("sends USDC tip into the SPL vault and updates SPL stats", async () => {
       const amount = 15_000_000; // 15 USDC (6 decimals) – enough for SPL withdrawal test later
       const balBefore = (await getAccount(provider.connection, splTipperTA)).amount;
 
-      await program.methods.sendTipSpl(new BN(amount), "15 USDC tip!")
+      await program.methods.sendTipSpl(new BN(amount), "15 USDC tip!", null, null)
         .accounts({
           tipper: splTipper.publicKey, tipperTokenAccount: splTipperTA,
           recipientProfile: creatorProfile, recipientOwner: creator.publicKey,
-          recipientTokenAccount: creatorTA,
+          tokenMint: mint, splVault: creatorSplVault, vaultTokenAccount: splVaultTA,
           tipperRecord: trPda(splTipper.publicKey, creatorProfile),
           rateLimit: rlPda(splTipper.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, splTipper.publicKey),
           platformConfig: configPda(),
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -319,11 +689,32 @@ describe("SolTip v2 - Full Platform Tests", () => {
       const balAfter = (await getAccount(provider.connection, splTipperTA)).amount;
       assert.equal(Number(balBefore - balAfter), amount, "Tipper token balance should decrease");
 
+      const vaultTA = await getAccount(provider.connection, splVaultTA);
+      assert.equal(Number(vaultTA.amount), amount, "Tokens should be escrowed in the SPL vault");
+      const v = await program.account.splVault.fetch(creatorSplVault);
+      assert.equal(v.balance.toNumber(), amount);
+      assert.equal(v.totalDeposited.toNumber(), amount);
+
       const p = await program.account.tipProfile.fetch(creatorProfile);
       assert.isTrue(p.totalAmountReceivedSpl.toNumber() >= amount);
       console.log("  SPL stats:", p.totalAmountReceivedSpl.toNumber(), "units total");
     });
 
+    it("does not let SPL tips be refunded out of the SOL vault", async () => {
+      const tr = await program.account.tipperRecord.fetch(trPda(splTipper.publicKey, creatorProfile));
+      assert.equal(tr.refundableAmount.toNumber(), 0, "SPL tips are never refundable");
+      try {
+        await program.methods.refundTip(new BN(1_000_000))
+          .accounts({
+            owner: creator.publicKey, tipProfile: creatorProfile, vault: creatorVault,
+            tipperRecord: trPda(splTipper.publicKey, creatorProfile), tipper: splTipper.publicKey,
+          }).signers([creator]).rpc();
+        assert.fail("SPL units must not be refundable as lamports");
+      } catch (e: any) {
+        assert.include(e.toString(), "RefundExceedsTipped");
+      }
+    });
+
     it("rejects SPL self-tip", async () => {
       try {
         // Would need creator's own token accounts to test fully
@@ -346,8 +737,11 @@ describe("SolTip v2 - Full Platform Tests", () => {
     it("creates a goal", async () => {
       tipGoal = goalPda(creatorProfile, GID);
       await program.methods
-        .createGoal(new BN(GID), "PC Fund", "Buy streaming PC", new BN(TARGET), SystemProgram.programId, null)
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal, systemProgram: SystemProgram.programId })
+        .createGoal(new BN(GID), {
+          title: "PC Fund", description: "Buy streaming PC", targetAmount: new BN(TARGET),
+          tokenMint: SystemProgram.programId, deadline: null, escrowed: false,
+        })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal, systemProgram: SystemProgram.programId, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const g = await program.account.tipGoal.fetch(tipGoal);
@@ -365,7 +759,10 @@ describe("SolTip v2 - Full Platform Tests", () => {
         .accounts({
           contributor: tipper1.publicKey, recipientProfile: creatorProfile,
           tipGoal, recipientOwner: creator.publicKey,
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
           platformConfig: configPda(), systemProgram: SystemProgram.programId,
+          referral: null,
+          matchingPool: null, matchRecord: null,
         }).signers([tipper1]).rpc();
 
       const g = await program.account.tipGoal.fetch(tipGoal);
@@ -379,7 +776,10 @@ describe("SolTip v2 - Full Platform Tests", () => {
         .accounts({
           contributor: tipper2.publicKey, recipientProfile: creatorProfile,
           tipGoal, recipientOwner: creator.publicKey,
+          blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
           platformConfig: configPda(), systemProgram: SystemProgram.programId,
+          referral: null,
+          matchingPool: null, matchRecord: null,
         }).signers([tipper2]).rpc();
 
       const g = await program.account.tipGoal.fetch(tipGoal);
@@ -397,78 +797,411 @@ describe("SolTip v2 - Full Platform Tests", () => {
       assert.equal(p.activeGoalsCount, 0, "Active goal count should decrement");
     });
 
-    it("enforces max 5 active goals", async () => {
-      // Create goals 2-6 (should succeed, filling count to 5), goal 7 should fail
-      for (let i = 2; i <= 7; i++) {
-        const gp = goalPda(creatorProfile, i);
-        try {
-          await program.methods
-            .createGoal(new BN(i), `Goal ${i}`, "desc", new BN(LAMPORTS_PER_SOL), SystemProgram.programId, null)
-            .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: gp, systemProgram: SystemProgram.programId })
-            .signers([creator]).rpc();
+    it("escrowed goal: holds funds and refunds after a missed deadline", async () => {
+      const EID = 100;
+      const escrowGoal = goalPda(creatorProfile, EID);
+      const deadline = Math.floor(Date.now() / 1000) + 3;
+      const contrib = 0.5 * LAMPORTS_PER_SOL;
 
-          if (i === 7) assert.fail("Should fail at 6th active goal");
-          console.log(`  Goal ${i} created`);
-        } catch (e) {
-          if (i === 7) {
-            expect(e.toString()).to.include("MaxActiveGoalsReached");
-            console.log("  6th active goal correctly rejected");
-          } else {
-            throw e;
-          }
-        }
+      await program.methods
+        .createGoal(new BN(EID), {
+          title: "Escrow Fund", description: "All or nothing", targetAmount: new BN(5 * LAMPORTS_PER_SOL),
+          tokenMint: SystemProgram.programId, deadline: new BN(deadline), escrowed: true,
+        })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal, systemProgram: SystemProgram.programId, platformConfig: configPda() })
+        .signers([creator]).rpc();
+
+      await program.methods.contributeGoal(new BN(contrib), "Pledge")
+        .accounts({
+          contributor: tipper1.publicKey, recipientProfile: creatorProfile,
+          tipGoal: escrowGoal, goalContribution: contribPda(escrowGoal, tipper1.publicKey),
+          recipientOwner: creator.publicKey,
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
+          platformConfig: configPda(), systemProgram: SystemProgram.programId,
+          referral: null,
+          matchingPool: null, matchRecord: null,
+        }).signers([tipper1]).rpc();
+
+      let g = await program.account.tipGoal.fetch(escrowGoal);
+      assert.equal(g.escrowed, true);
+      assert.equal(g.escrowBalance.toNumber(), contrib, "Full contribution held in escrow");
+      assert.equal(g.uniqueContributors, 1);
+      const rec = await program.account.goalContribution.fetch(contribPda(escrowGoal, tipper1.publicKey));
+      assert.equal(rec.amount.toNumber(), contrib);
+
+      try {
+        await program.methods.refundGoalContribution()
+          .accounts({ contributor: tipper1.publicKey, tipGoal: escrowGoal, recipientProfile: creatorProfile, goalContribution: contribPda(escrowGoal, tipper1.publicKey) })
+          .signers([tipper1]).rpc();
+        assert.fail("Refund must wait for the deadline");
+      } catch (e) {
+        expect(e.toString()).to.include("GoalNotExpired");
       }
+
+      await new Promise(resolve => setTimeout(resolve, 4000));
+
+      const profBefore = await program.account.tipProfile.fetch(creatorProfile);
+      const balBefore = await provider.connection.getBalance(tipper1.publicKey);
+      await program.methods.refundGoalContribution()
+        .accounts({ contributor: tipper1.publicKey, tipGoal: escrowGoal, recipientProfile: creatorProfile, goalContribution: contribPda(escrowGoal, tipper1.publicKey) })
+        .signers([tipper1]).rpc();
+      const balAfter = await provider.connection.getBalance(tipper1.publicKey);
+      assert.isTrue(balAfter - balBefore >= contrib - 10_000, "Contributor gets the full pledge back");
+
+      const profAfter = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(profBefore.totalTipsReceived.toNumber() - profAfter.totalTipsReceived.toNumber(), 1);
+      assert.equal(
+        profBefore.totalAmountReceivedLamports.toNumber() - profAfter.totalAmountReceivedLamports.toNumber(),
+        contrib, "Refund is taken back out of the profile stats",
+      );
+
+      g = await program.account.tipGoal.fetch(escrowGoal);
+      assert.equal(g.escrowBalance.toNumber(), 0);
+
+      await program.methods.closeGoal()
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal })
+        .signers([creator]).rpc();
     });
-  });
 
-  // ── 6. Subscriptions ─────────────────────────────────────────
+    it("escrowed goal: a failed goal's match goes back to the sponsor's pool", async () => {
+      const EID = 102;
+      const escrowGoal = goalPda(creatorProfile, EID);
+      const sponsor = Keypair.generate();
+      const fan = Keypair.generate();
+      await airdrop(sponsor.publicKey, 3);
+      await airdrop(fan.publicKey, 2);
+      const now = Math.floor(Date.now() / 1000);
+      const contrib = 0.4 * LAMPORTS_PER_SOL;
 
-  describe("6. Subscriptions", () => {
-    let subscription: PublicKey;
+      await program.methods
+        .createGoal(new BN(EID), {
+          title: "Matched Escrow", description: "Will miss", targetAmount: new BN(5 * LAMPORTS_PER_SOL),
+          tokenMint: SystemProgram.programId, deadline: new BN(now + 3), escrowed: true,
+        })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal, systemProgram: SystemProgram.programId, platformConfig: configPda() })
+        .signers([creator]).rpc();
 
-    it("creates SOL subscription", async () => {
-      subscription = subPda(tipper1.publicKey, creatorProfile);
+      const pool = matchPoolPda(creatorProfile, sponsor.publicKey, 7);
       await program.methods
-        .createSubscription(new BN(0.1 * LAMPORTS_PER_SOL), new BN(86400), false, SystemProgram.programId)
+        .createMatchingPool(new BN(7), 5_000, new BN(LAMPORTS_PER_SOL), new BN(LAMPORTS_PER_SOL), new BN(now + 3600))
         .accounts({
-          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
-          recipientOwner: creator.publicKey, subscription,
+          sponsor: sponsor.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal,
+          matchingPool: pool, platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
-        }).signers([tipper1]).rpc();
+        }).signers([sponsor]).rpc();
 
-      const s = await program.account.subscription.fetch(subscription);
-      assert.equal(s.isActive, true);
-      assert.equal(s.isSpl, false);
-      assert.equal(s.amountPerInterval.toNumber(), 0.1 * LAMPORTS_PER_SOL);
-      console.log("  Subscription created. Next payment:", s.nextPaymentDue.toNumber());
+      await program.methods.contributeGoal(new BN(contrib), "Matched pledge")
+        .accounts({
+          contributor: fan.publicKey, recipientProfile: creatorProfile,
+          tipGoal: escrowGoal, goalContribution: contribPda(escrowGoal, fan.publicKey),
+          recipientOwner: creator.publicKey,
+          blockedTipper: blockedPda(creatorProfile, fan.publicKey),
+          platformConfig: configPda(), systemProgram: SystemProgram.programId,
+          referral: null,
+          matchingPool: pool, matchRecord: matchRecPda(pool, fan.publicKey),
+        }).signers([fan]).rpc();
+
+      // 50% match is held in escrow with the pledge, not paid to the creator
+      const matched = contrib / 2;
+      let g = await program.account.tipGoal.fetch(escrowGoal);
+      assert.equal(g.escrowBalance.toNumber(), contrib + matched);
+      assert.equal(g.matchEscrow.toNumber(), matched);
+
+      const reclaim = () => program.methods.reclaimGoalMatch()
+        .accounts({ sponsor: sponsor.publicKey, matchingPool: pool, tipGoal: escrowGoal, recipientProfile: creatorProfile })
+        .signers([sponsor]).rpc();
+      try {
+        await reclaim();
+        assert.fail("The goal can still complete");
+      } catch (e) {
+        expect(e.toString()).to.include("GoalNotExpired");
+      }
+
+      await new Promise(resolve => setTimeout(resolve, 4000));
+
+      await program.methods.refundGoalContribution()
+        .accounts({ contributor: fan.publicKey, tipGoal: escrowGoal, recipientProfile: creatorProfile, goalContribution: contribPda(escrowGoal, fan.publicKey) })
+        .signers([fan]).rpc();
+      const poolBalBefore = await provider.connection.getBalance(pool);
+      await reclaim();
+
+      assert.equal(await provider.connection.getBalance(pool) - poolBalBefore, matched);
+      const mp = await program.account.matchingPool.fetch(pool);
+      assert.equal(mp.matchedAmount.toNumber(), 0, "Reclaimed match is back in the pool");
+      g = await program.account.tipGoal.fetch(escrowGoal);
+      assert.equal(g.escrowBalance.toNumber(), 0);
+      assert.equal(g.matchEscrow.toNumber(), 0);
+
+      await program.methods.closeGoal()
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal })
+        .signers([creator]).rpc();
     });
 
-    it("cancels subscription", async () => {
-      await program.methods.cancelSubscription()
+    it("escrowed goal: releases to the vault on completion", async () => {
+      const EID = 101;
+      const escrowGoal = goalPda(creatorProfile, EID);
+      const deadline = Math.floor(Date.now() / 1000) + 86_400;
+
+      await program.methods
+        .createGoal(new BN(EID), {
+          title: "Escrow Win", description: "Will complete", targetAmount: new BN(LAMPORTS_PER_SOL),
+          tokenMint: SystemProgram.programId, deadline: new BN(deadline), escrowed: true,
+        })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal, systemProgram: SystemProgram.programId, platformConfig: configPda() })
+        .signers([creator]).rpc();
+
+      await program.methods.contributeGoal(new BN(LAMPORTS_PER_SOL), "Full funding")
         .accounts({
-          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
-          recipientOwner: creator.publicKey, subscription,
-        }).signers([tipper1]).rpc();
+          contributor: tipper2.publicKey, recipientProfile: creatorProfile,
+          tipGoal: escrowGoal, goalContribution: contribPda(escrowGoal, tipper2.publicKey),
+          recipientOwner: creator.publicKey,
+          blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+          platformConfig: configPda(), systemProgram: SystemProgram.programId,
+          referral: null,
+          matchingPool: null, matchRecord: null,
+        }).signers([tipper2]).rpc();
 
-      // Account is closed after cancellation (rent returned to subscriber)
       try {
-        await program.account.subscription.fetch(subscription);
-        assert.fail("Subscription account should be closed");
+        await program.methods.closeGoal()
+          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal })
+          .signers([creator]).rpc();
+        assert.fail("Cannot close a goal holding escrow");
       } catch (e) {
-        expect(e.toString()).to.include("Account does not exist");
+        expect(e.toString()).to.include("GoalEscrowNotEmpty");
       }
+
+      const vaultBefore = await program.account.vault.fetch(creatorVault);
+      await program.methods.releaseGoalEscrow()
+        .accounts({
+          caller: tipper1.publicKey, tipGoal: escrowGoal, vault: creatorVault,
+          platformConfig: configPda(), platformTreasury: treasuryPda(),
+          tipProfile: creatorProfile, referral: null,
+        }).signers([tipper1]).rpc();
+      const vaultAfter = await program.account.vault.fetch(creatorVault);
+
+      // fee = 1 SOL * 100bps = 0.01 SOL → creator share 0.99 SOL
+      assert.equal(vaultAfter.balance.toNumber() - vaultBefore.balance.toNumber(), 0.99 * LAMPORTS_PER_SOL);
+
+      await program.methods.closeGoal()
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: escrowGoal })
+        .signers([creator]).rpc();
     });
 
-    it("rejects payment on cancelled subscription", async () => {
+    it("rejects escrowed goal without a deadline", async () => {
       try {
-        await program.methods.processSubscription()
-          .accounts({
-            subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
-            recipientOwner: creator.publicKey, subscription,
-            platformConfig: configPda(), platformTreasury: treasuryPda(),
-            systemProgram: SystemProgram.programId,
-          }).signers([tipper1]).rpc();
-        assert.fail("Should reject cancelled subscription payment");
+        await program.methods
+          .createGoal(new BN(102), {
+          title: "No deadline", description: "desc", targetAmount: new BN(LAMPORTS_PER_SOL),
+          tokenMint: SystemProgram.programId, deadline: null, escrowed: true,
+        })
+          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: goalPda(creatorProfile, 102), systemProgram: SystemProgram.programId, platformConfig: configPda() })
+          .signers([creator]).rpc();
+        assert.fail("Escrowed goal must have a deadline");
+      } catch (e) {
+        expect(e.toString()).to.include("EscrowedGoalRequiresDeadline");
+      }
+    });
+
+    it("accepts SPL contributions to a token goal with fee in tokens", async () => {
+      const SID = 103;
+      const splGoal = goalPda(creatorProfile, SID);
+      const contrib = 5_000_000; // 5 USDC
+
+      await program.methods
+        .createGoal(new BN(SID), {
+          title: "USDC Fund", description: "Stablecoin campaign", targetAmount: new BN(10_000_000),
+          tokenMint: mint, deadline: null, escrowed: false,
+        })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: splGoal, systemProgram: SystemProgram.programId, platformConfig: configPda() })
+        .signers([creator]).rpc();
+
+      const feeTA = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, admin, mint, treasuryPda(), true
+      )).address;
+      const creatorBefore = Number((await getAccount(provider.connection, creatorTA)).amount);
+      const feeBefore     = Number((await getAccount(provider.connection, feeTA)).amount);
+
+      await program.methods.contributeGoalSpl(new BN(contrib), "USDC pledge")
+        .accounts({
+          contributor: tipper1.publicKey, contributorTokenAccount: tipperTA,
+          recipientProfile: creatorProfile, recipientOwner: creator.publicKey,
+          tipGoal: splGoal, tokenMint: mint,
+          goalContribution: contribPda(splGoal, tipper1.publicKey),
+          recipientTokenAccount: creatorTA,
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
+          platformConfig: configPda(), platformTreasury: treasuryPda(),
+          platformFeeTokenAccount: feeTA,
+          tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+          referral: null, referrerTokenAccount: null,
+        }).signers([tipper1]).rpc();
+
+      // fee = 5_000_000 * 100bps = 50_000
+      const creatorAfter = Number((await getAccount(provider.connection, creatorTA)).amount);
+      const feeAfter     = Number((await getAccount(provider.connection, feeTA)).amount);
+      assert.equal(creatorAfter - creatorBefore, 4_950_000);
+      assert.equal(feeAfter - feeBefore, 50_000);
+
+      const g = await program.account.tipGoal.fetch(splGoal);
+      assert.equal(g.currentAmount.toNumber(), contrib);
+      assert.equal(g.uniqueContributors, 1);
+
+      // SOL contributions are rejected for a token goal
+      try {
+        await program.methods.contributeGoal(new BN(LAMPORTS_PER_SOL), null)
+          .accounts({
+            contributor: tipper2.publicKey, recipientProfile: creatorProfile,
+            tipGoal: splGoal, recipientOwner: creator.publicKey,
+            blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+            platformConfig: configPda(), systemProgram: SystemProgram.programId,
+            referral: null,
+            matchingPool: null, matchRecord: null,
+          }).signers([tipper2]).rpc();
+        assert.fail("SOL contribution to SPL goal should fail");
+      } catch (e) {
+        expect(e.toString()).to.include("TokenMintMismatch");
+      }
+
+      await program.methods.closeGoal()
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: splGoal })
+        .signers([creator]).rpc();
+    });
+
+    it("enforces max 5 active goals", async () => {
+      // Create goals 2-6 (should succeed, filling count to 5), goal 7 should fail
+      for (let i = 2; i <= 7; i++) {
+        const gp = goalPda(creatorProfile, i);
+        try {
+          await program.methods
+            .createGoal(new BN(i), {
+          title: `Goal ${i}`, description: "desc", targetAmount: new BN(LAMPORTS_PER_SOL),
+          tokenMint: SystemProgram.programId, deadline: null, escrowed: false,
+        })
+            .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: gp, systemProgram: SystemProgram.programId, platformConfig: configPda() })
+            .signers([creator]).rpc();
+
+          if (i === 7) assert.fail("Should fail at 6th active goal");
+          console.log(`  Goal ${i} created`);
+        } catch (e) {
+          if (i === 7) {
+            expect(e.toString()).to.include("MaxActiveGoalsReached");
+            console.log("  6th active goal correctly rejected");
+          } else {
+            throw e;
+          }
+        }
+      }
+    });
+  });
+
+  // ── 6. Subscriptions ─────────────────────────────────────────
+
+  describe("6. Subscriptions", () => {
+    let subscription: PublicKey;
+    const solTier = () => tierPda(creatorProfile, 1);
+    const splTier = () => tierPda(creatorProfile, 2);
+
+    it("creates SOL and SPL subscription tiers", async () => {
+      await program.methods
+        .createSubscriptionTier(new BN(1), {
+          name: "Supporter", price: new BN(0.1 * LAMPORTS_PER_SOL), intervalSeconds: new BN(86400),
+          tokenMint: SystemProgram.programId, maxMembers: 0, benefitsHash: Array(32).fill(1),
+        })
+        .accounts({
+          owner: creator.publicKey, tipProfile: creatorProfile,
+          subscriptionTier: solTier(), systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
+        }).signers([creator]).rpc();
+
+      await program.methods
+        .createSubscriptionTier(new BN(2), {
+          name: "USDC Member", price: new BN(5_000_000), intervalSeconds: new BN(30 * 86400),
+          tokenMint: mint, maxMembers: 1, benefitsHash: Array(32).fill(2),
+        })
+        .accounts({
+          owner: creator.publicKey, tipProfile: creatorProfile,
+          subscriptionTier: splTier(), systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
+        }).signers([creator]).rpc();
+
+      const t = await program.account.subscriptionTier.fetch(solTier());
+      assert.equal(t.name, "Supporter");
+      assert.equal(t.price.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      assert.equal(t.memberCount, 0);
+      assert.isTrue(t.isActive);
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(p.activeTiersCount, 2);
+    });
+
+    it("creates SOL subscription", async () => {
+      subscription = subPda(tipper1.publicKey, creatorProfile);
+      await program.methods
+        .createSubscription()
+        .accounts({
+          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscriptionTier: solTier(), subscription,
+          subscriberTokenAccount: null, tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
+          platformConfig: configPda(),
+        }).signers([tipper1]).rpc();
+
+      const s = await program.account.subscription.fetch(subscription);
+      assert.equal(s.isActive, true);
+      assert.equal(s.isSpl, false);
+      assert.equal(s.amountPerInterval.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      // default 3-day grace is clamped below the 1-day interval
+      assert.equal(s.gracePeriodSeconds.toNumber(), 86400 - 1);
+      assert.deepEqual(s.status, { active: {} });
+      assert.equal(s.missedIntervals, 0);
+      assert.equal(s.tier.toString(), solTier().toString());
+      const t = await program.account.subscriptionTier.fetch(solTier());
+      assert.equal(t.memberCount, 1);
+      console.log("  Subscription created. Next payment:", s.nextPaymentDue.toNumber());
+    });
+
+    it("rejects creator closing a subscription in good standing", async () => {
+      try {
+        await program.methods.closeLapsedSubscription()
+          .accounts({
+            owner: creator.publicKey, recipientProfile: creatorProfile,
+            subscriber: tipper1.publicKey, subscription, subscriptionTier: solTier(),
+          }).signers([creator]).rpc();
+        assert.fail("Only lapsed or ended subscriptions can be closed by the creator");
+      } catch (e) {
+        expect(e.toString()).to.include("SubscriptionNotLapsed");
+      }
+    });
+
+    it("cancels subscription", async () => {
+      await program.methods.cancelSubscription()
+        .accounts({
+          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscription, subscriptionTier: solTier(),
+          subscriberTokenAccount: null, tokenProgram: null,
+        }).signers([tipper1]).rpc();
+
+      // Account is closed after cancellation (rent returned to subscriber)
+      try {
+        await program.account.subscription.fetch(subscription);
+        assert.fail("Subscription account should be closed");
+      } catch (e) {
+        expect(e.toString()).to.include("Account does not exist");
+      }
+    });
+
+    it("rejects payment on cancelled subscription", async () => {
+      try {
+        await program.methods.processSubscription()
+          .accounts({
+            subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+            recipientOwner: creator.publicKey, subscription,
+            platformConfig: configPda(), platformTreasury: treasuryPda(),
+            subscriberTokenAccount: null, recipientTokenAccount: null,
+            platformFeeTokenAccount: null, tokenProgram: null,
+            systemProgram: SystemProgram.programId,
+            referral: null, referrerTokenAccount: null, subscriptionTier: null,
+          }).signers([tipper1]).rpc();
+        assert.fail("Should reject cancelled subscription payment");
       } catch (e) {
         // Account is closed after cancellation - Anchor may report constraint or not-found error
         const errStr = e.toString();
@@ -479,19 +1212,212 @@ describe("SolTip v2 - Full Platform Tests", () => {
       }
     });
 
-    it("rejects subscription below 1-day interval", async () => {
-      const sub2 = subPda(tipper2.publicKey, creatorProfile);
+    it("rejects tier below 1-day interval", async () => {
+      try {
+        await program.methods
+          .createSubscriptionTier(new BN(3), {
+            name: "Hourly", price: new BN(LAMPORTS_PER_SOL), intervalSeconds: new BN(3600), // 1 hour
+            tokenMint: SystemProgram.programId, maxMembers: 0, benefitsHash: Array(32).fill(0),
+          })
+          .accounts({
+            owner: creator.publicKey, tipProfile: creatorProfile,
+            subscriptionTier: tierPda(creatorProfile, 3), systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
+          }).signers([creator]).rpc();
+        assert.fail("Should reject <1 day interval");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidSubscriptionInterval");
+      }
+    });
+
+    it("prefunds a SOL subscription and rejects an early keeper crank", async () => {
+      const keeper = Keypair.generate();
+      await airdrop(keeper.publicKey, 1);
+      const sub = subPda(tipper1.publicKey, creatorProfile);
+
+      await program.methods
+        .createSubscription()
+        .accounts({
+          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscriptionTier: solTier(), subscription: sub,
+          subscriberTokenAccount: null, tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
+          platformConfig: configPda(),
+        }).signers([tipper1]).rpc();
+
+      await program.methods.fundSubscription(new BN(0.3 * LAMPORTS_PER_SOL))
+        .accounts({ subscriber: tipper1.publicKey, subscription: sub, systemProgram: SystemProgram.programId, platformConfig: configPda() })
+        .signers([tipper1]).rpc();
+
+      const s = await program.account.subscription.fetch(sub);
+      assert.equal(s.escrowBalance.toNumber(), 0.3 * LAMPORTS_PER_SOL);
+
+      try {
+        await program.methods.crankSubscription()
+          .accounts({
+            keeper: keeper.publicKey, recipientProfile: creatorProfile,
+            recipientOwner: creator.publicKey, subscription: sub,
+            platformConfig: configPda(), platformTreasury: treasuryPda(),
+            subscriberTokenAccount: null, recipientTokenAccount: null,
+            platformFeeTokenAccount: null, keeperTokenAccount: null, tokenProgram: null,
+            referral: null, referrerTokenAccount: null, subscriptionTier: null,
+          }).signers([keeper]).rpc();
+        assert.fail("Crank should fail before the payment is due");
+      } catch (e) {
+        expect(e.toString()).to.include("SubscriptionNotDue");
+      }
+
+      // Cancelling closes the PDA and returns the unused escrow to the subscriber
+      const balBefore = await provider.connection.getBalance(tipper1.publicKey);
+      await program.methods.cancelSubscription()
+        .accounts({
+          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscription: sub, subscriptionTier: solTier(),
+          subscriberTokenAccount: null, tokenProgram: null,
+        }).signers([tipper1]).rpc();
+      const balAfter = await provider.connection.getBalance(tipper1.publicKey);
+      assert.isTrue(balAfter - balBefore >= 0.3 * LAMPORTS_PER_SOL, "Escrow returned on cancel");
+    });
+
+    it("creates SPL subscription and approves the subscription PDA as delegate", async () => {
+      const splSub = subPda(tipper2.publicKey, creatorProfile);
+      const subTA  = await createAccount(provider.connection, tipper2, mint, tipper2.publicKey);
+      await mintTo(provider.connection, creator, mint, subTA, creator, 100_000_000);
+      const perInterval = 5_000_000; // 5 USDC / month
+
+      await program.methods
+        .createSubscription()
+        .accounts({
+          subscriber: tipper2.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscriptionTier: splTier(), subscription: splSub,
+          subscriberTokenAccount: subTA, tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+          platformConfig: configPda(),
+        }).signers([tipper2]).rpc();
+
+      const s = await program.account.subscription.fetch(splSub);
+      assert.equal(s.isSpl, true);
+      assert.equal(s.tokenMint.toString(), mint.toString());
+
+      const ta = await getAccount(provider.connection, subTA);
+      assert.equal(ta.delegate?.toString(), splSub.toString(), "Subscription PDA should be delegate");
+      assert.equal(Number(ta.delegatedAmount), perInterval * 12);
+
+      // Not yet due: processing must fail before any tokens move
+      try {
+        await program.methods.processSubscription()
+          .accounts({
+            subscriber: tipper2.publicKey, recipientProfile: creatorProfile,
+            recipientOwner: creator.publicKey, subscription: splSub,
+            platformConfig: configPda(), platformTreasury: treasuryPda(),
+            subscriberTokenAccount: subTA, recipientTokenAccount: creatorTA,
+            platformFeeTokenAccount: getAssociatedTokenAddressSync(mint, treasuryPda(), true),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            referral: null, referrerTokenAccount: null, subscriptionTier: null,
+          }).signers([tipper2]).rpc();
+        assert.fail("Payment should not be due yet");
+      } catch (e) {
+        expect(e.toString()).to.include("SubscriptionNotDue");
+      }
+
+      // Cancelling revokes the delegate approval
+      await program.methods.cancelSubscription()
+        .accounts({
+          subscriber: tipper2.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscription: splSub, subscriptionTier: splTier(),
+          subscriberTokenAccount: subTA, tokenProgram: TOKEN_PROGRAM_ID,
+        }).signers([tipper2]).rpc();
+      const after = await getAccount(provider.connection, subTA);
+      assert.isNull(after.delegate, "Delegate should be revoked on cancel");
+    });
+
+    it("rejects joining a full tier", async () => {
+      // USDC tier is capped at one member
+      const subTA1 = await createAccount(provider.connection, tipper1, mint, tipper1.publicKey);
+      const subTA2 = await createAccount(provider.connection, tipper2, mint, tipper2.publicKey);
+      await program.methods.createSubscription()
+        .accounts({
+          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscriptionTier: splTier(),
+          subscription: subPda(tipper1.publicKey, creatorProfile),
+          subscriberTokenAccount: subTA1, tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
+          platformConfig: configPda(),
+        }).signers([tipper1]).rpc();
+
+      try {
+        await program.methods.createSubscription()
+          .accounts({
+            subscriber: tipper2.publicKey, recipientProfile: creatorProfile,
+            recipientOwner: creator.publicKey, subscriptionTier: splTier(),
+            subscription: subPda(tipper2.publicKey, creatorProfile),
+            subscriberTokenAccount: subTA2, tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+            platformConfig: configPda(),
+          }).signers([tipper2]).rpc();
+        assert.fail("Should reject full tier");
+      } catch (e) {
+        expect(e.toString()).to.include("SubscriptionTierFull");
+      }
+
+      await program.methods.cancelSubscription()
+        .accounts({
+          subscriber: tipper1.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, subscriptionTier: splTier(),
+          subscription: subPda(tipper1.publicKey, creatorProfile),
+          subscriberTokenAccount: subTA1, tokenProgram: TOKEN_PROGRAM_ID,
+        }).signers([tipper1]).rpc();
+      const t = await program.account.subscriptionTier.fetch(splTier());
+      assert.equal(t.memberCount, 0);
+    });
+
+    it("updates tier price and rejects non-owner updates", async () => {
+      await program.methods
+        .updateSubscriptionTier(null, new BN(0.2 * LAMPORTS_PER_SOL), null, new BN(100), null)
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, subscriptionTier: solTier(), platformConfig: configPda() })
+        .signers([creator]).rpc();
+      const t = await program.account.subscriptionTier.fetch(solTier());
+      assert.equal(t.price.toNumber(), 0.2 * LAMPORTS_PER_SOL);
+      assert.equal(t.maxMembers, 100);
+
       try {
         await program.methods
-          .createSubscription(new BN(LAMPORTS_PER_SOL), new BN(3600), false, SystemProgram.programId) // 1 hour
+          .updateSubscriptionTier(null, new BN(1), null, null, null)
+          .accounts({ owner: tipper1.publicKey, tipProfile: creatorProfile, subscriptionTier: solTier(), platformConfig: configPda() })
+          .signers([tipper1]).rpc();
+        assert.fail("Should reject non-owner");
+      } catch (e) {
+        // constraint violation expected
+      }
+    });
+
+    it("retires a tier and rejects new members", async () => {
+      await program.methods.retireSubscriptionTier()
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, subscriptionTier: splTier() })
+        .signers([creator]).rpc();
+      const t = await program.account.subscriptionTier.fetch(splTier());
+      assert.isFalse(t.isActive);
+
+      const subTA = await createAccount(provider.connection, tipper2, mint, tipper2.publicKey);
+      try {
+        await program.methods.createSubscription()
           .accounts({
             subscriber: tipper2.publicKey, recipientProfile: creatorProfile,
-            recipientOwner: creator.publicKey, subscription: sub2,
+            recipientOwner: creator.publicKey, subscriptionTier: splTier(),
+            subscription: subPda(tipper2.publicKey, creatorProfile),
+            subscriberTokenAccount: subTA, tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+            platformConfig: configPda(),
           }).signers([tipper2]).rpc();
-        assert.fail("Should reject <1 day interval");
+        assert.fail("Should reject retired tier");
       } catch (e) {
-        expect(e.toString()).to.include("InvalidSubscriptionInterval");
+        expect(e.toString()).to.include("SubscriptionTierNotActive");
       }
     });
   });
@@ -513,8 +1439,9 @@ describe("SolTip v2 - Full Platform Tests", () => {
       await program.methods.withdraw(new BN(withdrawAmt))
         .accounts({
           owner: creator.publicKey, tipProfile: creatorProfile,
-          vault: creatorVault, platformTreasury: treasuryPda(),
-          systemProgram: SystemProgram.programId,
+          vault: creatorVault, platformConfig: configPda(),
+          platformTreasury: treasuryPda(), systemProgram: SystemProgram.programId,
+          referral: null,
         }).signers([creator]).rpc();
 
       const ownerAfter = await provider.connection.getBalance(creator.publicKey);
@@ -529,8 +1456,9 @@ describe("SolTip v2 - Full Platform Tests", () => {
         await program.methods.withdraw(new BN(1_000)) // way below 0.01 SOL minimum
           .accounts({
             owner: creator.publicKey, tipProfile: creatorProfile,
-            vault: creatorVault, platformTreasury: treasuryPda(),
-            systemProgram: SystemProgram.programId,
+            vault: creatorVault, platformConfig: configPda(),
+            platformTreasury: treasuryPda(), systemProgram: SystemProgram.programId,
+            referral: null,
           }).signers([creator]).rpc();
         assert.fail("Should reject below-minimum withdrawal");
       } catch (e) {
@@ -543,14 +1471,82 @@ describe("SolTip v2 - Full Platform Tests", () => {
         await program.methods.withdraw(new BN(10_000_000))
           .accounts({
             owner: tipper1.publicKey, tipProfile: creatorProfile,
-            vault: creatorVault, platformTreasury: treasuryPda(),
-            systemProgram: SystemProgram.programId,
+            vault: creatorVault, platformConfig: configPda(),
+            platformTreasury: treasuryPda(), systemProgram: SystemProgram.programId,
+            referral: null,
           }).signers([tipper1]).rpc();
         assert.fail("Should reject unauthorized withdrawal");
       } catch (e) {
         // constraint violation expected
       }
     });
+
+    it("refunds a tip inside the window and reverses stats and leaderboard", async () => {
+      const fan = Keypair.generate();
+      await airdrop(fan.publicKey, 2);
+      const amount = 0.2 * LAMPORTS_PER_SOL;
+      await program.methods.sendTip(new BN(amount), "oops, wrong amount", null, null)
+        .accounts({
+          tipper: fan.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(fan.publicKey, creatorProfile),
+          rateLimit: rlPda(fan.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, fan.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
+        }).signers([fan]).rpc();
+
+      const pBefore = await program.account.tipProfile.fetch(creatorProfile);
+      const vBefore = await program.account.vault.fetch(creatorVault);
+      const fanBefore = await provider.connection.getBalance(fan.publicKey);
+      const refund = (signer: Keypair, amt: number) => program.methods.refundTip(new BN(amt))
+        .accounts({
+          owner: signer.publicKey, tipProfile: creatorProfile, vault: creatorVault,
+          tipperRecord: trPda(fan.publicKey, creatorProfile), tipper: fan.publicKey,
+        }).signers([signer]).rpc();
+
+      try {
+        await refund(tipper1, amount);
+        assert.fail("Only the creator can refund");
+      } catch (e) {
+        // constraint violation expected
+      }
+      try {
+        await refund(creator, amount + 1);
+        assert.fail("Cannot refund more than was tipped");
+      } catch (e: any) {
+        assert.include(e.toString(), "RefundExceedsTipped");
+      }
+
+      const setWindow = (seconds: number) => program.methods
+        .updateProfileExtended(extParams({ refundWindowSeconds: new BN(seconds) }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+        .signers([creator]).rpc();
+
+      await refund(creator, amount / 2);
+      await setWindow(0);
+      try {
+        await refund(creator, amount / 2);
+        assert.fail("Refunds are off");
+      } catch (e: any) {
+        assert.include(e.toString(), "RefundWindowExpired");
+      }
+      await setWindow(7 * 86400);
+      await refund(creator, amount / 2);
+
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      const v = await program.account.vault.fetch(creatorVault);
+      const tr = await program.account.tipperRecord.fetch(trPda(fan.publicKey, creatorProfile));
+      assert.equal(await provider.connection.getBalance(fan.publicKey) - fanBefore, amount);
+      assert.equal(vBefore.balance.toNumber() - v.balance.toNumber(), amount);
+      assert.equal(pBefore.totalAmountReceivedLamports.toNumber() - p.totalAmountReceivedLamports.toNumber(), amount);
+      assert.equal(pBefore.totalUniqueTippers - p.totalUniqueTippers, 1);
+      assert.equal(tr.totalAmount.toNumber(), 0);
+      assert.equal(tr.tipCount, 0);
+      assert.equal(tr.refundableAmount.toNumber(), 0);
+      assert.isFalse(p.topTippers.some((e: any) => e.tipper.equals(fan.publicKey)));
+    });
   });
 
   // ── 8. Admin & Platform Config ────────────────────────────────
@@ -603,6 +1599,138 @@ describe("SolTip v2 - Full Platform Tests", () => {
         // constraint (has_one) violation expected
       }
     });
+
+    it("updates the platform fee within bounds", async () => {
+      await program.methods.updatePlatformConfig(200, null)
+        .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+        .signers([admin]).rpc();
+
+      let cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.platformFeeBps, 200);
+      assert.equal(cfg.treasury.toString(), treasuryPda().toString());
+
+      try {
+        await program.methods.updatePlatformConfig(1_001, null)
+          .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+          .signers([admin]).rpc();
+        assert.fail("Should reject fee above maximum");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidPlatformFee");
+      }
+
+      try {
+        await program.methods.updatePlatformConfig(null, PublicKey.default)
+          .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+          .signers([admin]).rpc();
+        assert.fail("Should reject default treasury");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidTreasury");
+      }
+
+      // Restore the default so later fee assertions hold
+      await program.methods.updatePlatformConfig(100, null)
+        .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+        .signers([admin]).rpc();
+      cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.platformFeeBps, 100);
+    });
+
+    it("rejects non-admin config update", async () => {
+      try {
+        await program.methods.updatePlatformConfig(0, null)
+          .accounts({ authority: tipper1.publicKey, platformConfig: configPda() })
+          .signers([tipper1]).rpc();
+        assert.fail("Should reject non-admin");
+      } catch (e) {
+        expect(e.toString()).to.include("NotAdmin");
+      }
+    });
+
+    it("transfers authority in two steps", async () => {
+      await program.methods.proposeAuthority(tipper2.publicKey)
+        .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+        .signers([admin]).rpc();
+
+      let cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.authority.toString(), admin.publicKey.toString());
+      assert.equal(cfg.pendingAuthority.toString(), tipper2.publicKey.toString());
+
+      try {
+        await program.methods.acceptAuthority()
+          .accounts({ newAuthority: tipper1.publicKey, platformConfig: configPda() })
+          .signers([tipper1]).rpc();
+        assert.fail("Should reject acceptance by a non-nominee");
+      } catch (e) {
+        expect(e.toString()).to.include("NotPendingAuthority");
+      }
+
+      await program.methods.acceptAuthority()
+        .accounts({ newAuthority: tipper2.publicKey, platformConfig: configPda() })
+        .signers([tipper2]).rpc();
+      cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.authority.toString(), tipper2.publicKey.toString());
+      assert.equal(cfg.pendingAuthority.toString(), PublicKey.default.toString());
+
+      // Hand authority back to admin for the remaining suites
+      await program.methods.proposeAuthority(admin.publicKey)
+        .accounts({ authority: tipper2.publicKey, platformConfig: configPda() })
+        .signers([tipper2]).rpc();
+      await program.methods.acceptAuthority()
+        .accounts({ newAuthority: admin.publicKey, platformConfig: configPda() })
+        .signers([admin]).rpc();
+      cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.authority.toString(), admin.publicKey.toString());
+    });
+
+    it("toggles features at runtime", async () => {
+      const FEATURE_GOALS = 1 << 1;
+
+      await program.methods.setFeatureFlags(FEATURE_GOALS, false)
+        .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+        .signers([admin]).rpc();
+      let cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.disabledFeatures, FEATURE_GOALS);
+
+      try {
+        await program.methods
+          .createGoal(new BN(200), {
+          title: "Disabled", description: "desc", targetAmount: new BN(LAMPORTS_PER_SOL),
+          tokenMint: SystemProgram.programId, deadline: null, escrowed: false,
+        })
+          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, tipGoal: goalPda(creatorProfile, 200), systemProgram: SystemProgram.programId, platformConfig: configPda() })
+          .signers([creator]).rpc();
+        assert.fail("Should reject while goals are disabled");
+      } catch (e) {
+        expect(e.toString()).to.include("GoalsDisabled");
+      } finally {
+        await program.methods.setFeatureFlags(FEATURE_GOALS, true)
+          .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+          .signers([admin]).rpc();
+      }
+
+      cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.disabledFeatures, 0);
+    });
+
+    it("rejects unknown feature flags and non-admin toggles", async () => {
+      try {
+        await program.methods.setFeatureFlags(1 << 20, false)
+          .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+          .signers([admin]).rpc();
+        assert.fail("Should reject unknown flag");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidFeatureFlag");
+      }
+
+      try {
+        await program.methods.setFeatureFlags(1, false)
+          .accounts({ authority: tipper1.publicKey, platformConfig: configPda() })
+          .signers([tipper1]).rpc();
+        assert.fail("Should reject non-admin");
+      } catch (e) {
+        expect(e.toString()).to.include("NotAdmin");
+      }
+    });
   });
 
   // ── 8b. SPL Withdrawal ────────────────────────────────────────
@@ -611,61 +1739,64 @@ describe("SolTip v2 - Full Platform Tests", () => {
     let platformFeeTA: PublicKey; // platform treasury token account
 
     before(async () => {
-      // Create a token account to receive the platform fee (owned by admin)
-      platformFeeTA = await createAccount(
-        provider.connection, admin, mint, admin.publicKey
-      );
-    });
-
-    it("deducts platform fee on SPL withdrawal", async () => {
-      const creatorTABefore = await getAccount(provider.connection, creatorTA);
+      // Platform fee token account must be owned by the treasury PDA
+      platformFeeTA = (await getOrCreateAssociatedTokenAccount(
+        provider.connection, admin, mint, treasuryPda(), true
+      )).address;
+    });
+
+    const withdrawAccounts = (owner: PublicKey) => ({
+      owner,
+      tipProfile:              creatorProfile,
+      tokenMint:               mint,
+      splVault:                creatorSplVault,
+      vaultTokenAccount:       splVaultTA,
+      creatorTokenAccount:     creatorTA,
+      platformConfig:          configPda(),
+      platformTreasury:        treasuryPda(),
+      platformFeeTokenAccount: platformFeeTA,
+      referral:                null,
+      referrerTokenAccount:    null,
+      tokenProgram:            TOKEN_PROGRAM_ID,
+    });
+
+    it("withdraws from the SPL vault with fee split", async () => {
+      const creatorTABefore  = await getAccount(provider.connection, creatorTA);
       const platformTABefore = await getAccount(provider.connection, platformFeeTA);
-      const creatorBalance = Number(creatorTABefore.amount);
-      console.log("  Creator SPL balance before withdrawal:", creatorBalance);
+      const vaultBefore      = await program.account.splVault.fetch(creatorSplVault);
 
       // Use MIN_WITHDRAWAL_AMOUNT as the test amount
       const withdrawAmount = 10_000_000;
-      if (creatorBalance < withdrawAmount) {
-        console.log("  Insufficient SPL balance – skipping withdrawal test");
-        return;
-      }
 
       // fee = withdrawAmount * 200bps / 10000 = 200_000
       // platform_fee = 200_000 * 100bps / 10000 = 2_000 units
+      // creator_share = 10_000_000 - 200_000 = 9_800_000 units
       await program.methods
         .withdrawSpl(new BN(withdrawAmount))
-        .accounts({
-          owner:                    creator.publicKey,
-          tipProfile:               creatorProfile,
-          creatorTokenAccount:      creatorTA,
-          platformFeeTokenAccount:  platformFeeTA,
-          tokenProgram:             TOKEN_PROGRAM_ID,
-        })
+        .accounts(withdrawAccounts(creator.publicKey))
         .signers([creator]).rpc();
 
-      const creatorTAAfter   = await getAccount(provider.connection, creatorTA);
-      const platformTAAfter  = await getAccount(provider.connection, platformFeeTA);
+      const creatorTAAfter  = await getAccount(provider.connection, creatorTA);
+      const platformTAAfter = await getAccount(provider.connection, platformFeeTA);
+      const vaultAfter      = await program.account.splVault.fetch(creatorSplVault);
 
       const platformGain = Number(platformTAAfter.amount) - Number(platformTABefore.amount);
-      const creatorLoss  = Number(creatorTABefore.amount) - Number(creatorTAAfter.amount);
+      const creatorGain  = Number(creatorTAAfter.amount) - Number(creatorTABefore.amount);
+      const vaultDebit   = vaultBefore.balance.toNumber() - vaultAfter.balance.toNumber();
 
-      console.log(`  SPL withdrawal: ${withdrawAmount} units | platform fee taken: ${platformGain} | creator loss: ${creatorLoss}`);
+      console.log(`  SPL withdrawal: ${withdrawAmount} units | platform fee: ${platformGain} | creator net: ${creatorGain}`);
 
-      assert.equal(platformGain, 2_000, "Platform should receive 2000 token units");
-      assert.equal(creatorLoss, 2_000,  "Creator loses exactly the platform fee");
+      assert.equal(platformGain, 2_000,     "Platform should receive 2000 token units");
+      assert.equal(creatorGain,  9_800_000, "Creator receives amount minus total fee");
+      assert.equal(vaultDebit,   9_802_000, "Vault debits creator share + platform fee");
+      assert.equal(vaultAfter.totalWithdrawn.toNumber(), 9_802_000);
     });
 
     it("rejects SPL withdrawal below minimum", async () => {
       try {
         await program.methods
-          .withdrawSpl(new BN(1)) // 1 lamport = below minimum
-          .accounts({
-            owner:                   creator.publicKey,
-            tipProfile:              creatorProfile,
-            creatorTokenAccount:     creatorTA,
-            platformFeeTokenAccount: platformFeeTA,
-            tokenProgram:            TOKEN_PROGRAM_ID,
-          })
+          .withdrawSpl(new BN(1)) // 1 unit = below minimum
+          .accounts(withdrawAccounts(creator.publicKey))
           .signers([creator]).rpc();
         assert.fail("Should reject below-minimum withdrawal");
       } catch (e) {
@@ -673,23 +1804,46 @@ describe("SolTip v2 - Full Platform Tests", () => {
       }
     });
 
+    it("rejects SPL withdrawal exceeding vault balance", async () => {
+      try {
+        await program.methods
+          .withdrawSpl(new BN(100_000_000))
+          .accounts(withdrawAccounts(creator.publicKey))
+          .signers([creator]).rpc();
+        assert.fail("Should reject overdraw");
+      } catch (e) {
+        expect(e.toString()).to.include("InsufficientTokenBalance");
+      }
+    });
+
     it("rejects SPL withdrawal by non-owner", async () => {
       try {
         await program.methods
           .withdrawSpl(new BN(10_000_000))
-          .accounts({
-            owner:                   tipper1.publicKey, // wrong signer
-            tipProfile:              creatorProfile,
-            creatorTokenAccount:     creatorTA,
-            platformFeeTokenAccount: platformFeeTA,
-            tokenProgram:            TOKEN_PROGRAM_ID,
-          })
+          .accounts(withdrawAccounts(tipper1.publicKey)) // wrong signer
           .signers([tipper1]).rpc();
         assert.fail("Should reject non-owner");
       } catch (e) {
         // has_one constraint expects NotProfileOwner
       }
     });
+
+    it("lets the platform authority withdraw SPL fees from the treasury", async () => {
+      const adminTA = await createAccount(provider.connection, admin, mint, admin.publicKey);
+      const feeBefore = Number((await getAccount(provider.connection, platformFeeTA)).amount);
+
+      await program.methods
+        .withdrawTreasurySpl(new BN(feeBefore))
+        .accounts({
+          authority: admin.publicKey, platformConfig: configPda(),
+          platformTreasury: treasuryPda(), treasuryTokenAccount: platformFeeTA,
+          destinationTokenAccount: adminTA, tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin]).rpc();
+
+      assert.equal(Number((await getAccount(provider.connection, platformFeeTA)).amount), 0);
+      assert.equal(Number((await getAccount(provider.connection, adminTA)).amount), feeBefore);
+    });
   });
 
   // ── 9. Mid-suite Statistics ──────────────────────────────────
@@ -717,14 +1871,16 @@ describe("SolTip v2 - Full Platform Tests", () => {
       await airdrop(tipper3.publicKey);
 
       try {
-        await program.methods.sendTip(new BN(LAMPORTS_PER_SOL), "Paused tip")
+        await program.methods.sendTip(new BN(LAMPORTS_PER_SOL), "Paused tip", null, null)
           .accounts({
             tipper: tipper3.publicKey, recipientProfile: creatorProfile,
             recipientOwner: creator.publicKey, vault: creatorVault,
             tipperRecord: trPda(tipper3.publicKey, creatorProfile),
             rateLimit: rlPda(tipper3.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, tipper3.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
+            matchingPool: null, matchRecord: null, pinnedSlot: null,
           }).signers([tipper3]).rpc();
         assert.fail("Should reject tip while platform is paused");
       } catch (e) {
@@ -750,12 +1906,13 @@ describe("SolTip v2 - Full Platform Tests", () => {
         [Buffer.from("tip_goal"), creatorProfile.toBuffer(), goalId2.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
-      await program.methods.createGoal(
-        goalId2, "Pause Test Goal", "Testing pause", new BN(5 * LAMPORTS_PER_SOL),
-        SystemProgram.programId, null
-      ).accounts({
+      await program.methods.createGoal(goalId2, {
+        title: "Pause Test Goal", description: "Testing pause", targetAmount: new BN(5 * LAMPORTS_PER_SOL),
+        tokenMint: SystemProgram.programId, deadline: null, escrowed: false,
+      }).accounts({
         owner: creator.publicKey, tipProfile: creatorProfile,
         tipGoal: goal2, systemProgram: SystemProgram.programId,
+        platformConfig: configPda(),
       }).signers([creator]).rpc();
 
       // Pause platform
@@ -768,7 +1925,10 @@ describe("SolTip v2 - Full Platform Tests", () => {
           .accounts({
             contributor: tipper1.publicKey, recipientProfile: creatorProfile,
             tipGoal: goal2, recipientOwner: creator.publicKey,
+            blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
             platformConfig: configPda(), systemProgram: SystemProgram.programId,
+            referral: null,
+            matchingPool: null, matchRecord: null,
           }).signers([tipper1]).rpc();
         assert.fail("Should reject goal contribution while paused");
       } catch (e) {
@@ -787,21 +1947,23 @@ describe("SolTip v2 - Full Platform Tests", () => {
     it("rejects messageless tip when accept_anonymous is false", async () => {
       // Disable anonymous tips on the creator's profile
       await program.methods.updateProfile(null, null, null, null, null, false)
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const tipper4 = Keypair.generate();
       await airdrop(tipper4.publicKey);
 
       try {
-        await program.methods.sendTip(new BN(LAMPORTS_PER_SOL), null) // null message = anonymous
+        await program.methods.sendTip(new BN(LAMPORTS_PER_SOL), null, null, null) // null message = anonymous
           .accounts({
             tipper: tipper4.publicKey, recipientProfile: creatorProfile,
             recipientOwner: creator.publicKey, vault: creatorVault,
             tipperRecord: trPda(tipper4.publicKey, creatorProfile),
             rateLimit: rlPda(tipper4.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, tipper4.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
+            matchingPool: null, matchRecord: null, pinnedSlot: null,
           }).signers([tipper4]).rpc();
         assert.fail("Should reject anonymous tip when disabled");
       } catch (e) {
@@ -809,7 +1971,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
       } finally {
         // Re-enable anonymous tips
         await program.methods.updateProfile(null, null, null, null, null, true)
-          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
           .signers([creator]).rpc();
       }
     });
@@ -820,12 +1982,10 @@ describe("SolTip v2 - Full Platform Tests", () => {
   describe("12. Update Profile Extended", () => {
     it("sets preset tip amounts", async () => {
       await program.methods
-        .updateProfileExtended(
-          [new BN(LAMPORTS_PER_SOL * 0.1), new BN(LAMPORTS_PER_SOL * 0.5), new BN(LAMPORTS_PER_SOL)],
-          null,
-          null
-        )
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+        .updateProfileExtended(extParams({
+          presetAmounts: [new BN(LAMPORTS_PER_SOL * 0.1), new BN(LAMPORTS_PER_SOL * 0.5), new BN(LAMPORTS_PER_SOL)],
+        }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -837,12 +1997,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
 
     it("sets social links", async () => {
       await program.methods
-        .updateProfileExtended(
-          null,
-          "twitter:@soltip,discord:soltip#1234",
-          null
-        )
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+        .updateProfileExtended(extParams({ socialLinks: "twitter:@soltip,discord:soltip#1234" }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -851,12 +2007,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
 
     it("sets webhook URL", async () => {
       await program.methods
-        .updateProfileExtended(
-          null,
-          null,
-          "https://webhook.example.com/tips"
-        )
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+        .updateProfileExtended(extParams({ webhookUrl: "https://webhook.example.com/tips" }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -866,8 +2018,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
     it("rejects unauthorized extended update", async () => {
       try {
         await program.methods
-          .updateProfileExtended([new BN(1000)], null, null)
-          .accounts({ owner: tipper1.publicKey, tipProfile: creatorProfile })
+          .updateProfileExtended(extParams({ presetAmounts: [new BN(1000)] }))
+          .accounts({ owner: tipper1.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
           .signers([tipper1]).rpc();
         assert.fail("Should reject unauthorized");
       } catch (e) {
@@ -877,12 +2029,12 @@ describe("SolTip v2 - Full Platform Tests", () => {
 
     it("updates all fields at once", async () => {
       await program.methods
-        .updateProfileExtended(
-          [new BN(LAMPORTS_PER_SOL * 0.25), new BN(LAMPORTS_PER_SOL * 2)],
-          "twitch:soltip_live",
-          "https://hooks.example.com/new"
-        )
-        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile })
+        .updateProfileExtended(extParams({
+          presetAmounts: [new BN(LAMPORTS_PER_SOL * 0.25), new BN(LAMPORTS_PER_SOL * 2)],
+          socialLinks: "twitch:soltip_live",
+          webhookUrl: "https://hooks.example.com/new",
+        }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
         .signers([creator]).rpc();
 
       const p = await program.account.tipProfile.fetch(creatorProfile);
@@ -890,6 +2042,214 @@ describe("SolTip v2 - Full Platform Tests", () => {
       assert.equal(p.socialLinks, "twitch:soltip_live");
       assert.equal(p.webhookUrl, "https://hooks.example.com/new");
     });
+
+    it("sets subscription grace period and back-charge policy", async () => {
+      await program.methods
+        .updateProfileExtended(extParams({ subscriptionGraceSeconds: new BN(86400), subscriptionBackcharge: true }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+        .signers([creator]).rpc();
+
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(p.subscriptionGraceSeconds.toNumber(), 86400);
+      assert.isTrue(p.subscriptionBackcharge);
+    });
+
+    it("rejects grace period above 14 days", async () => {
+      try {
+        await program.methods
+          .updateProfileExtended(extParams({ subscriptionGraceSeconds: new BN(15 * 86400) }))
+          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+          .signers([creator]).rpc();
+        assert.fail("Should reject grace period");
+      } catch (e: any) {
+        assert.include(e.toString(), "InvalidGracePeriod");
+      }
+    });
+
+    it("enforces creator rate limit settings on tips", async () => {
+      const settings = {
+        tipCooldownSeconds: 60,
+        maxTipsPerDay: 10,
+        maxTipAmount: new BN(LAMPORTS_PER_SOL),
+        minFirstTipAmount: new BN(0.05 * LAMPORTS_PER_SOL),
+      };
+      await program.methods
+        .updateProfileExtended(extParams({ rateLimitSettings: settings }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+        .signers([creator]).rpc();
+
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(p.rateLimitSettings.tipCooldownSeconds, 60);
+      assert.equal(p.rateLimitSettings.maxTipsPerDay, 10);
+
+      const newcomer = Keypair.generate();
+      await airdrop(newcomer.publicKey, 5);
+      const tip = (amount: number) => program.methods.sendTip(new BN(amount), "hi", null, null)
+        .accounts({
+          tipper: newcomer.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(newcomer.publicKey, creatorProfile),
+          rateLimit: rlPda(newcomer.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, newcomer.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
+        }).signers([newcomer]).rpc();
+
+      try {
+        await tip(0.01 * LAMPORTS_PER_SOL);
+        assert.fail("First tip below the creator's minimum");
+      } catch (e) {
+        expect(e.toString()).to.include("FirstTipTooSmall");
+      }
+      try {
+        await tip(2 * LAMPORTS_PER_SOL);
+        assert.fail("Tip above the creator's maximum");
+      } catch (e) {
+        expect(e.toString()).to.include("TipAboveCreatorMaximum");
+      }
+
+      await tip(0.05 * LAMPORTS_PER_SOL);
+      try {
+        await tip(0.01 * LAMPORTS_PER_SOL);
+        assert.fail("Second tip inside the 60 s cooldown");
+      } catch (e) {
+        expect(e.toString()).to.include("RateLimitExceeded");
+      }
+    });
+
+    it("rejects rate limit settings looser than the platform defaults", async () => {
+      try {
+        await program.methods
+          .updateProfileExtended(extParams({
+            rateLimitSettings: {
+              tipCooldownSeconds: 0, maxTipsPerDay: 1000,
+              maxTipAmount: new BN(0), minFirstTipAmount: new BN(0),
+            },
+          }))
+          .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+          .signers([creator]).rpc();
+        assert.fail("Daily cap above the platform maximum");
+      } catch (e: any) {
+        assert.include(e.toString(), "InvalidRateLimitSettings");
+      }
+
+      // Back to platform defaults for the remaining suites
+      await program.methods
+        .updateProfileExtended(extParams({
+          rateLimitSettings: {
+            tipCooldownSeconds: 0, maxTipsPerDay: 0,
+            maxTipAmount: new BN(0), minFirstTipAmount: new BN(0),
+          },
+        }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+        .signers([creator]).rpc();
+    });
+
+    it("gates media tips behind the creator's media minimum", async () => {
+      const viewer = Keypair.generate();
+      await airdrop(viewer.publicKey, 5);
+      const clip = { kind: { youtubeClip: {} }, url: "https://youtu.be/dQw4w9WgXcQ", startSeconds: 42 };
+      const tip = (amount: number, media: any) => program.methods
+        .sendTip(new BN(amount), "clip!", media, null)
+        .accounts({
+          tipper: viewer.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(viewer.publicKey, creatorProfile),
+          rateLimit: rlPda(viewer.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, viewer.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
+        }).signers([viewer]).rpc();
+
+      try {
+        await tip(LAMPORTS_PER_SOL, clip);
+        assert.fail("Media tips are off until the creator sets a minimum");
+      } catch (e) {
+        expect(e.toString()).to.include("MediaTipsDisabled");
+      }
+
+      await program.methods
+        .updateProfileExtended(extParams({ minMediaTipAmount: new BN(0.5 * LAMPORTS_PER_SOL) }))
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+        .signers([creator]).rpc();
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(p.minMediaTipAmount.toNumber(), 0.5 * LAMPORTS_PER_SOL);
+
+      try {
+        await tip(0.1 * LAMPORTS_PER_SOL, clip);
+        assert.fail("Below the media minimum");
+      } catch (e) {
+        expect(e.toString()).to.include("TipBelowMediaMinimum");
+      }
+      try {
+        await tip(LAMPORTS_PER_SOL, { kind: { image: {} }, url: "http://example.com/a.png", startSeconds: 0 });
+        assert.fail("Non-https media URL");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidMediaUrl");
+      }
+      try {
+        await tip(LAMPORTS_PER_SOL, { kind: { gif: {} }, url: "https://example.com/a.gif", startSeconds: 5 });
+        assert.fail("Start time on a GIF");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidMediaStartTime");
+      }
+
+      await tip(LAMPORTS_PER_SOL, clip);
+    });
+
+    it("accepts sealed messages only once the creator publishes a key", async () => {
+      const supporter = Keypair.generate();
+      await airdrop(supporter.publicKey, 5);
+      // The program only checks sizes; real clients seal with NaCl box
+      const sealed = {
+        ephemeralPubkey: Array.from(randomBytes(32)),
+        nonce: Array.from(randomBytes(24)),
+        ciphertext: Buffer.from(randomBytes(64)),
+      };
+      const tip = (message: string | null, encrypted: any) => program.methods
+        .sendTip(new BN(0.1 * LAMPORTS_PER_SOL), message, null, encrypted)
+        .accounts({
+          tipper: supporter.publicKey, recipientProfile: creatorProfile,
+          recipientOwner: creator.publicKey, vault: creatorVault,
+          tipperRecord: trPda(supporter.publicKey, creatorProfile),
+          rateLimit: rlPda(supporter.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, supporter.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
+        }).signers([supporter]).rpc();
+
+      try {
+        await tip(null, sealed);
+        assert.fail("No encryption key published yet");
+      } catch (e) {
+        expect(e.toString()).to.include("EncryptionKeyNotSet");
+      }
+
+      const key = Array.from(randomBytes(32));
+      await program.methods.setEncryptionKey(key)
+        .accounts({ owner: creator.publicKey, tipProfile: creatorProfile, platformConfig: configPda() })
+        .signers([creator]).rpc();
+      const p = await program.account.tipProfile.fetch(creatorProfile);
+      assert.deepEqual(Array.from(p.encryptionPubkey), key);
+
+      try {
+        await tip("also public", sealed);
+        assert.fail("Plaintext and sealed message together");
+      } catch (e) {
+        expect(e.toString()).to.include("ConflictingTipMessages");
+      }
+      try {
+        await tip(null, { ...sealed, ciphertext: Buffer.from(randomBytes(400)) });
+        assert.fail("Ciphertext above the size limit");
+      } catch (e) {
+        expect(e.toString()).to.include("InvalidEncryptedMessage");
+      }
+
+      await tip(null, sealed);
+    });
   });
 
   // ── 13. Polls ──────────────────────────────────────────────────
@@ -913,6 +2273,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
           tipProfile: creatorProfile,
           tipPoll,
           systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
         })
         .signers([creator]).rpc();
 
@@ -940,6 +2301,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
           profileOwner: creator.publicKey,
           vault: creatorVault,
           tipPoll,
+          rateLimit: rlPda(tipper1.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
           platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
         })
@@ -966,6 +2329,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
           profileOwner: creator.publicKey,
           vault: creatorVault,
           tipPoll,
+          rateLimit: rlPda(tipper2.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
           platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
         })
@@ -988,6 +2353,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
             profileOwner: creator.publicKey,
             vault: creatorVault,
             tipPoll,
+            rateLimit: rlPda(voter3.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, voter3.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
           })
@@ -1008,6 +2375,8 @@ describe("SolTip v2 - Full Platform Tests", () => {
             profileOwner: creator.publicKey,
             vault: creatorVault,
             tipPoll,
+            rateLimit: rlPda(creator.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, creator.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
           })
@@ -1055,6 +2424,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             tipProfile: creatorProfile,
             tipPoll: pp,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
           .signers([creator]).rpc();
       }
@@ -1069,6 +2439,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             tipProfile: creatorProfile,
             tipPoll: pp4,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
           .signers([creator]).rpc();
         assert.fail("Should reject 4th poll");
@@ -1095,6 +2466,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             tipProfile: creatorProfile,
             tipPoll: pp,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
           .signers([creator]).rpc();
         assert.fail("Should reject single option poll");
@@ -1134,6 +2506,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
           tipProfile: creatorProfile,
           contentGate,
           systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
         })
         .signers([creator]).rpc();
 
@@ -1154,8 +2527,12 @@ describe("SolTip v2 - Full Platform Tests", () => {
       // Send a tip first to create/update the record
       // Wait for rate limit cooldown
       await new Promise(resolve => setTimeout(resolve, 4000));
+      // tipper1's record predates this suite's refunds; it must still be at
+      // the original 149-byte layout so init_if_needed accepts it
+      const trInfo = await provider.connection.getAccountInfo(trPda(tipper1.publicKey, creatorProfile));
+      assert.equal(trInfo!.data.length, 149, "TipperRecord size must not change");
       const tipAmount = 0.6 * LAMPORTS_PER_SOL; // above the 0.5 SOL gate requirement
-      await program.methods.sendTip(new BN(tipAmount), "Tip for gate access")
+      await program.methods.sendTip(new BN(tipAmount), "Tip for gate access", null, null)
         .accounts({
           tipper: tipper1.publicKey,
           recipientProfile: creatorProfile,
@@ -1163,8 +2540,10 @@ describe("SolTip v2 - Full Platform Tests", () => {
           vault: creatorVault,
           tipperRecord: trPda(tipper1.publicKey, creatorProfile),
           rateLimit: rlPda(tipper1.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, tipper1.publicKey),
           platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
         }).signers([tipper1]).rpc();
 
       const tr = trPda(tipper1.publicKey, creatorProfile);
@@ -1175,7 +2554,9 @@ describe("SolTip v2 - Full Platform Tests", () => {
           recipientProfile: creatorProfile,
           profileOwner: creator.publicKey,
           tipperRecord: tr,
+          subscription: null,
           contentGate,
+          platformConfig: configPda(),
         })
         .signers([tipper1]).rpc();
 
@@ -1193,7 +2574,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
       // Wait for rate limit cooldown
       await new Promise(resolve => setTimeout(resolve, 4000));
       const smallAmount = 1000; // minimum tip
-      await program.methods.sendTip(new BN(smallAmount), "tiny tip")
+      await program.methods.sendTip(new BN(smallAmount), "tiny tip", null, null)
         .accounts({
           tipper: newTipper.publicKey,
           recipientProfile: creatorProfile,
@@ -1201,8 +2582,10 @@ describe("SolTip v2 - Full Platform Tests", () => {
           vault: creatorVault,
           tipperRecord: trPda(newTipper.publicKey, creatorProfile),
           rateLimit: rlPda(newTipper.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, newTipper.publicKey),
           platformConfig: configPda(),
           systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
         }).signers([newTipper]).rpc();
 
       try {
@@ -1213,7 +2596,9 @@ describe("SolTip v2 - Full Platform Tests", () => {
             recipientProfile: creatorProfile,
             profileOwner: creator.publicKey,
             tipperRecord: trPda(newTipper.publicKey, creatorProfile),
+            subscription: null,
             contentGate,
+            platformConfig: configPda(),
           })
           .signers([newTipper]).rpc();
         assert.fail("Should reject insufficient tipper");
@@ -1257,6 +2642,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             tipProfile: creatorProfile,
             contentGate: gp,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
           .signers([tipper1]).rpc();
         assert.fail("Should reject unauthorized");
@@ -1279,6 +2665,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
         .accounts({
           owner: tipper1.publicKey,
           tipProfile: tipper1Profile,
+          usernameRegistry: usernamePda("tipper_one"),
           systemProgram: SystemProgram.programId,
         })
         .signers([tipper1]).rpc();
@@ -1298,8 +2685,9 @@ describe("SolTip v2 - Full Platform Tests", () => {
           referral,
           owner: tipper1.publicKey,
           systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
         })
-        .signers([tipper1]).rpc();
+        .signers([tipper1, creator]).rpc();
 
       const ref = await program.account.referral.fetch(referral);
       assert.equal(ref.referrer.toString(), tipper1.publicKey.toString());
@@ -1323,8 +2711,9 @@ describe("SolTip v2 - Full Platform Tests", () => {
             referral,
             owner: tipper1.publicKey,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
-          .signers([tipper1]).rpc();
+          .signers([tipper1, creator]).rpc();
         assert.fail("Should reject duplicate referral");
       } catch (e) {
         // already initialized
@@ -1344,6 +2733,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             referral: selfRef,
             owner: creator.publicKey,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
           .signers([creator]).rpc();
         assert.fail("Should reject self-referral");
@@ -1361,6 +2751,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
           .accounts({
             owner: tipper2.publicKey,
             tipProfile: tipper2Profile,
+            usernameRegistry: usernamePda("tipper_two"),
             systemProgram: SystemProgram.programId,
           })
           .signers([tipper2]).rpc();
@@ -1368,25 +2759,131 @@ describe("SolTip v2 - Full Platform Tests", () => {
         // may already exist
       }
 
-      const highFeeRef = referralPda(tipper2.publicKey, creatorProfile);
+      const highFeeRef = referralPda(tipper2.publicKey, tipper1Profile);
       try {
         await program.methods
           .registerReferral(3000) // 30% - above max 20%
           .accounts({
             referrer: tipper2.publicKey,
             referrerProfile: tipper2Profile,
-            refereeProfile: creatorProfile,
-            refereeOwner: creator.publicKey,
+            refereeProfile: tipper1Profile,
+            refereeOwner: tipper1.publicKey,
             referral: highFeeRef,
             owner: tipper2.publicKey,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
-          .signers([tipper2]).rpc();
+          .signers([tipper2, tipper1]).rpc();
         assert.fail("Should reject high fee");
       } catch (e) {
         expect(e.toString()).to.include("InvalidReferralFee");
       }
     });
+
+    it("rejects a second referrer for an already-referred profile", async () => {
+      try {
+        await program.methods
+          .registerReferral(500)
+          .accounts({
+            referrer: tipper2.publicKey,
+            referrerProfile: profilePda(tipper2.publicKey),
+            refereeProfile: creatorProfile,
+            refereeOwner: creator.publicKey,
+            referral: referralPda(tipper2.publicKey, creatorProfile),
+            owner: tipper2.publicKey,
+            systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
+          })
+          .signers([tipper2, creator]).rpc();
+        assert.fail("Should reject second referrer");
+      } catch (e) {
+        expect(e.toString()).to.include("ReferralAlreadyExists");
+      }
+    });
+
+    it("rejects a referral the referred creator did not sign", async () => {
+      try {
+        await program.methods
+          .registerReferral(500)
+          .accounts({
+            referrer: tipper2.publicKey,
+            referrerProfile: profilePda(tipper2.publicKey),
+            refereeProfile: tipper1Profile,
+            refereeOwner: tipper1.publicKey,
+            referral: referralPda(tipper2.publicKey, tipper1Profile),
+            owner: tipper2.publicKey,
+            systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
+          })
+          .signers([tipper2]).rpc();
+        assert.fail("Should require the referee's signature");
+      } catch (e) {
+        expect(e.toString()).to.match(/Signature|signer/i);
+      }
+    });
+
+it("requires the referral account once a creator is referred", async () => {
+      const profile = await program.account.tipProfile.fetch(creatorProfile);
+      assert.equal(profile.referral.toString(), referralPda(tipper1.publicKey, creatorProfile).toString());
+
+      // Goal 999 is still open from the pause tests
+      const goal = goalPda(creatorProfile, 999);
+      try {
+        await program.methods.contributeGoal(new BN(LAMPORTS_PER_SOL), null)
+          .accounts({
+            contributor: tipper2.publicKey, recipientProfile: creatorProfile,
+            tipGoal: goal, recipientOwner: creator.publicKey,
+            blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+            platformConfig: configPda(), systemProgram: SystemProgram.programId,
+            referral: null,
+            matchingPool: null, matchRecord: null,
+          }).signers([tipper2]).rpc();
+        assert.fail("Should require referral account");
+      } catch (e) {
+        expect(e.toString()).to.include("ReferralAccountMissing");
+      }
+    });
+
+    it("accrues the referrer's share of platform fees and lets them claim it", async () => {
+      const referral = referralPda(tipper1.publicKey, creatorProfile);
+      const goal = goalPda(creatorProfile, 999);
+
+      await program.methods.contributeGoal(new BN(LAMPORTS_PER_SOL), null)
+        .accounts({
+          contributor: tipper2.publicKey, recipientProfile: creatorProfile,
+          tipGoal: goal, recipientOwner: creator.publicKey,
+          blockedTipper: blockedPda(creatorProfile, tipper2.publicKey),
+          platformConfig: configPda(), systemProgram: SystemProgram.programId,
+          referral,
+          matchingPool: null, matchRecord: null,
+        }).signers([tipper2]).rpc();
+
+      // platform fee = 1 SOL * 100bps = 0.01 SOL; referrer share = 5% of that
+      const expected = 0.01 * LAMPORTS_PER_SOL * 0.05;
+      const ref = await program.account.referral.fetch(referral);
+      assert.equal(ref.pendingLamports.toNumber(), expected);
+      assert.equal(ref.totalEarned.toNumber(), expected);
+      assert.equal(ref.referralCount, 1);
+
+      const before = await provider.connection.getBalance(tipper1.publicKey);
+      await program.methods.claimReferralEarnings()
+        .accounts({ referrer: tipper1.publicKey, referral })
+        .signers([tipper1]).rpc();
+      const after = await provider.connection.getBalance(tipper1.publicKey);
+      assert.isTrue(after > before, "Referrer should receive earnings");
+
+      const claimed = await program.account.referral.fetch(referral);
+      assert.equal(claimed.pendingLamports.toNumber(), 0);
+
+      try {
+        await program.methods.claimReferralEarnings()
+          .accounts({ referrer: tipper1.publicKey, referral })
+          .signers([tipper1]).rpc();
+        assert.fail("Should reject empty claim");
+      } catch (e) {
+        expect(e.toString()).to.include("NothingToClaim");
+      }
+    });
   });
 
   // ── 16. Configure Split & Send Tip Split ───────────────────────
@@ -1403,12 +2900,13 @@ describe("SolTip v2 - Full Platform Tests", () => {
         .configureSplit([
           { wallet: split1, shareBps: 6000 },
           { wallet: split2, shareBps: 4000 },
-        ])
+        ], false)
         .accounts({
           owner: creator.publicKey,
           tipProfile: creatorProfile,
           tipSplit,
           systemProgram: SystemProgram.programId,
+          platformConfig: configPda(),
         })
         .signers([creator]).rpc();
 
@@ -1441,7 +2939,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
       const wrong = Keypair.generate().publicKey;
       try {
         await program.methods
-          .sendTipSplit(new BN(LAMPORTS_PER_SOL), "Bad split")
+          .sendTipSplit(new BN(LAMPORTS_PER_SOL), "Bad split", null, null)
           .accounts({
             tipper: tipper3.publicKey,
             recipientProfile: creatorProfile,
@@ -1449,6 +2947,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             vault: creatorVault,
             tipSplit,
             rateLimit: rlPda(tipper3.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, tipper3.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
           })
@@ -1463,11 +2962,141 @@ describe("SolTip v2 - Full Platform Tests", () => {
       }
     });
 
+    it("splits an SPL tip 60/40 across recipient token accounts", async () => {
+      const tipSplit = splitPda(creatorProfile);
+      const splitTipper = Keypair.generate();
+      await airdrop(splitTipper.publicKey);
+      const tipperTA = await createAccount(provider.connection, splitTipper, mint, splitTipper.publicKey);
+      await mintTo(provider.connection, creator, mint, tipperTA, creator, 10_000_000);
+      const ta1 = (await getOrCreateAssociatedTokenAccount(provider.connection, creator, mint, split1)).address;
+      const ta2 = (await getOrCreateAssociatedTokenAccount(provider.connection, creator, mint, split2)).address;
+      const before1 = (await getAccount(provider.connection, ta1)).amount;
+      const before2 = (await getAccount(provider.connection, ta2)).amount;
+
+      const accounts = {
+        tipper: splitTipper.publicKey, tipperTokenAccount: tipperTA,
+        recipientProfile: creatorProfile, profileOwner: creator.publicKey,
+        tokenMint: mint, tipSplit,
+        tipperRecord: trPda(splitTipper.publicKey, creatorProfile),
+        rateLimit: rlPda(splitTipper.publicKey, creatorProfile),
+        blockedTipper: blockedPda(creatorProfile, splitTipper.publicKey),
+        platformConfig: configPda(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+
+      // Destinations must be owned by the configured wallets, in split order
+      try {
+        await program.methods.sendTipSplitSpl(new BN(5_000_000), "Swapped", null, null)
+          .accounts(accounts)
+          .remainingAccounts([
+            { pubkey: ta2, isWritable: true, isSigner: false },
+            { pubkey: ta1, isWritable: true, isSigner: false },
+          ])
+          .signers([splitTipper]).rpc();
+        assert.fail("Should reject swapped token accounts");
+      } catch (e) {
+        expect(e.toString()).to.include("SplitRecipientMismatch");
+      }
+
+      await program.methods.sendTipSplitSpl(new BN(5_000_000), "Band tip", null, null)
+        .accounts(accounts)
+        .remainingAccounts([
+          { pubkey: ta1, isWritable: true, isSigner: false },
+          { pubkey: ta2, isWritable: true, isSigner: false },
+        ])
+        .signers([splitTipper]).rpc();
+
+      const after1 = (await getAccount(provider.connection, ta1)).amount;
+      const after2 = (await getAccount(provider.connection, ta2)).amount;
+      assert.equal(Number(after1 - before1), 3_000_000);
+      assert.equal(Number(after2 - before2), 2_000_000);
+
+      const tr = await program.account.tipperRecord.fetch(trPda(splitTipper.publicKey, creatorProfile));
+      assert.equal(tr.tipCount, 1);
+      assert.equal(tr.totalAmount.toNumber(), 5_000_000);
+      console.log("  SPL split: 3 / 2 tokens to two recipients");
+    });
+
+    it("accrues split shares in the vault for recipients to claim", async () => {
+      const tipSplit = splitPda(creatorProfile);
+      const recipients = [
+        { wallet: split1, shareBps: 6000 },
+        { wallet: split2, shareBps: 4000 },
+      ];
+      const configure = (accrue: boolean) => program.methods
+        .updateSplit(recipients, accrue)
+        .accounts({
+          owner: creator.publicKey, tipProfile: creatorProfile, tipSplit,
+          systemProgram: SystemProgram.programId, platformConfig: configPda(),
+        })
+        .signers([creator]).rpc();
+      try {
+        await program.methods.configureSplit(recipients, true)
+          .accounts({
+            owner: creator.publicKey, tipProfile: creatorProfile, tipSplit,
+            systemProgram: SystemProgram.programId, platformConfig: configPda(),
+          })
+          .signers([creator]).rpc();
+        assert.fail("configure_split only creates; changes go through update_split");
+      } catch (e) {
+        // init on an existing account expected to fail
+      }
+      await configure(true);
+
+      const accrueTipper = Keypair.generate();
+      await airdrop(accrueTipper.publicKey);
+      const vaultBefore = await program.account.vault.fetch(creatorVault);
+
+      // No recipient accounts needed: the tip lands in the vault
+      await program.methods.sendTipSplit(new BN(LAMPORTS_PER_SOL), "Accrued", null, null)
+        .accounts({
+          tipper: accrueTipper.publicKey, recipientProfile: creatorProfile,
+          profileOwner: creator.publicKey, vault: creatorVault, tipSplit,
+          rateLimit: rlPda(accrueTipper.publicKey, creatorProfile),
+          blockedTipper: blockedPda(creatorProfile, accrueTipper.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([accrueTipper]).rpc();
+
+      let s = await program.account.tipSplit.fetch(tipSplit);
+      assert.equal(s.claimable[0].toNumber(), 0.6 * LAMPORTS_PER_SOL);
+      assert.equal(s.claimable[1].toNumber(), 0.4 * LAMPORTS_PER_SOL);
+      let v = await program.account.vault.fetch(creatorVault);
+      assert.equal(v.splitReserved.toNumber() - vaultBefore.splitReserved.toNumber(), LAMPORTS_PER_SOL);
+
+      const claim = (recipient: Keypair) => program.methods.claimSplitShare()
+        .accounts({ recipient: recipient.publicKey, tipProfile: creatorProfile, vault: creatorVault, tipSplit })
+        .signers([recipient]).rpc();
+
+      const before = await provider.connection.getBalance(split1);
+      await claim(tipper1);
+      const after = await provider.connection.getBalance(split1);
+      assert.isAbove(after - before, 0.59 * LAMPORTS_PER_SOL, "Recipient receives their share (less the tx fee)");
+
+      try {
+        await claim(tipper1);
+        assert.fail("Nothing left to claim");
+      } catch (e) {
+        expect(e.toString()).to.include("NoSplitShareToClaim");
+      }
+
+      await claim(tipper2);
+      s = await program.account.tipSplit.fetch(tipSplit);
+      v = await program.account.vault.fetch(creatorVault);
+      assert.equal(s.claimable[0].toNumber() + s.claimable[1].toNumber(), 0);
+      assert.equal(v.splitReserved.toNumber(), 0);
+
+      await configure(false);
+      console.log("  Accrued 0.6 / 0.4 SOL, both recipients claimed");
+    });
+
     it("rejects self-tip via split", async () => {
       const tipSplit = splitPda(creatorProfile);
       try {
         await program.methods
-          .sendTipSplit(new BN(LAMPORTS_PER_SOL), "Self split")
+          .sendTipSplit(new BN(LAMPORTS_PER_SOL), "Self split", null, null)
           .accounts({
             tipper: creator.publicKey,
             recipientProfile: creatorProfile,
@@ -1475,6 +3104,7 @@ describe("SolTip v2 - Full Platform Tests", () => {
             vault: creatorVault,
             tipSplit,
             rateLimit: rlPda(creator.publicKey, creatorProfile),
+            blockedTipper: blockedPda(creatorProfile, creator.publicKey),
             platformConfig: configPda(),
             systemProgram: SystemProgram.programId,
           })
@@ -1495,12 +3125,13 @@ describe("SolTip v2 - Full Platform Tests", () => {
         await program.methods
           .configureSplit([
             { wallet: tipper1.publicKey, shareBps: 10000 },
-          ])
+          ], false)
           .accounts({
             owner: tipper1.publicKey,
             tipProfile: creatorProfile,
             tipSplit,
             systemProgram: SystemProgram.programId,
+            platformConfig: configPda(),
           })
           .signers([tipper1]).rpc();
         assert.fail("Should reject unauthorized");
@@ -1510,6 +3141,402 @@ describe("SolTip v2 - Full Platform Tests", () => {
     });
   });
 
+  // ── 16b. Council Governance ──────────────────────────────────
+
+  describe("16b. Council Governance", () => {
+    const TIMELOCK = 3;
+    const execAccounts = (id: number, extra: object = {}) => ({
+      executor: admin.publicKey, governance: governancePda(),
+      proposal: proposalPda(id), platformConfig: configPda(),
+      tipProfile: null, platformTreasury: null, destination: null,
+      treasuryTokenAccount: null, destinationTokenAccount: null, tokenProgram: null,
+      systemProgram: null, bounty: null, requester: null, vault: null, splVault: null,
+      vaultTokenAccount: null, requesterTokenAccount: null, escrowTokenAccount: null,
+      ...extra,
+    });
+
+    it("hands platform authority to a 2-of-3 council", async () => {
+      await program.methods
+        .initializeGovernance([admin.publicKey, tipper1.publicKey, tipper2.publicKey], 2, new BN(TIMELOCK))
+        .accounts({
+          authority: admin.publicKey, platformConfig: configPda(),
+          governance: governancePda(), systemProgram: SystemProgram.programId,
+        }).signers([admin]).rpc();
+
+      const cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.authority.toString(), governancePda().toString());
+      const gov = await program.account.governance.fetch(governancePda());
+      assert.equal(gov.threshold, 2);
+      assert.equal(gov.approvers.length, 3);
+
+      // The old single key can no longer act alone
+      try {
+        await program.methods.pausePlatform(true)
+          .accounts({ authority: admin.publicKey, platformConfig: configPda() })
+          .signers([admin]).rpc();
+        assert.fail("Single-key admin should be rejected");
+      } catch (e) {
+        expect(e.toString()).to.include("NotAdmin");
+      }
+    });
+
+    it("rejects proposals from non-members and duplicate approvals", async () => {
+      try {
+        await program.methods.createAdminProposal({ pause: { paused: true } })
+          .accounts({
+            proposer: creator.publicKey, governance: governancePda(),
+            proposal: proposalPda(0), systemProgram: SystemProgram.programId,
+          }).signers([creator]).rpc();
+        assert.fail("Non-member should be rejected");
+      } catch (e) {
+        expect(e.toString()).to.include("NotCouncilMember");
+      }
+
+      await program.methods.createAdminProposal({ pause: { paused: true } })
+        .accounts({
+          proposer: admin.publicKey, governance: governancePda(),
+          proposal: proposalPda(0), systemProgram: SystemProgram.programId,
+        }).signers([admin]).rpc();
+
+      try {
+        await program.methods.approveAdminProposal()
+          .accounts({ approver: admin.publicKey, governance: governancePda(), proposal: proposalPda(0) })
+          .signers([admin]).rpc();
+        assert.fail("Duplicate approval should be rejected");
+      } catch (e) {
+        expect(e.toString()).to.include("AlreadyApproved");
+      }
+
+      try {
+        await program.methods.executeAdminProposal()
+          .accounts(execAccounts(0)).signers([admin]).rpc();
+        assert.fail("Should need a second approval");
+      } catch (e) {
+        expect(e.toString()).to.include("ProposalNotApproved");
+      }
+    });
+
+    it("executes an emergency pause without waiting for the timelock", async () => {
+      await program.methods.approveAdminProposal()
+        .accounts({ approver: tipper1.publicKey, governance: governancePda(), proposal: proposalPda(0) })
+        .signers([tipper1]).rpc();
+      await program.methods.executeAdminProposal()
+        .accounts(execAccounts(0)).signers([admin]).rpc();
+
+      const cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.paused, true);
+      const prop = await program.account.adminProposal.fetch(proposalPda(0));
+      assert.deepEqual(prop.status, { executed: {} });
+    });
+
+    it("timelocks unpause, treasury withdrawal and authority transfer", async () => {
+      const actions = [
+        { pause: { paused: false } },
+        { withdrawTreasury: { amount: new BN(100_000), destination: tipper2.publicKey } },
+        { transferAuthority: { newAuthority: admin.publicKey } },
+      ];
+      for (let i = 0; i < actions.length; i++) {
+        await program.methods.createAdminProposal(actions[i] as any)
+          .accounts({
+            proposer: admin.publicKey, governance: governancePda(),
+            proposal: proposalPda(i + 1), systemProgram: SystemProgram.programId,
+          }).signers([admin]).rpc();
+        await program.methods.approveAdminProposal()
+          .accounts({ approver: tipper2.publicKey, governance: governancePda(), proposal: proposalPda(i + 1) })
+          .signers([tipper2]).rpc();
+      }
+
+      try {
+        await program.methods.executeAdminProposal()
+          .accounts(execAccounts(2, { platformTreasury: treasuryPda(), destination: tipper2.publicKey, systemProgram: SystemProgram.programId }))
+          .signers([admin]).rpc();
+        assert.fail("Treasury withdrawal must wait for the timelock");
+      } catch (e) {
+        expect(e.toString()).to.include("TimelockNotElapsed");
+      }
+
+      await new Promise(resolve => setTimeout(resolve, (TIMELOCK + 2) * 1000));
+
+      await program.methods.executeAdminProposal()
+        .accounts(execAccounts(1)).signers([admin]).rpc();
+      let cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.paused, false);
+
+      try {
+        await program.methods.executeAdminProposal()
+          .accounts(execAccounts(2, { platformTreasury: treasuryPda(), destination: tipper1.publicKey, systemProgram: SystemProgram.programId }))
+          .signers([admin]).rpc();
+        assert.fail("Destination must match the proposal");
+      } catch (e) {
+        expect(e.toString()).to.include("ProposalAccountMismatch");
+      }
+
+      const before = await provider.connection.getBalance(tipper2.publicKey);
+      await program.methods.executeAdminProposal()
+        .accounts(execAccounts(2, { platformTreasury: treasuryPda(), destination: tipper2.publicKey, systemProgram: SystemProgram.programId }))
+        .signers([admin]).rpc();
+      const after = await provider.connection.getBalance(tipper2.publicKey);
+      assert.equal(after - before, 100_000);
+
+      // Hand authority back to the single admin key for the remaining suites
+      await program.methods.executeAdminProposal()
+        .accounts(execAccounts(3)).signers([admin]).rpc();
+      await program.methods.acceptAuthority()
+        .accounts({ newAuthority: admin.publicKey, platformConfig: configPda() })
+        .signers([admin]).rpc();
+      cfg = await program.account.platformConfig.fetch(configPda());
+      assert.equal(cfg.authority.toString(), admin.publicKey.toString());
+    });
+  });
+
+  // ── 16c. Close Profile ─────────────────────────────────────────
+
+  describe("16c. Close Profile", () => {
+    const closeAccounts = (owner: PublicKey, username: string, extra: Record<string, PublicKey | null> = {}) => ({
+      owner, tipProfile: profilePda(owner), usernameRegistry: usernamePda(username),
+      vault: vaultPda(profilePda(owner)), tipSplit: splitPda(profilePda(owner)), pinnedSlot: pinnedPda(profilePda(owner)),
+      platformConfig: configPda(), platformTreasury: treasuryPda(),
+      referral: null, systemProgram: SystemProgram.programId,
+      ...extra,
+    });
+
+    it("rejects closing a profile with active goals", async () => {
+      try {
+        await program.methods.closeProfile()
+          .accounts(closeAccounts(creator.publicKey, "streamer_pro"))
+          .signers([creator]).rpc();
+        assert.fail("Active goals must be closed first");
+      } catch (e) {
+        expect(e.toString()).to.include("ProfileHasActiveGoals");
+      }
+    });
+
+    it("drains the vault through the fee path and reclaims all rent", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 5);
+      const profile = profilePda(owner.publicKey);
+      const vault   = vaultPda(profile);
+      const tier    = tierPda(profile, 1);
+
+      await program.methods.createProfile("closing_soon", "Closing", "", "")
+        .accounts({ owner: owner.publicKey, tipProfile: profile, usernameRegistry: usernamePda("closing_soon"), systemProgram: SystemProgram.programId })
+        .signers([owner]).rpc();
+      await program.methods.initializeVault()
+        .accounts({ owner: owner.publicKey, tipProfile: profile, vault, systemProgram: SystemProgram.programId })
+        .signers([owner]).rpc();
+      await program.methods.sendTip(new BN(0.1 * LAMPORTS_PER_SOL), null, null, null)
+        .accounts({
+          tipper: tipper1.publicKey, recipientProfile: profile,
+          recipientOwner: owner.publicKey, vault,
+          tipperRecord: trPda(tipper1.publicKey, profile),
+          rateLimit: rlPda(tipper1.publicKey, profile),
+          blockedTipper: blockedPda(profile, tipper1.publicKey),
+          platformConfig: configPda(),
+          systemProgram: SystemProgram.programId,
+          matchingPool: null, matchRecord: null, pinnedSlot: null,
+        }).signers([tipper1]).rpc();
+      await program.methods.configurePinnedSlot(new BN(60), new BN(0.05 * LAMPORTS_PER_SOL), 1_000)
+        .accounts({ owner: owner.publicKey, tipProfile: profile, pinnedSlot: pinnedPda(profile), systemProgram: SystemProgram.programId })
+        .signers([owner]).rpc();
+      await program.methods
+        .createSubscriptionTier(new BN(1), {
+          name: "Closing Tier", price: new BN(0.1 * LAMPORTS_PER_SOL), intervalSeconds: new BN(86400),
+          tokenMint: SystemProgram.programId, maxMembers: 0, benefitsHash: Array(32).fill(3),
+        })
+        .accounts({
+          owner: owner.publicKey, tipProfile: profile, subscriptionTier: tier,
+          systemProgram: SystemProgram.programId, platformConfig: configPda(),
+        }).signers([owner]).rpc();
+
+      const treasuryBefore = await provider.connection.getBalance(treasuryPda());
+      await program.methods.closeProfile()
+        .accounts(closeAccounts(owner.publicKey, "closing_soon"))
+        .remainingAccounts([{ pubkey: tier, isWritable: true, isSigner: false }])
+        .signers([owner]).rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(profile), "Profile should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(vault), "Vault should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(pinnedPda(profile)), "Pinned slot should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(tier), "Passed tiers should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(usernamePda("closing_soon")), "Username should be released");
+      const treasuryAfter = await provider.connection.getBalance(treasuryPda());
+      assert.isAtLeast(treasuryAfter, treasuryBefore, "Treasury only ever receives the platform fee");
+      console.log("  Profile closed | treasury fee:", treasuryAfter - treasuryBefore);
+    });
+  });
+
+  // ── 16d. Bounties ──────────────────────────────────────────────
+
+  describe("16d. Bounties", () => {
+    let viewer: Keypair;
+
+    before(async () => {
+      viewer = Keypair.generate();
+      await airdrop(viewer.publicKey, 5);
+    });
+
+    const create = (id: number, amount: number, expiresAt: number) => program.methods
+      .createBounty(new BN(id), new BN(amount), "play the intro song", new BN(expiresAt))
+      .accounts({
+        requester: viewer.publicKey, tipProfile: creatorProfile, vault: creatorVault,
+        bounty: bountyPda(creatorProfile, viewer.publicKey, id),
+        blockedTipper: blockedPda(creatorProfile, viewer.publicKey),
+        platformConfig: configPda(), systemProgram: SystemProgram.programId,
+      }).signers([viewer]).rpc();
+
+    const creatorStep = (id: number, step: "acceptBounty" | "fulfillBounty") => program.methods[step]()
+      .accounts({
+        creator: creator.publicKey, bounty: bountyPda(creatorProfile, viewer.publicKey, id),
+        ...(step === "acceptBounty" ? { tipProfile: creatorProfile } : {}),
+      })
+      .signers([creator]).rpc();
+
+    const payoutAccounts = (id: number) => ({
+      bounty: bountyPda(creatorProfile, viewer.publicKey, id), requester: viewer.publicKey,
+      tipProfile: creatorProfile, vault: creatorVault, splVault: null, vaultTokenAccount: null,
+      escrowTokenAccount: null, tokenProgram: null,
+    });
+
+    it("rejects an expiry in the past", async () => {
+      try {
+        await create(1, 0.1 * LAMPORTS_PER_SOL, Math.floor(Date.now() / 1000) - 10);
+        assert.fail("Expiry must be in the future");
+      } catch (e: any) {
+        assert.include(e.toString(), "InvalidBountyExpiry");
+      }
+    });
+
+    it("escrows, accepts, fulfills and pays a bounty via dispute resolution", async () => {
+      const amount = 0.3 * LAMPORTS_PER_SOL;
+      const bounty = bountyPda(creatorProfile, viewer.publicKey, 1);
+      await create(1, amount, Math.floor(Date.now() / 1000) + 3600);
+
+      try {
+        await creatorStep(1, "fulfillBounty");
+        assert.fail("Must be accepted first");
+      } catch (e: any) {
+        assert.include(e.toString(), "BountyNotAccepted");
+      }
+      await creatorStep(1, "acceptBounty");
+      await creatorStep(1, "fulfillBounty");
+
+      // A stranger cannot release inside the review window
+      try {
+        await program.methods.releaseBounty()
+          .accounts({ caller: tipper2.publicKey, ...payoutAccounts(1), platformConfig: configPda() })
+          .signers([tipper2]).rpc();
+        assert.fail("Review window still open");
+      } catch (e: any) {
+        assert.include(e.toString(), "BountyReviewWindowOpen");
+      }
+
+      await program.methods.disputeBounty()
+        .accounts({ requester: viewer.publicKey, bounty })
+        .signers([viewer]).rpc();
+      assert.deepEqual((await program.account.bounty.fetch(bounty)).status, { disputed: {} });
+
+      const vBefore = (await program.account.vault.fetch(creatorVault)).balance.toNumber();
+      await program.methods.resolveBountyDispute(true)
+        .accounts({
+          authority: admin.publicKey, platformConfig: configPda(),
+          ...payoutAccounts(1), requesterTokenAccount: null,
+        }).signers([admin]).rpc();
+
+      const v = await program.account.vault.fetch(creatorVault);
+      assert.equal(v.balance.toNumber() - vBefore, amount);
+      assert.isNull(await provider.connection.getAccountInfo(bounty), "Bounty should be closed");
+    });
+
+    it("lets the requester cancel an open bounty but not an accepted one", async () => {
+      const amount = 0.1 * LAMPORTS_PER_SOL;
+      const refund = (id: number) => program.methods.refundBounty()
+        .accounts({
+          caller: viewer.publicKey, bounty: bountyPda(creatorProfile, viewer.publicKey, id),
+          requester: viewer.publicKey, tipProfile: creatorProfile, requesterTokenAccount: null,
+          escrowTokenAccount: null, tokenProgram: null,
+        }).signers([viewer]).rpc();
+
+      await create(2, amount, Math.floor(Date.now() / 1000) + 3600);
+      const before = await provider.connection.getBalance(viewer.publicKey);
+      await refund(2);
+      const after = await provider.connection.getBalance(viewer.publicKey);
+      assert.isAbove(after - before, amount, "Escrow and rent come back");
+      assert.isNull(await provider.connection.getAccountInfo(bountyPda(creatorProfile, viewer.publicKey, 2)));
+
+      await create(3, amount, Math.floor(Date.now() / 1000) + 3600);
+      await creatorStep(3, "acceptBounty");
+      try {
+        await refund(3);
+        assert.fail("Accepted bounties refund only after expiry");
+      } catch (e: any) {
+        assert.include(e.toString(), "BountyNotRefundable");
+      }
+    });
+
+    it("releases on the requester's approval", async () => {
+      const vBefore = (await program.account.vault.fetch(creatorVault)).balance.toNumber();
+      await creatorStep(3, "fulfillBounty");
+      await program.methods.releaseBounty()
+        .accounts({ caller: viewer.publicKey, ...payoutAccounts(3), platformConfig: configPda() })
+        .signers([viewer]).rpc();
+      const v = await program.account.vault.fetch(creatorVault);
+      assert.equal(v.balance.toNumber() - vBefore, 0.1 * LAMPORTS_PER_SOL);
+      assert.equal((await program.account.tipProfile.fetch(creatorProfile)).activeBounties, 0);
+    });
+
+    it("keeps a profile with an accepted bounty open until it is settled", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 5);
+      const profile = profilePda(owner.publicKey);
+      const vault   = vaultPda(profile);
+      const bounty  = bountyPda(profile, viewer.publicKey, 1);
+
+      await program.methods.createProfile("bounty_closer", "Bounty Closer", "", "")
+        .accounts({ owner: owner.publicKey, tipProfile: profile, usernameRegistry: usernamePda("bounty_closer"), systemProgram: SystemProgram.programId })
+        .signers([owner]).rpc();
+      await program.methods.initializeVault()
+        .accounts({ owner: owner.publicKey, tipProfile: profile, vault, systemProgram: SystemProgram.programId })
+        .signers([owner]).rpc();
+      await program.methods
+        .createBounty(new BN(1), new BN(0.1 * LAMPORTS_PER_SOL), "shout out my channel", new BN(Math.floor(Date.now() / 1000) + 3600))
+        .accounts({
+          requester: viewer.publicKey, tipProfile: profile, vault, bounty,
+          blockedTipper: blockedPda(profile, viewer.publicKey),
+          platformConfig: configPda(), systemProgram: SystemProgram.programId,
+        }).signers([viewer]).rpc();
+      await program.methods.acceptBounty()
+        .accounts({ creator: owner.publicKey, bounty, tipProfile: profile })
+        .signers([owner]).rpc();
+      assert.equal((await program.account.tipProfile.fetch(profile)).activeBounties, 1);
+
+      const close = () => program.methods.closeProfile()
+        .accounts({
+          owner: owner.publicKey, tipProfile: profile, usernameRegistry: usernamePda("bounty_closer"),
+          vault, tipSplit: splitPda(profile), pinnedSlot: pinnedPda(profile),
+          platformConfig: configPda(), platformTreasury: treasuryPda(),
+          referral: null, systemProgram: SystemProgram.programId,
+        }).signers([owner]).rpc();
+      try {
+        await close();
+        assert.fail("The accepted bounty's escrow pays into the vault");
+      } catch (e: any) {
+        assert.include(e.toString(), "ProfileHasActiveBounties");
+      }
+
+      await program.methods.fulfillBounty()
+        .accounts({ creator: owner.publicKey, bounty })
+        .signers([owner]).rpc();
+      await program.methods.releaseBounty()
+        .accounts({
+          caller: viewer.publicKey, bounty, requester: viewer.publicKey, tipProfile: profile,
+          vault, splVault: null, vaultTokenAccount: null, escrowTokenAccount: null,
+          platformConfig: configPda(), tokenProgram: null,
+        }).signers([viewer]).rpc();
+      await close();
+      assert.isNull(await provider.connection.getAccountInfo(profile), "Profile should be closed");
+    });
+  });
+
   // ── 17. Final Comprehensive Statistics ─────────────────────────
 
   describe("17. Final Comprehensive Statistics", () => {